@@ -0,0 +1,419 @@
+use crate::{
+    byte_buffer::MByteBuffer,
+    decode_mode::DecodeMode,
+    error::{MByteBufferError, Result},
+};
+use std::marker::PhantomData;
+
+/// The order bits are packed into each byte by [`BitWriter`] and unpacked by [`BitReader`].
+///
+/// This is implemented by the two marker types [`MsbFirst`] and [`LsbFirst`] and can not be
+/// implemented outside of this crate.
+pub trait BitOrder: private::Sealed + Copy + Default + Send + Sync + 'static {
+    /// Whether the most significant bit of a value goes into the highest free bit of a byte.
+    const MSB_FIRST: bool;
+}
+
+/// Values are written starting with their most significant bit, filling each byte from its
+/// highest bit down. This is the order most network protocols document their bit fields in.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct MsbFirst;
+
+/// Values are written starting with their least significant bit, filling each byte from its
+/// lowest bit up, as in DEFLATE.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct LsbFirst;
+
+impl BitOrder for MsbFirst {
+    const MSB_FIRST: bool = true;
+}
+
+impl BitOrder for LsbFirst {
+    const MSB_FIRST: bool = false;
+}
+
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for super::MsbFirst {}
+    impl Sealed for super::LsbFirst {}
+}
+
+#[inline]
+fn mask(bits: u32) -> u64 {
+    if bits >= 64 {
+        u64::MAX
+    } else {
+        (1 << bits) - 1
+    }
+}
+
+/// A value that can be written in a given number of bits by [`BitWriter`] and read back by
+/// [`BitReader`].
+///
+/// Implemented for `bool` and the integer primitives. Signed integers are written in two's
+/// complement and sign extended when read. Implement it for your own types, such as small
+/// enums, to pack them with `#[mbytey(bits = N)]`.
+pub trait BitField: Sized {
+    /// The value as the lowest `bits` bits of a `u64`.
+    ///
+    /// # Errors
+    /// - [`MByteBufferError::BitOverflow`] is returned if the value does not fit in `bits` bits.
+    fn to_bit_field(&self, bits: u32) -> Result<u64>;
+
+    /// The value of the lowest `bits` bits of `raw`, which has no higher bits set.
+    fn from_bit_field(raw: u64, bits: u32) -> Result<Self>;
+
+    /// The value of the lowest `bits` bits of `raw`, validated as strictly as `mode` asks.
+    ///
+    /// This is what [`BitReader::read`] calls with the [`DecodeMode`] of its buffer.
+    /// Defaults to [`from_bit_field`](Self::from_bit_field).
+    #[inline]
+    fn from_bit_field_with_mode(raw: u64, bits: u32, mode: DecodeMode) -> Result<Self> {
+        let _ = mode;
+
+        Self::from_bit_field(raw, bits)
+    }
+}
+
+impl BitField for bool {
+    #[inline]
+    fn to_bit_field(&self, _bits: u32) -> Result<u64> {
+        Ok(*self as u64)
+    }
+
+    #[inline]
+    fn from_bit_field(raw: u64, bits: u32) -> Result<Self> {
+        Self::from_bit_field_with_mode(raw, bits, DecodeMode::Strict)
+    }
+
+    #[inline]
+    fn from_bit_field_with_mode(raw: u64, _bits: u32, mode: DecodeMode) -> Result<Self> {
+        // Values wider than a byte are never valid, so they are reported as the largest byte.
+        mode.decode_bool(u8::try_from(raw).unwrap_or(u8::MAX))
+    }
+}
+
+macro_rules! impl_bit_field_unsigned {
+    ($($type:ty),*) => {
+        $(
+            impl BitField for $type {
+                #[inline]
+                fn to_bit_field(&self, bits: u32) -> Result<u64> {
+                    let value = *self as u64;
+
+                    if value & !mask(bits) != 0 {
+                        return Err(MByteBufferError::BitOverflow {
+                            value: value as i128,
+                            bits,
+                        });
+                    }
+
+                    Ok(value)
+                }
+
+                #[inline]
+                fn from_bit_field(raw: u64, bits: u32) -> Result<Self> {
+                    <$type>::try_from(raw).map_err(|_| MByteBufferError::BitOverflow {
+                        value: raw as i128,
+                        bits,
+                    })
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_bit_field_signed {
+    ($($type:ty),*) => {
+        $(
+            impl BitField for $type {
+                #[inline]
+                fn to_bit_field(&self, bits: u32) -> Result<u64> {
+                    let value = *self as i64;
+                    let shift = 64 - bits.clamp(1, 64);
+
+                    // The value fits if sign extending its lowest bits gives it back.
+                    if (value << shift) >> shift != value {
+                        return Err(MByteBufferError::BitOverflow {
+                            value: value as i128,
+                            bits,
+                        });
+                    }
+
+                    Ok(value as u64 & mask(bits))
+                }
+
+                #[inline]
+                fn from_bit_field(raw: u64, bits: u32) -> Result<Self> {
+                    let shift = 64 - bits.clamp(1, 64);
+                    let value = ((raw << shift) as i64) >> shift;
+
+                    <$type>::try_from(value).map_err(|_| MByteBufferError::BitOverflow {
+                        value: value as i128,
+                        bits,
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_bit_field_unsigned!(u8, u16, u32, u64, usize);
+impl_bit_field_signed!(i8, i16, i32, i64, isize);
+
+/// Writes values of any bit width into a [`MByteBuffer`].
+///
+/// Created by [`MByteBuffer::bit_writer`]. Every completed byte is written at the cursor of the
+/// buffer right away. The last partial byte is padded with zero bits and written by
+/// [`align`](Self::align) or [`finish`](Self::finish), or when the writer is dropped, in which
+/// case an error writing it is ignored.
+///
+/// # Examples
+/// ```
+/// use mmap_bytey_byte_buffer::{bits::MsbFirst, byte_buffer::MByteBuffer};
+///
+/// let mut buffer = MByteBuffer::new().unwrap();
+/// let mut bits = buffer.bit_writer::<MsbFirst>();
+///
+/// bits.write_bool(true).unwrap();
+/// bits.write_bits(0b101, 3).unwrap();
+/// bits.write(&-2i8, 4).unwrap();
+/// bits.finish().unwrap();
+///
+/// buffer.move_cursor(0).unwrap();
+/// assert_eq!(buffer.read_slice(1).unwrap(), [0b1101_1110]);
+/// ```
+#[derive(Debug)]
+pub struct BitWriter<'a, B: BitOrder> {
+    buffer: &'a mut MByteBuffer,
+    pending: u8,
+    used: u32,
+    order: PhantomData<B>,
+}
+
+impl<'a, B: BitOrder> BitWriter<'a, B> {
+    pub(crate) fn new(buffer: &'a mut MByteBuffer) -> Self {
+        BitWriter {
+            buffer,
+            pending: 0,
+            used: 0,
+            order: PhantomData,
+        }
+    }
+
+    /// Writes the lowest `bits` bits of `value`.
+    ///
+    /// # Errors
+    /// - [`MByteBufferError::BitOverflow`] is returned if `value` has bits set above `bits`.
+    /// - See [`write_slice`](MByteBuffer::write_slice) for errors writing completed bytes.
+    ///
+    /// # Panics
+    /// Panics if `bits` is greater than 64.
+    pub fn write_bits(&mut self, value: u64, bits: u32) -> Result<&mut Self> {
+        assert!(bits <= 64, "can not write more than 64 bits at once");
+
+        if value & !mask(bits) != 0 {
+            return Err(MByteBufferError::BitOverflow {
+                value: value as i128,
+                bits,
+            });
+        }
+
+        let mut left = bits;
+
+        while left > 0 {
+            let free = 8 - self.used;
+            let take = free.min(left);
+
+            if B::MSB_FIRST {
+                let chunk = (value >> (left - take)) & mask(take);
+
+                self.pending |= (chunk as u8) << (free - take);
+            } else {
+                let chunk = (value >> (bits - left)) & mask(take);
+
+                self.pending |= (chunk as u8) << self.used;
+            }
+
+            self.used += take;
+            left -= take;
+
+            if self.used == 8 {
+                self.flush()?;
+            }
+        }
+
+        Ok(self)
+    }
+
+    /// Writes a single bit.
+    pub fn write_bool(&mut self, value: bool) -> Result<&mut Self> {
+        self.write_bits(value as u64, 1)
+    }
+
+    /// Writes a [`BitField`] in `bits` bits.
+    pub fn write<T: BitField>(&mut self, value: &T, bits: u32) -> Result<&mut Self> {
+        self.write_bits(value.to_bit_field(bits)?, bits)
+    }
+
+    /// Pads the current byte with zero bits and writes it, so the next value starts on a byte
+    /// boundary. Does nothing if the writer already is on one.
+    pub fn align(&mut self) -> Result<&mut Self> {
+        if self.used > 0 {
+            self.flush()?;
+        }
+
+        Ok(self)
+    }
+
+    /// Aligns the writer and ends it, leaving the cursor of the buffer after the last byte written.
+    pub fn finish(mut self) -> Result<()> {
+        self.align()?;
+
+        Ok(())
+    }
+
+    /// Returns the number of bits written into the current byte.
+    pub fn bit_offset(&self) -> u32 {
+        self.used
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        self.buffer.write_slice(&[self.pending])?;
+        self.pending = 0;
+        self.used = 0;
+
+        Ok(())
+    }
+}
+
+impl<B: BitOrder> Drop for BitWriter<'_, B> {
+    fn drop(&mut self) {
+        if self.used > 0 {
+            let _ = self.flush();
+        }
+    }
+}
+
+/// Reads values of any bit width from a [`MByteBuffer`].
+///
+/// Created by [`MByteBuffer::bit_reader`] or [`MByteBufferReader::bit_reader`](crate::byte_buffer::MByteBufferReader::bit_reader).
+/// A byte is taken from the cursor of the buffer as soon as its first bit is read, so after the
+/// reader is dropped the cursor points at the byte following the last bit read, and any bits
+/// left in that last byte are skipped.
+///
+/// # Examples
+/// ```
+/// use mmap_bytey_byte_buffer::{bits::LsbFirst, byte_buffer::MByteBuffer};
+///
+/// let mut buffer = MByteBuffer::new().unwrap();
+///
+/// buffer.write(0b1110_1011u8).unwrap();
+/// buffer.move_cursor(0).unwrap();
+///
+/// let mut bits = buffer.bit_reader::<LsbFirst>();
+///
+/// assert_eq!(bits.read_bits(3).unwrap(), 0b011);
+/// assert!(bits.read_bool().unwrap());
+/// assert_eq!(bits.read::<i8>(4).unwrap(), -2);
+/// ```
+#[derive(Debug)]
+pub struct BitReader<'a, B: BitOrder> {
+    data: &'a [u8],
+    cursor: &'a mut usize,
+    mode: DecodeMode,
+    current: u8,
+    left: u32,
+    order: PhantomData<B>,
+}
+
+impl<'a, B: BitOrder> BitReader<'a, B> {
+    pub(crate) fn new(data: &'a [u8], cursor: &'a mut usize, mode: DecodeMode) -> Self {
+        BitReader {
+            data,
+            cursor,
+            mode,
+            current: 0,
+            left: 0,
+            order: PhantomData,
+        }
+    }
+
+    /// Reads `bits` bits as the lowest bits of a `u64`.
+    ///
+    /// # Errors
+    /// - [`MByteBufferError::ReadOutOfBounds`] is returned if the buffer runs out of bytes.
+    ///
+    /// # Panics
+    /// Panics if `bits` is greater than 64.
+    pub fn read_bits(&mut self, bits: u32) -> Result<u64> {
+        assert!(bits <= 64, "can not read more than 64 bits at once");
+
+        let mut value = 0u64;
+        let mut read = 0;
+
+        while read < bits {
+            if self.left == 0 {
+                self.next_byte()?;
+            }
+
+            let take = self.left.min(bits - read);
+
+            if B::MSB_FIRST {
+                let chunk = (self.current >> (self.left - take)) as u64 & mask(take);
+
+                value = (value << take) | chunk;
+            } else {
+                let chunk = (self.current >> (8 - self.left)) as u64 & mask(take);
+
+                value |= chunk << read;
+            }
+
+            self.left -= take;
+            read += take;
+        }
+
+        Ok(value)
+    }
+
+    /// Reads a single bit.
+    pub fn read_bool(&mut self) -> Result<bool> {
+        Ok(self.read_bits(1)? == 1)
+    }
+
+    /// Reads a [`BitField`] from `bits` bits, validated in the [`DecodeMode`] of the buffer.
+    pub fn read<T: BitField>(&mut self, bits: u32) -> Result<T> {
+        T::from_bit_field_with_mode(self.read_bits(bits)?, bits, self.mode)
+    }
+
+    /// Skips the bits left in the current byte, so the next value starts on a byte boundary.
+    pub fn align(&mut self) -> &mut Self {
+        self.left = 0;
+        self
+    }
+
+    /// Returns the number of bits read from the current byte.
+    pub fn bit_offset(&self) -> u32 {
+        (8 - self.left) % 8
+    }
+
+    fn next_byte(&mut self) -> Result<()> {
+        let cursor = *self.cursor;
+
+        match self.data.get(cursor) {
+            Some(&byte) => {
+                self.current = byte;
+                self.left = 8;
+                *self.cursor += 1;
+
+                Ok(())
+            }
+            None => Err(MByteBufferError::ReadOutOfBounds {
+                length: self.data.len(),
+                start: cursor,
+                end: cursor + 1,
+            }),
+        }
+    }
+}
@@ -1,9 +1,14 @@
-use crate::byte_buffer_write::MByteBufferWrite;
+use crate::byte_buffer_write::{padding_to, MByteBufferWrite};
 use crate::{
     allocator::{Buffer, BUFFER_SIZE},
+    bits::{BitOrder, BitReader, BitWriter},
     byte_buffer_read::MByteBufferRead,
+    byte_buffer_read_ref::MByteBufferReadRef,
+    byte_order::{Big, ByteOrder, Little, Native},
+    decode_mode::DecodeMode,
 };
-use std::{ptr, slice};
+use crate::{byte_buffer_read::time::OffsetBy, byte_buffer_write::time::signed_offset};
+use std::{ptr, slice, time::Instant};
 
 use crate::error::{MByteBufferError, Result};
 /// A resizeable buffer to store data in.
@@ -34,7 +39,9 @@ use crate::error::{MByteBufferError, Result};
 pub struct MByteBuffer {
     length: usize,
     cursor: usize,
-    buffer: Buffer,
+    mode: DecodeMode,
+    /// The allocation holding the data, `None` for a [counter](Self::counter).
+    buffer: Option<Buffer>,
 }
 
 /// `MByteBuffer` are `Send` Becuase `u8` is `Send` because the data they
@@ -61,10 +68,49 @@ impl MByteBuffer {
         Ok(Self {
             length: 0,
             cursor: 0,
-            buffer: Buffer::new()?,
+            mode: DecodeMode::default(),
+            buffer: Some(Buffer::new()?),
         })
     }
 
+    /// Constructs a [`MByteBuffer`] that only counts the bytes written to it.
+    ///
+    /// Nothing is allocated or stored, so writing is not limited by the capacity, and afterwards
+    /// [`length`](Self::length) is the number of bytes the values take up. Reading from a counter
+    /// fails with [`MByteBufferError::ReadOutOfBounds`] as it holds no bytes.
+    ///
+    /// # Examples
+    /// ```
+    /// use mmap_bytey_byte_buffer::byte_buffer::MByteBuffer;
+    ///
+    /// let mut counter = MByteBuffer::counter();
+    ///
+    /// counter.write(vec![0u8; 4096]).unwrap();
+    ///
+    /// assert_eq!(counter.length(), 8 + 4096);
+    /// ```
+    pub fn counter() -> Self {
+        Self {
+            length: 0,
+            cursor: 0,
+            mode: DecodeMode::default(),
+            buffer: None,
+        }
+    }
+
+    /// Whether the buffer is a [counter](Self::counter).
+    pub fn is_counter(&self) -> bool {
+        self.buffer.is_none()
+    }
+
+    /// The data written so far, empty for a counter.
+    fn data(&self) -> &[u8] {
+        match &self.buffer {
+            Some(buffer) => &buffer.as_ref()[..self.length],
+            None => &[],
+        }
+    }
+
     /// Writes a slice of type [u8] to the [`MByteBuffer`] **without safety checks**.
     ///
     /// # Safety
@@ -91,11 +137,14 @@ impl MByteBuffer {
     pub unsafe fn write_slice_unchecked(&mut self, source: &[u8]) -> &mut Self {
         let source_length = source.len();
 
-        ptr::copy_nonoverlapping(
-            source.as_ptr(),
-            self.buffer.as_mut().as_mut_ptr().add(self.cursor),
-            source_length,
-        );
+        if let Some(buffer) = &mut self.buffer {
+            ptr::copy_nonoverlapping(
+                source.as_ptr(),
+                buffer.as_mut().as_mut_ptr().add(self.cursor),
+                source_length,
+            );
+        }
+
         self.cursor += source.len();
 
         self
@@ -123,8 +172,9 @@ impl MByteBuffer {
     /// buffer.write_slice(&values);
     /// ```
     pub fn write_slice(&mut self, source: &[u8]) -> Result<&mut Self> {
-        if self.cursor + source.len() > BUFFER_SIZE {
-            return Err(MByteBufferError::MaxCapacity);
+        match self.cursor.checked_add(source.len()) {
+            Some(end) if end <= self.capacity() => {}
+            _ => return Err(MByteBufferError::MaxCapacity),
         }
 
         unsafe {
@@ -138,6 +188,75 @@ impl MByteBuffer {
         Ok(self)
     }
 
+    /// Writes `count` zero bytes to the [`MByteBuffer`], for example to fill reserved space.
+    ///
+    /// # Errors & Behaviour
+    /// See [`write_slice`](Self::write_slice).
+    ///
+    /// # Examples
+    /// ```
+    /// use mmap_bytey_byte_buffer::byte_buffer::MByteBuffer;
+    ///
+    /// let mut buffer = MByteBuffer::new().unwrap();
+    ///
+    /// buffer.write_zeros(3).unwrap();
+    ///
+    /// assert_eq!(buffer.length(), 3);
+    /// ```
+    pub fn write_zeros(&mut self, mut count: usize) -> Result<&mut Self> {
+        const ZEROS: [u8; 64] = [0; 64];
+
+        while count > 0 {
+            let chunk = count.min(ZEROS.len());
+
+            self.write_slice(&ZEROS[..chunk])?;
+            count -= chunk;
+        }
+
+        Ok(self)
+    }
+
+    /// Writes zero bytes until the current cursor position is a multiple of `alignment`.
+    ///
+    /// An `alignment` of 0 or 1 writes nothing.
+    ///
+    /// # Errors & Behaviour
+    /// See [`write_slice`](Self::write_slice).
+    ///
+    /// # Examples
+    /// ```
+    /// use mmap_bytey_byte_buffer::byte_buffer::MByteBuffer;
+    ///
+    /// let mut buffer = MByteBuffer::new().unwrap();
+    ///
+    /// buffer.write(1u8).unwrap();
+    /// buffer.pad_to(4).unwrap();
+    ///
+    /// assert_eq!(buffer.cursor(), 4);
+    /// ```
+    pub fn pad_to(&mut self, alignment: usize) -> Result<&mut Self> {
+        self.write_zeros(padding_to(self.cursor, alignment))
+    }
+
+    /// Writes a slice of type [u8] to the [`MByteBuffer`], reversing the byte order of every
+    /// `width` sized chunk. Used to write slices of numbers in the non-native byte order in one go.
+    #[inline]
+    pub(crate) fn write_slice_swapped(&mut self, source: &[u8], width: usize) -> Result<&mut Self> {
+        debug_assert!(width > 0 && source.len().is_multiple_of(width));
+
+        let start = self.cursor;
+
+        self.write_slice(source)?;
+
+        if let Some(buffer) = &mut self.buffer {
+            for chunk in buffer.as_mut()[start..self.cursor].chunks_exact_mut(width) {
+                chunk.reverse();
+            }
+        }
+
+        Ok(self)
+    }
+
     /// Writes the given value to the [`MByteBuffer`].
     ///
     /// The value has to implement the [`ByteBufferWrite`] trait.
@@ -155,9 +274,7 @@ impl MByteBuffer {
     /// buffer.write(&value);
     /// ```
     pub fn write<T: MByteBufferWrite>(&mut self, source: T) -> Result<&mut Self> {
-        source.write_to_mbuffer(self)?;
-
-        Ok(self)
+        self.write_with::<Native, T>(source)
     }
 
     /// Writes the given value to the [`MByteBuffer`] in **little endian** ordering.
@@ -177,9 +294,7 @@ impl MByteBuffer {
     /// buffer.write_le(&value);
     /// ```
     pub fn write_le<T: MByteBufferWrite>(&mut self, source: T) -> Result<&mut Self> {
-        source.write_to_mbuffer_le(self)?;
-
-        Ok(self)
+        self.write_with::<Little, T>(source)
     }
 
     /// Writes the given value to the [`MByteBuffer`] in **big endian** ordering.
@@ -199,7 +314,30 @@ impl MByteBuffer {
     /// buffer.write_be(&value);
     /// ```
     pub fn write_be<T: MByteBufferWrite>(&mut self, source: T) -> Result<&mut Self> {
-        source.write_to_mbuffer_be(self)?;
+        self.write_with::<Big, T>(source)
+    }
+
+    /// Writes the given value to the [`MByteBuffer`] in the byte order `O`.
+    ///
+    /// The value has to implement the [`ByteBufferWrite`] trait.
+    ///
+    /// # Errors & Behaviour
+    /// See [`write_slice`](Self::write_slice).
+    ///
+    /// # Examples
+    /// ```
+    /// use mmap_bytey_byte_buffer::{byte_buffer::MByteBuffer, byte_order::Little};
+    ///
+    /// let mut buffer = MByteBuffer::new().unwrap();
+    /// let value: u32 = 12345;
+    ///
+    /// buffer.write_with::<Little, _>(&value);
+    /// ```
+    pub fn write_with<O: ByteOrder, T: MByteBufferWrite>(
+        &mut self,
+        source: T,
+    ) -> Result<&mut Self> {
+        source.write_to_mbuffer_with::<O>(self)?;
 
         Ok(self)
     }
@@ -211,6 +349,7 @@ impl MByteBuffer {
     /// - The size does not exceed the capacity of the buffer.
     /// - The result of cursor position + the given size does not exceed the length of the buffer.
     /// - The cursor position is not out of bounds
+    /// - The buffer is not a [counter](Self::counter), which holds no data.
     ///
     /// # Behaviour
     /// The current cursor position will be increased by the given size.
@@ -230,7 +369,7 @@ impl MByteBuffer {
     /// }
     ///```
     pub unsafe fn read_slice_unchecked(&mut self, size: usize) -> &[u8] {
-        let ret = slice::from_raw_parts(self.buffer.as_ref().as_ptr().add(self.cursor), size);
+        let ret = slice::from_raw_parts(self.pointer().add(self.cursor), size);
         self.cursor += size;
 
         ret
@@ -257,17 +396,69 @@ impl MByteBuffer {
     /// println!("{:?}", buffer.read_slice(4));
     /// ```
     pub fn read_slice(&mut self, size: usize) -> Result<&[u8]> {
-        if self.cursor + size > self.length {
-            return Err(MByteBufferError::ReadOutOfBounds {
-                length: self.length,
-                start: self.cursor,
-                end: self.cursor + size,
-            });
+        let length = self.data().len();
+
+        match self.cursor.checked_add(size) {
+            Some(end) if end <= length => {}
+            _ => {
+                return Err(MByteBufferError::ReadOutOfBounds {
+                    length,
+                    start: self.cursor,
+                    end: self.cursor.saturating_add(size),
+                })
+            }
         }
 
         Ok(unsafe { self.read_slice_unchecked(size) })
     }
 
+    /// Moves the current cursor position forward by `count` bytes without reading them, for
+    /// example to skip reserved space.
+    ///
+    /// # Errors
+    /// - [`MByteBufferError::ReadOutOfBounds`] is returned if the result of the current cursor position + `count` exceeds the buffer's length
+    ///
+    /// # Examples
+    /// ```
+    /// use mmap_bytey_byte_buffer::byte_buffer::MByteBuffer;
+    ///
+    /// let mut buffer = MByteBuffer::new().unwrap();
+    ///
+    /// buffer.write_slice(&[0, 0, 7]).unwrap();
+    /// buffer.move_cursor(0).unwrap();
+    /// buffer.skip(2).unwrap();
+    ///
+    /// assert_eq!(buffer.read::<u8>().unwrap(), 7);
+    /// ```
+    pub fn skip(&mut self, count: usize) -> Result<&mut Self> {
+        self.read_slice(count)?;
+
+        Ok(self)
+    }
+
+    /// Moves the current cursor position forward to the next multiple of `alignment`.
+    ///
+    /// An `alignment` of 0 or 1 leaves the cursor where it is.
+    ///
+    /// # Errors
+    /// See [`skip`](Self::skip).
+    ///
+    /// # Examples
+    /// ```
+    /// use mmap_bytey_byte_buffer::byte_buffer::MByteBuffer;
+    ///
+    /// let mut buffer = MByteBuffer::new().unwrap();
+    ///
+    /// buffer.write_slice(&[1, 0, 0, 0, 7]).unwrap();
+    /// buffer.move_cursor(1).unwrap();
+    /// buffer.align_to(4).unwrap();
+    ///
+    /// assert_eq!(buffer.read::<u8>().unwrap(), 7);
+    /// ```
+    pub fn align_to(&mut self, alignment: usize) -> Result<&mut Self> {
+        self.skip(padding_to(self.cursor, alignment))
+    }
+
     /// Reads a value of type T that implements the [`ByteBufferRead`] trait from the buffer.
     ///
     /// # Errors & Behaviour
@@ -289,7 +480,7 @@ impl MByteBuffer {
     /// let x: u32 = buffer.read().unwrap();
     /// ```
     pub fn read<T: MByteBufferRead>(&mut self) -> Result<T> {
-        T::read_from_mbuffer(self)
+        self.read_with::<Native, T>()
     }
 
     /// Reads a value of type T that implements the [`ByteBufferRead`] trait from the buffer in **little endian** ordering.
@@ -313,7 +504,7 @@ impl MByteBuffer {
     /// let x: u32 = buffer.read_le().unwrap();
     /// ```
     pub fn read_le<T: MByteBufferRead>(&mut self) -> Result<T> {
-        T::read_from_mbuffer_le(self)
+        self.read_with::<Little, T>()
     }
 
     /// Reads a value of type T that implements the [`ByteBufferRead`] trait from the buffer in **big endian** ordering.
@@ -337,7 +528,223 @@ impl MByteBuffer {
     /// let x: u32 = buffer.read_be().unwrap();
     /// ```
     pub fn read_be<T: MByteBufferRead>(&mut self) -> Result<T> {
-        T::read_from_mbuffer_be(self)
+        self.read_with::<Big, T>()
+    }
+
+    /// Reads a value of type T that implements the [`ByteBufferRead`] trait from the buffer in the byte order `O`.
+    ///
+    /// # Errors & Behaviour
+    /// See [`read_slice`](Self::read_slice).
+    ///
+    /// # Examples
+    /// ```
+    /// use mmap_bytey_byte_buffer::{byte_buffer::MByteBuffer, byte_order::Big};
+    ///
+    /// let mut buffer = MByteBuffer::new().unwrap();
+    /// let value: u32 = 12345;
+    ///
+    /// buffer.write_with::<Big, _>(&value);
+    /// buffer.move_cursor(0);
+    ///
+    /// assert_eq!(buffer.read_with::<Big, u32>().unwrap(), value);
+    /// ```
+    pub fn read_with<O: ByteOrder, T: MByteBufferRead>(&mut self) -> Result<T> {
+        T::read_from_mbuffer_with::<O>(self)
+    }
+
+    /// Writes `instant` as a signed offset from `base` in the byte order `O`.
+    ///
+    /// An [`Instant`] has no meaning outside of the process that created it, so it is written
+    /// relative to a `base` both sides agree on, for example the time a connection was opened.
+    /// The offset uses the same layout as a [`SystemTime`](std::time::SystemTime) and can be
+    /// before `base`.
+    ///
+    /// # Errors & Behaviour
+    /// See [`write_slice`](Self::write_slice).
+    ///
+    /// # Examples
+    /// ```
+    /// use mmap_bytey_byte_buffer::{byte_buffer::MByteBuffer, byte_order::Native};
+    /// use std::time::{Duration, Instant};
+    ///
+    /// let mut buffer = MByteBuffer::new().unwrap();
+    /// let base = Instant::now();
+    /// let later = base + Duration::from_millis(1500);
+    ///
+    /// buffer.write_instant_with::<Native>(later, base).unwrap();
+    /// buffer.move_cursor(0).unwrap();
+    ///
+    /// assert_eq!(buffer.read_instant_with::<Native>(base).unwrap(), later);
+    /// ```
+    pub fn write_instant_with<O: ByteOrder>(
+        &mut self,
+        instant: Instant,
+        base: Instant,
+    ) -> Result<&mut Self> {
+        let (secs, nanos) = match instant.checked_duration_since(base) {
+            Some(after) => signed_offset(false, after)?,
+            None => signed_offset(true, base.duration_since(instant))?,
+        };
+
+        self.write_with::<O, i64>(secs)?.write_with::<O, u32>(nanos)
+    }
+
+    /// Reads an [`Instant`] written by [`write_instant_with`](Self::write_instant_with) relative to `base`.
+    ///
+    /// # Errors & Behaviour
+    /// See [`read_slice`](Self::read_slice). Also fails if the offset can not be applied to `base`.
+    pub fn read_instant_with<O: ByteOrder>(&mut self, base: Instant) -> Result<Instant> {
+        let secs = self.read_with::<O, i64>()?;
+        let nanos = self.read_with::<O, u32>()?;
+
+        base.offset_by(secs, nanos)
+    }
+
+    /// Returns a [`BitWriter`] that packs values of any bit width at the current cursor position.
+    ///
+    /// # Examples
+    /// ```
+    /// use mmap_bytey_byte_buffer::{bits::MsbFirst, byte_buffer::MByteBuffer};
+    ///
+    /// let mut buffer = MByteBuffer::new().unwrap();
+    /// let mut bits = buffer.bit_writer::<MsbFirst>();
+    ///
+    /// bits.write_bits(0b11, 2).unwrap();
+    /// bits.write_bits(0x3ff, 10).unwrap();
+    /// bits.finish().unwrap();
+    ///
+    /// assert_eq!(buffer.length(), 2);
+    /// ```
+    pub fn bit_writer<B: BitOrder>(&mut self) -> BitWriter<'_, B> {
+        BitWriter::new(self)
+    }
+
+    /// Returns a [`BitReader`] that unpacks values of any bit width from the current cursor position.
+    ///
+    /// # Examples
+    /// ```
+    /// use mmap_bytey_byte_buffer::{bits::MsbFirst, byte_buffer::MByteBuffer};
+    ///
+    /// let mut buffer = MByteBuffer::new().unwrap();
+    ///
+    /// buffer.write_slice(&[0b1111_1111, 0b1111_0000]).unwrap();
+    /// buffer.move_cursor(0).unwrap();
+    ///
+    /// let mut bits = buffer.bit_reader::<MsbFirst>();
+    ///
+    /// assert_eq!(bits.read_bits(2).unwrap(), 0b11);
+    /// assert_eq!(bits.read_bits(10).unwrap(), 0x3ff);
+    /// ```
+    pub fn bit_reader<B: BitOrder>(&mut self) -> BitReader<'_, B> {
+        let data = match &self.buffer {
+            Some(buffer) => &buffer.as_ref()[..self.length],
+            None => &[],
+        };
+
+        BitReader::new(data, &mut self.cursor, self.mode)
+    }
+
+    /// Returns a [`MByteBufferReader`] that reads from the current cursor position.
+    ///
+    /// Values read through the reader can borrow from the [`MByteBuffer`], so types such as
+    /// `&str` and `&[u8]` can be read without copying. The reader moves the buffer's cursor.
+    ///
+    /// # Examples
+    /// ```
+    /// use mmap_bytey_byte_buffer::byte_buffer::MByteBuffer;
+    ///
+    /// let mut buffer = MByteBuffer::new().unwrap();
+    ///
+    /// buffer.write("hello").unwrap();
+    /// buffer.write(12345u32).unwrap();
+    /// buffer.move_cursor(0).unwrap();
+    ///
+    /// let mut reader = buffer.reader();
+    /// let text: &str = reader.read().unwrap();
+    /// let value: u32 = reader.read().unwrap();
+    ///
+    /// assert_eq!(text, "hello");
+    /// assert_eq!(value, 12345);
+    /// ```
+    pub fn reader(&mut self) -> MByteBufferReader<'_> {
+        MByteBufferReader {
+            data: match &self.buffer {
+                Some(buffer) => &buffer.as_ref()[..self.length],
+                None => &[],
+            },
+            cursor: &mut self.cursor,
+            mode: self.mode,
+        }
+    }
+
+    /// Reads a value of type T that implements the [`MByteBufferReadRef`] trait from the buffer.
+    ///
+    /// The returned value may borrow from the buffer, which stays borrowed for as long as the value lives.
+    ///
+    /// # Errors & Behaviour
+    /// See [`read_slice`](Self::read_slice).
+    ///
+    /// # Examples
+    /// ```
+    /// use mmap_bytey_byte_buffer::byte_buffer::MByteBuffer;
+    ///
+    /// let mut buffer = MByteBuffer::new().unwrap();
+    ///
+    /// buffer.write("hello").unwrap();
+    /// buffer.move_cursor(0).unwrap();
+    ///
+    /// assert_eq!(buffer.read_ref::<&str>().unwrap(), "hello");
+    /// ```
+    pub fn read_ref<'a, T: MByteBufferReadRef<'a>>(&'a mut self) -> Result<T> {
+        self.read_ref_with::<Native, T>()
+    }
+
+    /// Reads a value of type T that implements the [`MByteBufferReadRef`] trait from the buffer in **little endian** ordering.
+    ///
+    /// # Errors & Behaviour
+    /// See [`read_slice`](Self::read_slice).
+    ///
+    /// # Examples
+    /// ```
+    /// use mmap_bytey_byte_buffer::byte_buffer::MByteBuffer;
+    ///
+    /// let mut buffer = MByteBuffer::new().unwrap();
+    ///
+    /// buffer.write_le("hello").unwrap();
+    /// buffer.move_cursor(0).unwrap();
+    ///
+    /// assert_eq!(buffer.read_ref_le::<&str>().unwrap(), "hello");
+    /// ```
+    pub fn read_ref_le<'a, T: MByteBufferReadRef<'a>>(&'a mut self) -> Result<T> {
+        self.read_ref_with::<Little, T>()
+    }
+
+    /// Reads a value of type T that implements the [`MByteBufferReadRef`] trait from the buffer in **big endian** ordering.
+    ///
+    /// # Errors & Behaviour
+    /// See [`read_slice`](Self::read_slice).
+    ///
+    /// # Examples
+    /// ```
+    /// use mmap_bytey_byte_buffer::byte_buffer::MByteBuffer;
+    ///
+    /// let mut buffer = MByteBuffer::new().unwrap();
+    ///
+    /// buffer.write_be("hello").unwrap();
+    /// buffer.move_cursor(0).unwrap();
+    ///
+    /// assert_eq!(buffer.read_ref_be::<&str>().unwrap(), "hello");
+    /// ```
+    pub fn read_ref_be<'a, T: MByteBufferReadRef<'a>>(&'a mut self) -> Result<T> {
+        self.read_ref_with::<Big, T>()
+    }
+
+    /// Reads a value of type T that implements the [`MByteBufferReadRef`] trait from the buffer in the byte order `O`.
+    ///
+    /// # Errors & Behaviour
+    /// See [`read_slice`](Self::read_slice).
+    pub fn read_ref_with<'a, O: ByteOrder, T: MByteBufferReadRef<'a>>(&'a mut self) -> Result<T> {
+        T::read_ref_from_mbuffer_with::<O>(&mut self.reader())
     }
 
     /// Moves the current cursor position **without safety checks**.
@@ -487,7 +894,8 @@ impl MByteBuffer {
 
     /// Returns the capacity of the [`MByteBuffer`].
     ///
-    /// The capacity of the buffer is the size of the heap allocation used to store data.
+    /// The capacity of the buffer is the size of the heap allocation used to store data, and
+    /// `usize::MAX` for a [counter](Self::counter).
     ///
     /// # Examples
     /// ```
@@ -498,7 +906,29 @@ impl MByteBuffer {
     /// println!("{}", buffer.capacity());
     /// ```
     pub fn capacity(&self) -> usize {
-        BUFFER_SIZE
+        match self.buffer {
+            Some(_) => BUFFER_SIZE,
+            None => usize::MAX,
+        }
+    }
+
+    /// Returns how many more bytes can be written from the current cursor position.
+    ///
+    /// Together with [`MByteBufferWrite::encoded_len`] this tells whether a value still fits.
+    ///
+    /// # Examples
+    /// ```
+    /// use mmap_bytey_byte_buffer::{byte_buffer::MByteBuffer, byte_buffer_write::MByteBufferWrite};
+    ///
+    /// let mut buffer = MByteBuffer::new().unwrap();
+    /// let message = String::from("hello");
+    ///
+    /// if message.encoded_len().unwrap() <= buffer.remaining_capacity() {
+    ///     buffer.write(&message).unwrap();
+    /// }
+    /// ```
+    pub fn remaining_capacity(&self) -> usize {
+        self.capacity() - self.cursor
     }
 
     /// Returns the current cursor position of the [`MByteBuffer`].
@@ -524,9 +954,12 @@ impl MByteBuffer {
     /// This method is unsafe due to the unsafe nature of pointers itself.
     ///
     /// This method can result in undefined behaviour if the buffer is resized and the underlying heap allocator moves
-    /// the pointer
+    /// the pointer. The pointer of a [counter](Self::counter) is null.
     pub unsafe fn pointer(&self) -> *const u8 {
-        self.buffer.as_ptr()
+        match &self.buffer {
+            Some(buffer) => buffer.as_ptr(),
+            None => ptr::null(),
+        }
     }
 
     /// Returns a mutable pointer to the allocation.
@@ -535,9 +968,12 @@ impl MByteBuffer {
     /// This method is unsafe due to the unsafe nature of pointers itself.
     ///
     /// This method can result in undefined behaviour if the buffer is resized and the underlying heap allocator moves
-    /// the pointer
+    /// the pointer. The pointer of a [counter](Self::counter) is null.
     pub unsafe fn mut_pointer(&mut self) -> *mut u8 {
-        self.buffer.as_mut_ptr()
+        match &mut self.buffer {
+            Some(buffer) => buffer.as_mut_ptr(),
+            None => ptr::null_mut(),
+        }
     }
 
     /// Returns true if the length is 0
@@ -571,6 +1007,9 @@ impl MByteBuffer {
     #[inline]
     pub fn read_to_buffer(&mut self, len: usize) -> Result<Self> {
         let mut buffer = MByteBuffer::new()?;
+
+        buffer.mode = self.mode;
+
         let bytes = self.read_slice(len)?;
         buffer.write_slice(bytes)?;
         buffer.cursor = 0;
@@ -596,6 +1035,11 @@ impl MByteBuffer {
     /// ```
     pub fn as_slice(&mut self) -> &[u8] {
         self.cursor = 0;
+
+        if self.buffer.is_none() {
+            return &[];
+        }
+
         unsafe { self.read_slice_unchecked(self.length()) }
     }
 
@@ -620,27 +1064,61 @@ impl MByteBuffer {
     /// assert_eq!(slice.len(), 4);
     /// ```
     pub fn slice_from(&mut self, cursor: usize, size: usize) -> Result<&[u8]> {
-        if cursor + size > self.length {
-            return Err(MByteBufferError::ReadOutOfBounds {
-                length: self.length,
-                start: cursor,
-                end: cursor + size,
-            });
+        let length = self.data().len();
+
+        match cursor.checked_add(size) {
+            Some(end) if end <= length => {}
+            _ => {
+                return Err(MByteBufferError::ReadOutOfBounds {
+                    length,
+                    start: cursor,
+                    end: cursor.saturating_add(size),
+                })
+            }
         }
 
         self.cursor = cursor;
         Ok(unsafe { self.read_slice_unchecked(size) })
     }
 
+    /// Returns how strictly values are validated while they are read.
+    pub fn decode_mode(&self) -> DecodeMode {
+        self.mode
+    }
+
+    /// Sets how strictly values are validated while they are read. See [`DecodeMode`].
+    ///
+    /// # Examples
+    /// ```
+    /// use mmap_bytey_byte_buffer::{byte_buffer::MByteBuffer, decode_mode::DecodeMode};
+    ///
+    /// let mut buffer = MByteBuffer::new().unwrap();
+    ///
+    /// buffer.set_decode_mode(DecodeMode::Lenient);
+    ///
+    /// assert_eq!(buffer.decode_mode(), DecodeMode::Lenient);
+    /// ```
+    pub fn set_decode_mode(&mut self, mode: DecodeMode) -> &mut Self {
+        self.mode = mode;
+        self
+    }
+
     /// A Panicless Clone that returns a Error instead.
     pub fn try_clone(&self) -> Result<Self> {
-        let mut buffer = Buffer::new()?;
+        let buffer = match &self.buffer {
+            Some(source) => {
+                let mut buffer = Buffer::new()?;
 
-        buffer[..].copy_from_slice(&self.buffer[..]);
+                buffer[..].copy_from_slice(&source[..]);
+                Some(buffer)
+            }
+            None => None,
+        };
 
         Ok(Self {
             length: self.length,
             cursor: self.cursor,
+            mode: self.mode,
             buffer,
         })
     }
@@ -651,6 +1129,7 @@ impl Clone for MByteBuffer {
         Self {
             length: self.length,
             cursor: self.cursor,
+            mode: self.mode,
             buffer: self.buffer.clone(),
         }
     }
@@ -658,6 +1137,114 @@ impl Clone for MByteBuffer {
     fn clone_from(&mut self, source: &Self) {
         self.length = source.length;
         self.cursor = source.cursor;
-        self.buffer.copy_from_slice(&source.buffer[..]);
+        self.mode = source.mode;
+
+        match (&mut self.buffer, &source.buffer) {
+            (Some(buffer), Some(source)) => buffer.copy_from_slice(&source[..]),
+            _ => self.buffer = source.buffer.clone(),
+        }
+    }
+}
+
+/// A reader that borrows the data of a [`MByteBuffer`].
+///
+/// Created by [`MByteBuffer::reader`]. Data read through it lives as long as the borrow of the
+/// buffer, which allows [`MByteBufferReadRef`] types to reference the buffer instead of copying.
+/// Reading moves the cursor of the underlying [`MByteBuffer`].
+#[derive(Debug)]
+pub struct MByteBufferReader<'a> {
+    data: &'a [u8],
+    cursor: &'a mut usize,
+    mode: DecodeMode,
+}
+
+impl<'a> MByteBufferReader<'a> {
+    /// Reads a slice of type [u8] of the given size that borrows from the [`MByteBuffer`].
+    ///
+    /// # Behaviour
+    /// The current cursor position will be increased by the given size.
+    ///
+    /// # Errors
+    /// - [`MByteBufferError::ReadOutOfBounds`] is returned if the result of the current cursor position + the given size exceeds the buffer's length
+    pub fn read_slice(&mut self, size: usize) -> Result<&'a [u8]> {
+        let start = *self.cursor;
+
+        match start
+            .checked_add(size)
+            .and_then(|end| self.data.get(start..end))
+        {
+            Some(bytes) => {
+                *self.cursor += size;
+                Ok(bytes)
+            }
+            None => Err(MByteBufferError::ReadOutOfBounds {
+                length: self.data.len(),
+                start,
+                end: start.saturating_add(size),
+            }),
+        }
+    }
+
+    /// Moves the current cursor position forward by `count` bytes without reading them.
+    ///
+    /// # Errors
+    /// - [`MByteBufferError::ReadOutOfBounds`] is returned if the result of the current cursor position + `count` exceeds the buffer's length
+    pub fn skip(&mut self, count: usize) -> Result<&mut Self> {
+        self.read_slice(count)?;
+
+        Ok(self)
+    }
+
+    /// Moves the current cursor position forward to the next multiple of `alignment`.
+    ///
+    /// # Errors
+    /// See [`skip`](Self::skip).
+    pub fn align_to(&mut self, alignment: usize) -> Result<&mut Self> {
+        self.skip(padding_to(*self.cursor, alignment))
+    }
+
+    /// Reads a value of type T that implements the [`MByteBufferReadRef`] trait.
+    pub fn read<T: MByteBufferReadRef<'a>>(&mut self) -> Result<T> {
+        self.read_with::<Native, T>()
+    }
+
+    /// Reads a value of type T that implements the [`MByteBufferReadRef`] trait in **little endian** ordering.
+    pub fn read_le<T: MByteBufferReadRef<'a>>(&mut self) -> Result<T> {
+        self.read_with::<Little, T>()
+    }
+
+    /// Reads a value of type T that implements the [`MByteBufferReadRef`] trait in **big endian** ordering.
+    pub fn read_be<T: MByteBufferReadRef<'a>>(&mut self) -> Result<T> {
+        self.read_with::<Big, T>()
+    }
+
+    /// Reads a value of type T that implements the [`MByteBufferReadRef`] trait in the byte order `O`.
+    pub fn read_with<O: ByteOrder, T: MByteBufferReadRef<'a>>(&mut self) -> Result<T> {
+        T::read_ref_from_mbuffer_with::<O>(self)
+    }
+
+    /// Returns a [`BitReader`] that unpacks values of any bit width from the current cursor position.
+    pub fn bit_reader<B: BitOrder>(&mut self) -> BitReader<'_, B> {
+        BitReader::new(self.data, self.cursor, self.mode)
+    }
+
+    /// Returns the current cursor position of the underlying [`MByteBuffer`].
+    pub fn cursor(&self) -> usize {
+        *self.cursor
+    }
+
+    /// Returns the [`DecodeMode`] of the underlying [`MByteBuffer`].
+    pub fn decode_mode(&self) -> DecodeMode {
+        self.mode
+    }
+
+    /// Returns the length of the underlying [`MByteBuffer`].
+    pub fn length(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns the bytes from the cursor to the end without moving the cursor.
+    pub(crate) fn remaining(&self) -> &'a [u8] {
+        &self.data[(*self.cursor).min(self.data.len())..]
     }
 }
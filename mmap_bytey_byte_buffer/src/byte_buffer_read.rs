@@ -1,4 +1,9 @@
-use crate::{byte_buffer::MByteBuffer, error::Result};
+use crate::{
+    byte_buffer::MByteBuffer,
+    byte_order::{Big, ByteOrder, Endianness, Little, Native},
+    error::Result,
+};
+use std::mem::{self, MaybeUninit};
 
 mod arrays;
 mod bound;
@@ -6,41 +11,187 @@ mod boxed;
 mod byte;
 mod cell;
 mod char;
+mod cmp;
+mod collections;
 mod cow;
+mod ffi;
+mod net;
 mod num;
 mod option;
+mod path;
 mod phantom;
+mod quantized;
+mod range;
 mod result;
 mod string;
-mod time;
+mod string_encoding;
+pub(crate) mod time;
 mod tuple;
+mod varint;
 
+#[cfg(feature = "chrono")]
+mod chrono;
+#[cfg(feature = "glam")]
+mod glam;
+#[cfg(feature = "mint")]
+mod mint;
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
+#[cfg(feature = "time")]
+mod offset_date_time;
+#[cfg(feature = "uuid")]
+mod uuid;
+
+/// Reads a value back out of a [`MByteBuffer`].
+///
+/// Implementors should only override [`read_from_mbuffer_with`](Self::read_from_mbuffer_with), which is
+/// generic over the [`ByteOrder`]. The three `read_from_mbuffer*` methods are kept so older
+/// implementations that override all three of them instead keep working.
+/// At least one of the two sets has to be overridden, else the defaults call each other forever.
 pub trait MByteBufferRead {
+    #[inline]
+    fn read_from_mbuffer_with<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<Self>
+    where
+        Self: Sized,
+    {
+        match O::ENDIANNESS {
+            Endianness::Native => Self::read_from_mbuffer(buffer),
+            Endianness::Little => Self::read_from_mbuffer_le(buffer),
+            Endianness::Big => Self::read_from_mbuffer_be(buffer),
+        }
+    }
+
+    #[inline]
     fn read_from_mbuffer(buffer: &mut MByteBuffer) -> Result<Self>
     where
-        Self: Sized;
+        Self: Sized,
+    {
+        Self::read_from_mbuffer_with::<Native>(buffer)
+    }
+
+    #[inline]
     fn read_from_mbuffer_le(buffer: &mut MByteBuffer) -> Result<Self>
     where
-        Self: Sized;
+        Self: Sized,
+    {
+        Self::read_from_mbuffer_with::<Little>(buffer)
+    }
+
+    #[inline]
     fn read_from_mbuffer_be(buffer: &mut MByteBuffer) -> Result<Self>
     where
-        Self: Sized;
+        Self: Sized,
+    {
+        Self::read_from_mbuffer_with::<Big>(buffer)
+    }
+
+    /// Reads one element for every slot of `out`, without a length prefix.
+    /// On success every slot is initialized, on failure none are.
+    /// Primitive numbers override this to copy the whole slice at once.
+    #[inline]
+    fn read_slice_from_mbuffer_with<O: ByteOrder>(
+        buffer: &mut MByteBuffer,
+        out: &mut [MaybeUninit<Self>],
+    ) -> Result<()>
+    where
+        Self: Sized,
+    {
+        read_each(out, || buffer.read_with::<O, Self>())
+    }
+}
+
+//The Guard is thanks to bincode's array read implementation. it was designed based on their work.
+//If we find any major bugs that could Affect them we should let them know and give them any fixes.
+struct Guard<'a, T> {
+    slice_mut: &'a mut [MaybeUninit<T>],
+    initialized: usize,
+}
+
+impl<T> Drop for Guard<'_, T> {
+    fn drop(&mut self) {
+        debug_assert!(self.initialized <= self.slice_mut.len());
+
+        // SAFETY: this slice will contain only initialized objects.
+        unsafe {
+            std::ptr::drop_in_place(
+                &mut *(self.slice_mut.get_unchecked_mut(..self.initialized)
+                    as *mut [MaybeUninit<T>] as *mut [T]),
+            );
+        }
+    }
+}
+
+/// Fills `out` one element at a time, dropping the already read elements if one of them fails.
+#[inline]
+fn read_each<T>(out: &mut [MaybeUninit<T>], mut read: impl FnMut() -> Result<T>) -> Result<()> {
+    let mut guard = Guard {
+        slice_mut: out,
+        initialized: 0,
+    };
+
+    while guard.initialized < guard.slice_mut.len() {
+        let item = read().map_err(|e| e.at_index(guard.initialized))?;
+
+        // SAFETY: `guard.initialized` starts at 0, is increased by one in the
+        // loop and the loop is aborted once it reaches `slice_mut.len()`.
+        unsafe {
+            guard
+                .slice_mut
+                .get_unchecked_mut(guard.initialized)
+                .write(item);
+        }
+        guard.initialized += 1;
+    }
+
+    mem::forget(guard);
+    Ok(())
+}
+
+/// Copies numbers straight out of the buffer into `out`, reversing the bytes of each element if `swap` is set.
+///
+/// # Safety
+/// `T` must be a primitive number, valid for any bit pattern.
+#[inline]
+pub(crate) unsafe fn read_numbers<T>(
+    buffer: &mut MByteBuffer,
+    out: &mut [MaybeUninit<T>],
+    swap: bool,
+) -> Result<()> {
+    let size = mem::size_of_val(out);
+    let source = buffer.read_slice(size)?;
+
+    std::ptr::copy_nonoverlapping(source.as_ptr(), out.as_mut_ptr() as *mut u8, size);
+
+    if swap && mem::size_of::<T>() > 1 {
+        // SAFETY: every byte of `out` was initialized by the copy above.
+        let bytes = std::slice::from_raw_parts_mut(out.as_mut_ptr() as *mut u8, size);
+
+        for chunk in bytes.chunks_exact_mut(mem::size_of::<T>()) {
+            chunk.reverse();
+        }
+    }
+
+    Ok(())
 }
 
 macro_rules! impl_byte_buffer_read_types {
     ($($type:ty),*) => {
         $(
             impl MByteBufferRead for $type {
-                fn read_from_mbuffer(buffer: &mut MByteBuffer) -> Result<$type> {
-                    Ok(<$type>::from_ne_bytes(buffer.read_slice(std::mem::size_of::<$type>())?.try_into().expect("This really shouldn't fail")))
-                }
+                #[inline]
+                fn read_from_mbuffer_with<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<$type> {
+                    let mut bytes: [u8; std::mem::size_of::<$type>()] = buffer.read_slice(std::mem::size_of::<$type>())?.try_into().expect("This really shouldn't fail");
+
+                    if O::SWAP {
+                        bytes.reverse();
+                    }
 
-                fn read_from_mbuffer_le(buffer: &mut MByteBuffer) -> Result<$type> {
-                    Ok(<$type>::from_le_bytes(buffer.read_slice(std::mem::size_of::<$type>())?.try_into().expect("This really shouldn't fail")))
+                    Ok(<$type>::from_ne_bytes(bytes))
                 }
 
-                fn read_from_mbuffer_be(buffer: &mut MByteBuffer) -> Result<$type> {
-                    Ok(<$type>::from_be_bytes(buffer.read_slice(std::mem::size_of::<$type>())?.try_into().expect("This really shouldn't fail")))
+                #[inline]
+                fn read_slice_from_mbuffer_with<O: ByteOrder>(buffer: &mut MByteBuffer, out: &mut [MaybeUninit<$type>]) -> Result<()> {
+                    unsafe { read_numbers(buffer, out, O::SWAP) }
                 }
             }
         )*
@@ -1,106 +1,21 @@
 use crate::{
     byte_buffer::MByteBuffer,
     byte_buffer_read::MByteBufferRead,
+    byte_order::ByteOrder,
     error::{MByteBufferError, Result},
 };
-use std::{
-    any::TypeId,
-    mem::{self, MaybeUninit},
-};
-
-//The Array read implementation is thanks to bincode. it was designed based on their work.
-//If we find any major bugs that could Affect them we should let them know and give them any fixes.
-struct Guard<'a, T, const N: usize> {
-    array_mut: &'a mut [MaybeUninit<T>; N],
-    initialized: usize,
-}
-
-impl<T, const N: usize> Drop for Guard<'_, T, N> {
-    fn drop(&mut self) {
-        debug_assert!(self.initialized <= N);
-
-        // SAFETY: this slice will contain only initialized objects.
-        unsafe {
-            std::ptr::drop_in_place(
-                &mut *(self.array_mut.get_unchecked_mut(..self.initialized)
-                    as *mut [MaybeUninit<T>] as *mut [T]),
-            );
-        }
-    }
-}
-
-impl<T: MByteBufferRead + 'static, const N: usize> MByteBufferRead for [T; N] {
-    #[inline]
-    fn read_from_mbuffer(buffer: &mut MByteBuffer) -> Result<Self> {
-        if N == 0 {
-            return Err(MByteBufferError::OtherError {
-                error: "Can not read to an [T;0]. The array must have a size.".to_owned(),
-            });
-        }
-
-        let size = buffer.read::<u64>()? as usize;
-
-        if size != N {
-            return Err(MByteBufferError::OtherError {
-                error: format!(
-                    "Invalid size in buffer for [T; {}]. Should be [T; {}]?",
-                    N, size
-                ),
-            });
-        }
-
-        if TypeId::of::<u8>() == TypeId::of::<T>() {
-            let mut buf = [0u8; N];
-            buf[..N].copy_from_slice(buffer.read_slice(N)?);
-            let ptr = &mut buf as *mut _ as *mut [T; N];
-
-            Ok(unsafe { ptr.read() })
-        } else {
-            let mut array = unsafe { MaybeUninit::<[MaybeUninit<T>; N]>::uninit().assume_init() };
-            let mut guard = Guard {
-                array_mut: &mut array,
-                initialized: 0,
-            };
-
-            for _ in 0..N {
-                let item = buffer.read::<T>()?;
-
-                // SAFETY: `guard.initialized` starts at 0, is increased by one in the
-                // loop and the loop is aborted once it reaches N (which is
-                // `array.len()`).
-                unsafe {
-                    guard
-                        .array_mut
-                        .get_unchecked_mut(guard.initialized)
-                        .write(item);
-                }
-                guard.initialized += 1;
-            }
-
-            if guard.initialized == N {
-                mem::forget(guard);
-
-                // SAFETY: the condition above asserts that all elements are
-                // initialized.
-                let out = unsafe { (&array as *const _ as *const [T; N]).read() };
-                Ok(out)
-            } else {
-                Err(MByteBufferError::OtherError {
-                    error: "Not all of the array was initialized".to_owned(),
-                })
-            }
-        }
-    }
+use std::mem::MaybeUninit;
 
+impl<T: MByteBufferRead, const N: usize> MByteBufferRead for [T; N] {
     #[inline]
-    fn read_from_mbuffer_le(buffer: &mut MByteBuffer) -> Result<Self> {
+    fn read_from_mbuffer_with<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<Self> {
         if N == 0 {
             return Err(MByteBufferError::OtherError {
                 error: "Can not read to an [T;0]. The array must have a size.".to_owned(),
             });
         }
 
-        let size = buffer.read_le::<u64>()? as usize;
+        let size = buffer.read_with::<O, u64>()? as usize;
 
         if size != N {
             return Err(MByteBufferError::OtherError {
@@ -111,161 +26,62 @@ impl<T: MByteBufferRead + 'static, const N: usize> MByteBufferRead for [T; N] {
             });
         }
 
-        if TypeId::of::<u8>() == TypeId::of::<T>() {
-            let mut buf = [0u8; N];
-            buf[..N].copy_from_slice(buffer.read_slice(N)?);
-            let ptr = &mut buf as *mut _ as *mut [T; N];
-
-            Ok(unsafe { ptr.read() })
-        } else {
-            let mut array = unsafe { MaybeUninit::<[MaybeUninit<T>; N]>::uninit().assume_init() };
-            let mut guard = Guard {
-                array_mut: &mut array,
-                initialized: 0,
-            };
-
-            for _ in 0..N {
-                let item = buffer.read_le::<T>()?;
+        let mut array = unsafe { MaybeUninit::<[MaybeUninit<T>; N]>::uninit().assume_init() };
 
-                // SAFETY: `guard.initialized` starts at 0, is increased by one in the
-                // loop and the loop is aborted once it reaches N (which is
-                // `array.len()`).
-                unsafe {
-                    guard
-                        .array_mut
-                        .get_unchecked_mut(guard.initialized)
-                        .write(item);
-                }
-                guard.initialized += 1;
-            }
-
-            if guard.initialized == N {
-                mem::forget(guard);
+        T::read_slice_from_mbuffer_with::<O>(buffer, &mut array)?;
 
-                // SAFETY: the condition above asserts that all elements are
-                // initialized.
-                let out = unsafe { (&array as *const _ as *const [T; N]).read() };
-                Ok(out)
-            } else {
-                Err(MByteBufferError::OtherError {
-                    error: "Not all of the array was initialized".to_owned(),
-                })
-            }
-        }
-    }
-
-    #[inline]
-    fn read_from_mbuffer_be(buffer: &mut MByteBuffer) -> Result<Self> {
-        if N == 0 {
-            return Err(MByteBufferError::OtherError {
-                error: "Can not read to an [T;0]. The array must have a size.".to_owned(),
-            });
-        }
-
-        let size = buffer.read_be::<u64>()? as usize;
-
-        if size != N {
-            return Err(MByteBufferError::OtherError {
-                error: format!(
-                    "Invalid size in buffer for [T; {}]. Should be [T; {}]?",
-                    N, size
-                ),
-            });
-        }
-
-        if TypeId::of::<u8>() == TypeId::of::<T>() {
-            let mut buf = [0u8; N];
-            buf[..N].copy_from_slice(buffer.read_slice(N)?);
-            let ptr = &mut buf as *mut _ as *mut [T; N];
-
-            Ok(unsafe { ptr.read() })
-        } else {
-            let mut array = unsafe { MaybeUninit::<[MaybeUninit<T>; N]>::uninit().assume_init() };
-            let mut guard = Guard {
-                array_mut: &mut array,
-                initialized: 0,
-            };
-
-            for _ in 0..N {
-                let item = buffer.read_be::<T>()?;
-
-                // SAFETY: `guard.initialized` starts at 0, is increased by one in the
-                // loop and the loop is aborted once it reaches N (which is
-                // `array.len()`).
-                unsafe {
-                    guard
-                        .array_mut
-                        .get_unchecked_mut(guard.initialized)
-                        .write(item);
-                }
-                guard.initialized += 1;
-            }
-
-            if guard.initialized == N {
-                mem::forget(guard);
-
-                // SAFETY: the condition above asserts that all elements are
-                // initialized.
-                let out = unsafe { (&array as *const _ as *const [T; N]).read() };
-                Ok(out)
-            } else {
-                Err(MByteBufferError::OtherError {
-                    error: "Not all of the array was initialized".to_owned(),
-                })
-            }
-        }
+        // SAFETY: read_slice_from_mbuffer_with only returns Ok once every element is initialized.
+        Ok(unsafe { (&array as *const _ as *const [T; N]).read() })
     }
 }
 
 impl<T: MByteBufferRead> MByteBufferRead for Vec<T> {
     #[inline]
-    fn read_from_mbuffer(buffer: &mut MByteBuffer) -> Result<Self> {
-        let size = buffer.read::<u64>()? as usize;
+    fn read_from_mbuffer_with<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<Self> {
+        let size = buffer.read_with::<O, u64>()? as usize;
 
         if size == 0 {
             return Ok(Vec::new());
         }
 
-        let mut vec = Vec::with_capacity(size);
-
-        for _ in 0..size {
-            vec.push(buffer.read::<T>()?);
-        }
+        let left = buffer.length().saturating_sub(buffer.cursor());
 
-        Ok(vec)
-    }
+        // Every element but a zero-sized one takes at least a byte, so a length beyond the bytes
+        // left is either corrupt or made of such elements. Reading those one by one keeps an
+        // untrusted prefix from sizing the allocation.
+        if size > left {
+            let mut vec = Vec::with_capacity(left);
 
-    #[inline]
-    fn read_from_mbuffer_le(buffer: &mut MByteBuffer) -> Result<Self> {
-        let size = buffer.read_le::<u64>()? as usize;
+            for index in 0..size {
+                vec.push(buffer.read_with::<O, T>().map_err(|e| e.at_index(index))?);
+            }
 
-        if size == 0 {
-            return Ok(Vec::new());
+            return Ok(vec);
         }
 
         let mut vec = Vec::with_capacity(size);
 
-        for _ in 0..size {
-            vec.push(buffer.read_le::<T>()?);
+        T::read_slice_from_mbuffer_with::<O>(buffer, &mut vec.spare_capacity_mut()[..size])?;
+
+        // SAFETY: read_slice_from_mbuffer_with only returns Ok once the first `size` elements are initialized.
+        unsafe {
+            vec.set_len(size);
         }
 
         Ok(vec)
     }
+}
 
-    #[inline]
-    fn read_from_mbuffer_be(buffer: &mut MByteBuffer) -> Result<Self> {
-        let size = buffer.read_be::<u64>()? as usize;
-
-        if size == 0 {
-            return Ok(Vec::new());
-        }
-
-        let mut vec = Vec::with_capacity(size);
+/// Reads `N` values written without a length prefix, for types made of a fixed number of components.
+#[cfg_attr(not(feature = "glam"), allow(dead_code))]
+#[inline]
+pub(crate) fn read_components<O: ByteOrder, T: MByteBufferRead + Copy, const N: usize>(
+    buffer: &mut MByteBuffer,
+) -> Result<[T; N]> {
+    let mut components = [MaybeUninit::<T>::uninit(); N];
 
-        for _ in 0..size {
-            vec.push(buffer.read_be::<T>()?);
-        }
+    T::read_slice_from_mbuffer_with::<O>(buffer, &mut components)?;
 
-        Ok(vec)
-    }
+    // SAFETY: read_slice_from_mbuffer_with only returns Ok once every element is initialized.
+    Ok(components.map(|component| unsafe { component.assume_init() }))
 }
@@ -1,43 +1,21 @@
 use crate::{
     byte_buffer::MByteBuffer,
     byte_buffer_read::MByteBufferRead,
+    byte_order::ByteOrder,
     error::{MByteBufferError, Result},
 };
 use std::ops::Bound;
 
 impl<T: MByteBufferRead> MByteBufferRead for Bound<T> {
     #[inline]
-    fn read_from_mbuffer(buffer: &mut MByteBuffer) -> Result<Self> {
-        match buffer.read::<u8>()? {
+    fn read_from_mbuffer_with<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<Self> {
+        match buffer.read_with::<O, u8>()? {
             0 => Ok(Bound::Unbounded),
-            1 => Ok(Bound::Included(buffer.read::<T>()?)),
-            2 => Ok(Bound::Excluded(buffer.read::<T>()?)),
-            _ => Err(MByteBufferError::OtherError {
-                error: "Invalid Read to Bound".to_owned(),
-            }),
-        }
-    }
-
-    #[inline]
-    fn read_from_mbuffer_le(buffer: &mut MByteBuffer) -> Result<Self> {
-        match buffer.read_le::<u8>()? {
-            0 => Ok(Bound::Unbounded),
-            1 => Ok(Bound::Included(buffer.read_le::<T>()?)),
-            2 => Ok(Bound::Excluded(buffer.read_le::<T>()?)),
-            _ => Err(MByteBufferError::OtherError {
-                error: "Invalid Read to Bound".to_owned(),
-            }),
-        }
-    }
-
-    #[inline]
-    fn read_from_mbuffer_be(buffer: &mut MByteBuffer) -> Result<Self> {
-        match buffer.read_be::<u8>()? {
-            0 => Ok(Bound::Unbounded),
-            1 => Ok(Bound::Included(buffer.read_be::<T>()?)),
-            2 => Ok(Bound::Excluded(buffer.read_be::<T>()?)),
-            _ => Err(MByteBufferError::OtherError {
-                error: "Invalid Read to Bound".to_owned(),
+            1 => Ok(Bound::Included(buffer.read_with::<O, T>()?)),
+            2 => Ok(Bound::Excluded(buffer.read_with::<O, T>()?)),
+            tag => Err(MByteBufferError::InvalidTag {
+                tag: u64::from(tag),
+                type_name: core::any::type_name::<Self>(),
             }),
         }
     }
@@ -1,18 +1,50 @@
-use crate::{byte_buffer::MByteBuffer, byte_buffer_read::MByteBufferRead, error::Result};
+use crate::{
+    byte_buffer::MByteBuffer, byte_buffer_read::MByteBufferRead, byte_order::ByteOrder,
+    error::Result,
+};
+use std::{rc::Rc, sync::Arc};
 
 impl<T: MByteBufferRead> MByteBufferRead for Box<T> {
     #[inline]
-    fn read_from_mbuffer(buffer: &mut MByteBuffer) -> Result<Box<T>> {
-        Ok(Box::new(buffer.read::<T>()?))
+    fn read_from_mbuffer_with<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<Box<T>> {
+        Ok(Box::new(buffer.read_with::<O, T>()?))
     }
+}
 
+impl<T: MByteBufferRead> MByteBufferRead for Rc<T> {
     #[inline]
-    fn read_from_mbuffer_le(buffer: &mut MByteBuffer) -> Result<Box<T>> {
-        Ok(Box::new(buffer.read_le::<T>()?))
+    fn read_from_mbuffer_with<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<Rc<T>> {
+        Ok(Rc::new(buffer.read_with::<O, T>()?))
     }
+}
 
+impl<T: MByteBufferRead> MByteBufferRead for Arc<T> {
     #[inline]
-    fn read_from_mbuffer_be(buffer: &mut MByteBuffer) -> Result<Box<T>> {
-        Ok(Box::new(buffer.read_be::<T>()?))
+    fn read_from_mbuffer_with<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<Arc<T>> {
+        Ok(Arc::new(buffer.read_with::<O, T>()?))
     }
 }
+
+/// Implements the trait for the unsized pointee types by reading the owned form they are
+/// written as and converting it with `From`.
+macro_rules! impl_unsized_read {
+    ($($pointer:ident),*) => {
+        $(
+            impl MByteBufferRead for $pointer<str> {
+                #[inline]
+                fn read_from_mbuffer_with<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<Self> {
+                    Ok(buffer.read_with::<O, String>()?.into())
+                }
+            }
+
+            impl<T: MByteBufferRead> MByteBufferRead for $pointer<[T]> {
+                #[inline]
+                fn read_from_mbuffer_with<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<Self> {
+                    Ok(buffer.read_with::<O, Vec<T>>()?.into())
+                }
+            }
+        )*
+    };
+}
+
+impl_unsized_read!(Box, Rc, Arc);
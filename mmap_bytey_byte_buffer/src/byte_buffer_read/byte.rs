@@ -1,18 +1,11 @@
-use crate::{byte_buffer::MByteBuffer, byte_buffer_read::MByteBufferRead, error::Result};
+use crate::{
+    byte_buffer::MByteBuffer, byte_buffer_read::MByteBufferRead, byte_order::ByteOrder,
+    error::Result,
+};
 
 impl MByteBufferRead for bool {
     #[inline]
-    fn read_from_mbuffer(buffer: &mut MByteBuffer) -> Result<bool> {
-        Ok(buffer.read::<u8>()? != 0)
-    }
-
-    #[inline]
-    fn read_from_mbuffer_le(buffer: &mut MByteBuffer) -> Result<bool> {
-        Ok(buffer.read::<u8>()? != 0)
-    }
-
-    #[inline]
-    fn read_from_mbuffer_be(buffer: &mut MByteBuffer) -> Result<bool> {
-        Ok(buffer.read::<u8>()? != 0)
+    fn read_from_mbuffer_with<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<bool> {
+        buffer.decode_mode().decode_bool(buffer.read::<u8>()?)
     }
 }
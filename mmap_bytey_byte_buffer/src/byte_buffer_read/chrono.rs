@@ -0,0 +1,30 @@
+use crate::{
+    byte_buffer::MByteBuffer,
+    byte_buffer_read::MByteBufferRead,
+    byte_order::ByteOrder,
+    error::{MByteBufferError, Result},
+};
+use ::chrono::{DateTime, NaiveDate, Utc};
+
+impl MByteBufferRead for DateTime<Utc> {
+    #[inline]
+    fn read_from_mbuffer_with<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<Self> {
+        let secs = buffer.read_with::<O, i64>()?;
+        let nanos = buffer.read_with::<O, u32>()?;
+
+        DateTime::from_timestamp(secs, nanos).ok_or_else(|| MByteBufferError::OtherError {
+            error: format!("Invalid DateTime<Utc>: secs: {} nanos: {}", secs, nanos),
+        })
+    }
+}
+
+impl MByteBufferRead for NaiveDate {
+    #[inline]
+    fn read_from_mbuffer_with<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<Self> {
+        let days = buffer.read_with::<O, i32>()?;
+
+        NaiveDate::from_num_days_from_ce_opt(days).ok_or_else(|| MByteBufferError::OtherError {
+            error: format!("Invalid NaiveDate: days: {}", days),
+        })
+    }
+}
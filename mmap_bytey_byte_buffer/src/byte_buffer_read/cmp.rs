@@ -0,0 +1,29 @@
+use crate::{
+    byte_buffer::MByteBuffer,
+    byte_buffer_read::MByteBufferRead,
+    byte_order::ByteOrder,
+    error::{MByteBufferError, Result},
+};
+use std::cmp::{Ordering, Reverse};
+
+impl MByteBufferRead for Ordering {
+    #[inline]
+    fn read_from_mbuffer_with<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<Self> {
+        match buffer.read_with::<O, i8>()? {
+            -1 => Ok(Ordering::Less),
+            0 => Ok(Ordering::Equal),
+            1 => Ok(Ordering::Greater),
+            tag => Err(MByteBufferError::InvalidTag {
+                tag: tag as u64,
+                type_name: core::any::type_name::<Self>(),
+            }),
+        }
+    }
+}
+
+impl<T: MByteBufferRead> MByteBufferRead for Reverse<T> {
+    #[inline]
+    fn read_from_mbuffer_with<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<Self> {
+        Ok(Reverse(buffer.read_with::<O, T>()?))
+    }
+}
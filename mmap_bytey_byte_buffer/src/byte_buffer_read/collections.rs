@@ -0,0 +1,157 @@
+use crate::{
+    byte_buffer::MByteBuffer,
+    byte_buffer_read::MByteBufferRead,
+    byte_buffer_write::Sorted,
+    byte_order::ByteOrder,
+    error::{MByteBufferError, Result},
+};
+use std::{
+    collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque},
+    hash::{BuildHasher, Hash},
+};
+
+/// Reads the length prefix of a collection and returns it with a capacity hint.
+/// The hint never exceeds the bytes left in the buffer, so a corrupt length can not
+/// make us allocate more than the buffer could ever hold.
+#[inline]
+fn read_len<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<(usize, usize)> {
+    let len = buffer.read_with::<O, u64>()? as usize;
+    let left = buffer.length().saturating_sub(buffer.cursor());
+
+    Ok((len, len.min(left)))
+}
+
+#[inline]
+fn read_entry<O: ByteOrder, K: MByteBufferRead, V: MByteBufferRead>(
+    buffer: &mut MByteBuffer,
+    index: usize,
+) -> Result<(K, V)> {
+    let key = buffer.read_with::<O, K>().map_err(|e| e.at_index(index))?;
+    let value = buffer.read_with::<O, V>().map_err(|e| e.at_index(index))?;
+
+    Ok((key, value))
+}
+
+#[inline]
+fn duplicate_key<T>(index: usize) -> MByteBufferError {
+    MByteBufferError::DuplicateKey {
+        index,
+        type_name: core::any::type_name::<T>(),
+    }
+}
+
+impl<K, V, S> MByteBufferRead for HashMap<K, V, S>
+where
+    K: MByteBufferRead + Eq + Hash,
+    V: MByteBufferRead,
+    S: BuildHasher + Default,
+{
+    #[inline]
+    fn read_from_mbuffer_with<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<Self> {
+        let (len, hint) = read_len::<O>(buffer)?;
+        let mut map = HashMap::with_capacity_and_hasher(hint, S::default());
+
+        for index in 0..len {
+            let (key, value) = read_entry::<O, K, V>(buffer, index)?;
+
+            if map.insert(key, value).is_some() {
+                return Err(duplicate_key::<Self>(index));
+            }
+        }
+
+        Ok(map)
+    }
+}
+
+impl<K: MByteBufferRead + Ord, V: MByteBufferRead> MByteBufferRead for BTreeMap<K, V> {
+    #[inline]
+    fn read_from_mbuffer_with<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<Self> {
+        let (len, _) = read_len::<O>(buffer)?;
+        let mut map = BTreeMap::new();
+
+        for index in 0..len {
+            let (key, value) = read_entry::<O, K, V>(buffer, index)?;
+
+            if map.insert(key, value).is_some() {
+                return Err(duplicate_key::<Self>(index));
+            }
+        }
+
+        Ok(map)
+    }
+}
+
+impl<T, S> MByteBufferRead for HashSet<T, S>
+where
+    T: MByteBufferRead + Eq + Hash,
+    S: BuildHasher + Default,
+{
+    #[inline]
+    fn read_from_mbuffer_with<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<Self> {
+        let (len, hint) = read_len::<O>(buffer)?;
+        let mut set = HashSet::with_capacity_and_hasher(hint, S::default());
+
+        for index in 0..len {
+            let item = buffer.read_with::<O, T>().map_err(|e| e.at_index(index))?;
+
+            if !set.insert(item) {
+                return Err(duplicate_key::<Self>(index));
+            }
+        }
+
+        Ok(set)
+    }
+}
+
+impl<T: MByteBufferRead + Ord> MByteBufferRead for BTreeSet<T> {
+    #[inline]
+    fn read_from_mbuffer_with<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<Self> {
+        let (len, _) = read_len::<O>(buffer)?;
+        let mut set = BTreeSet::new();
+
+        for index in 0..len {
+            let item = buffer.read_with::<O, T>().map_err(|e| e.at_index(index))?;
+
+            if !set.insert(item) {
+                return Err(duplicate_key::<Self>(index));
+            }
+        }
+
+        Ok(set)
+    }
+}
+
+impl<T: MByteBufferRead> MByteBufferRead for VecDeque<T> {
+    #[inline]
+    fn read_from_mbuffer_with<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<Self> {
+        Ok(buffer.read_with::<O, Vec<T>>()?.into())
+    }
+}
+
+impl<T: MByteBufferRead + Ord> MByteBufferRead for BinaryHeap<T> {
+    #[inline]
+    fn read_from_mbuffer_with<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<Self> {
+        Ok(buffer.read_with::<O, Vec<T>>()?.into())
+    }
+}
+
+impl<T: MByteBufferRead> MByteBufferRead for LinkedList<T> {
+    #[inline]
+    fn read_from_mbuffer_with<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<Self> {
+        let (len, _) = read_len::<O>(buffer)?;
+        let mut list = LinkedList::new();
+
+        for index in 0..len {
+            list.push_back(buffer.read_with::<O, T>().map_err(|e| e.at_index(index))?);
+        }
+
+        Ok(list)
+    }
+}
+
+impl<T: MByteBufferRead> MByteBufferRead for Sorted<T> {
+    #[inline]
+    fn read_from_mbuffer_with<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<Self> {
+        Ok(Sorted(buffer.read_with::<O, T>()?))
+    }
+}
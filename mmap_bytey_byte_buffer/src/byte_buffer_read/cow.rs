@@ -1,19 +1,17 @@
-use crate::{byte_buffer::MByteBuffer, byte_buffer_read::MByteBufferRead, error::Result};
+use crate::{
+    byte_buffer::MByteBuffer, byte_buffer_read::MByteBufferRead, byte_order::ByteOrder,
+    error::Result,
+};
 use std::borrow::Cow;
 
-impl<T: MByteBufferRead + ToOwned<Owned = T>> MByteBufferRead for Cow<'_, T> {
+/// Always reads into [`Cow::Owned`]. `Cow<'_, str>` reads a `String` and `Cow<'_, [T]>` a `Vec<T>`.
+/// Use [`MByteBufferReadRef`](crate::byte_buffer_read_ref::MByteBufferReadRef) to borrow instead.
+impl<T: ToOwned + ?Sized> MByteBufferRead for Cow<'_, T>
+where
+    T::Owned: MByteBufferRead,
+{
     #[inline]
-    fn read_from_mbuffer(buffer: &mut MByteBuffer) -> Result<Self> {
-        Ok(Cow::Owned(buffer.read::<T>()?))
-    }
-
-    #[inline]
-    fn read_from_mbuffer_le(buffer: &mut MByteBuffer) -> Result<Self> {
-        Ok(Cow::Owned(buffer.read_le::<T>()?))
-    }
-
-    #[inline]
-    fn read_from_mbuffer_be(buffer: &mut MByteBuffer) -> Result<Self> {
-        Ok(Cow::Owned(buffer.read_be::<T>()?))
+    fn read_from_mbuffer_with<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<Self> {
+        Ok(Cow::Owned(buffer.read_with::<O, T::Owned>()?))
     }
 }
@@ -0,0 +1,31 @@
+use crate::{
+    byte_buffer::MByteBuffer,
+    byte_buffer_read::{string::read_len_prefixed, MByteBufferRead},
+    byte_order::ByteOrder,
+    error::Result,
+};
+use std::ffi::{CString, OsString};
+
+/// Fails with [`NulError`](crate::error::MByteBufferError::NulError) if the bytes contain a nul.
+impl MByteBufferRead for CString {
+    #[inline]
+    fn read_from_mbuffer_with<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<Self> {
+        Ok(CString::new(read_len_prefixed::<O>(buffer)?)?)
+    }
+}
+
+impl MByteBufferRead for OsString {
+    #[cfg(unix)]
+    #[inline]
+    fn read_from_mbuffer_with<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<Self> {
+        use std::os::unix::ffi::OsStrExt;
+
+        Ok(std::ffi::OsStr::from_bytes(read_len_prefixed::<O>(buffer)?).to_owned())
+    }
+
+    #[cfg(not(unix))]
+    #[inline]
+    fn read_from_mbuffer_with<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<Self> {
+        Ok(buffer.read_with::<O, String>()?.into())
+    }
+}
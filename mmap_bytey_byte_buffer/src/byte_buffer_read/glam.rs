@@ -0,0 +1,51 @@
+use crate::{
+    byte_buffer::MByteBuffer,
+    byte_buffer_read::{arrays::read_components, MByteBufferRead},
+    byte_order::ByteOrder,
+    error::Result,
+};
+use ::glam::{
+    DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4, IVec2, IVec3, IVec4, Mat2, Mat3, Mat3A, Mat4,
+    Quat, UVec2, UVec3, UVec4, Vec2, Vec3, Vec3A, Vec4,
+};
+
+/// Implements the trait by reading the components written by the matching write impl.
+macro_rules! impl_glam_read {
+    ($($type:ty: [$scalar:ty; $len:literal] => $from_array:ident($($by_ref:tt)?)),* $(,)?) => {
+        $(
+            impl MByteBufferRead for $type {
+                #[inline]
+                fn read_from_mbuffer_with<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<Self> {
+                    let components = read_components::<O, $scalar, $len>(buffer)?;
+
+                    Ok(<$type>::$from_array($($by_ref)? components))
+                }
+            }
+        )*
+    };
+}
+
+impl_glam_read!(
+    Vec2: [f32; 2] => from_array(),
+    Vec3: [f32; 3] => from_array(),
+    Vec3A: [f32; 3] => from_array(),
+    Vec4: [f32; 4] => from_array(),
+    DVec2: [f64; 2] => from_array(),
+    DVec3: [f64; 3] => from_array(),
+    DVec4: [f64; 4] => from_array(),
+    IVec2: [i32; 2] => from_array(),
+    IVec3: [i32; 3] => from_array(),
+    IVec4: [i32; 4] => from_array(),
+    UVec2: [u32; 2] => from_array(),
+    UVec3: [u32; 3] => from_array(),
+    UVec4: [u32; 4] => from_array(),
+    Quat: [f32; 4] => from_array(),
+    DQuat: [f64; 4] => from_array(),
+    Mat2: [f32; 4] => from_cols_array(&),
+    Mat3: [f32; 9] => from_cols_array(&),
+    Mat3A: [f32; 9] => from_cols_array(&),
+    Mat4: [f32; 16] => from_cols_array(&),
+    DMat2: [f64; 4] => from_cols_array(&),
+    DMat3: [f64; 9] => from_cols_array(&),
+    DMat4: [f64; 16] => from_cols_array(&),
+);
@@ -0,0 +1,39 @@
+use crate::{
+    byte_buffer::MByteBuffer, byte_buffer_read::MByteBufferRead, byte_order::ByteOrder,
+    error::Result,
+};
+use ::mint::{
+    ColumnMatrix2, ColumnMatrix3, ColumnMatrix4, Point2, Point3, Quaternion, RowMatrix2,
+    RowMatrix3, RowMatrix4, Vector2, Vector3, Vector4,
+};
+
+/// Implements the trait by reading the fields in the order the matching write impl wrote them.
+macro_rules! impl_mint_read {
+    ($($type:ident { $($field:ident: $field_type:ty),+ }),* $(,)?) => {
+        $(
+            impl<T: MByteBufferRead> MByteBufferRead for $type<T> {
+                #[inline]
+                fn read_from_mbuffer_with<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<Self> {
+                    Ok($type {
+                        $($field: buffer.read_with::<O, $field_type>()?,)+
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_mint_read!(
+    Vector2 { x: T, y: T },
+    Vector3 { x: T, y: T, z: T },
+    Vector4 { x: T, y: T, z: T, w: T },
+    Point2 { x: T, y: T },
+    Point3 { x: T, y: T, z: T },
+    Quaternion { v: Vector3<T>, s: T },
+    ColumnMatrix2 { x: Vector2<T>, y: Vector2<T> },
+    ColumnMatrix3 { x: Vector3<T>, y: Vector3<T>, z: Vector3<T> },
+    ColumnMatrix4 { x: Vector4<T>, y: Vector4<T>, z: Vector4<T>, w: Vector4<T> },
+    RowMatrix2 { x: Vector2<T>, y: Vector2<T> },
+    RowMatrix3 { x: Vector3<T>, y: Vector3<T>, z: Vector3<T> },
+    RowMatrix4 { x: Vector4<T>, y: Vector4<T>, z: Vector4<T>, w: Vector4<T> },
+);
@@ -0,0 +1,46 @@
+use crate::{
+    byte_buffer::MByteBuffer, byte_buffer_read::MByteBufferRead, byte_order::ByteOrder,
+    error::Result,
+};
+use ::nalgebra::{Point, Quaternion, SMatrix, SVector, Scalar, Unit};
+
+impl<T: Scalar + MByteBufferRead, const R: usize, const C: usize> MByteBufferRead
+    for SMatrix<T, R, C>
+{
+    #[inline]
+    fn read_from_mbuffer_with<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<Self> {
+        let len = R * C;
+        let mut components = Vec::with_capacity(len);
+
+        T::read_slice_from_mbuffer_with::<O>(buffer, &mut components.spare_capacity_mut()[..len])?;
+
+        // SAFETY: read_slice_from_mbuffer_with only returns Ok once the first `len` elements are initialized.
+        unsafe {
+            components.set_len(len);
+        }
+
+        Ok(SMatrix::from_column_slice(&components))
+    }
+}
+
+impl<T: Scalar + MByteBufferRead, const D: usize> MByteBufferRead for Point<T, D> {
+    #[inline]
+    fn read_from_mbuffer_with<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<Self> {
+        Ok(Point::from(buffer.read_with::<O, SVector<T, D>>()?))
+    }
+}
+
+impl<T: Scalar + MByteBufferRead> MByteBufferRead for Quaternion<T> {
+    #[inline]
+    fn read_from_mbuffer_with<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<Self> {
+        Ok(Quaternion::from(buffer.read_with::<O, SVector<T, 4>>()?))
+    }
+}
+
+/// The value is not normalized again, it is trusted to be a unit value as it was written.
+impl<T: MByteBufferRead> MByteBufferRead for Unit<T> {
+    #[inline]
+    fn read_from_mbuffer_with<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<Self> {
+        Ok(Unit::new_unchecked(buffer.read_with::<O, T>()?))
+    }
+}
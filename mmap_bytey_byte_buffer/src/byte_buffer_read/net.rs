@@ -0,0 +1,80 @@
+use crate::{
+    byte_buffer::MByteBuffer,
+    byte_buffer_read::MByteBufferRead,
+    byte_buffer_write::net::{IPV4_TAG, IPV6_TAG},
+    byte_order::{Big, ByteOrder},
+    error::{MByteBufferError, Result},
+};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+
+#[inline]
+fn invalid_tag<T>(tag: u8) -> MByteBufferError {
+    MByteBufferError::InvalidTag {
+        tag: u64::from(tag),
+        type_name: core::any::type_name::<T>(),
+    }
+}
+
+impl MByteBufferRead for Ipv4Addr {
+    #[inline]
+    fn read_from_mbuffer_with<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<Self> {
+        let mut octets = [0u8; 4];
+
+        octets.copy_from_slice(buffer.read_slice(4)?);
+        Ok(Ipv4Addr::from(octets))
+    }
+}
+
+impl MByteBufferRead for Ipv6Addr {
+    #[inline]
+    fn read_from_mbuffer_with<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<Self> {
+        let mut octets = [0u8; 16];
+
+        octets.copy_from_slice(buffer.read_slice(16)?);
+        Ok(Ipv6Addr::from(octets))
+    }
+}
+
+impl MByteBufferRead for IpAddr {
+    #[inline]
+    fn read_from_mbuffer_with<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<Self> {
+        match buffer.read_with::<O, u8>()? {
+            IPV4_TAG => Ok(IpAddr::V4(buffer.read_with::<O, Ipv4Addr>()?)),
+            IPV6_TAG => Ok(IpAddr::V6(buffer.read_with::<O, Ipv6Addr>()?)),
+            tag => Err(invalid_tag::<Self>(tag)),
+        }
+    }
+}
+
+impl MByteBufferRead for SocketAddrV4 {
+    #[inline]
+    fn read_from_mbuffer_with<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<Self> {
+        let ip = buffer.read_with::<O, Ipv4Addr>()?;
+        let port = buffer.read_with::<Big, u16>()?;
+
+        Ok(SocketAddrV4::new(ip, port))
+    }
+}
+
+impl MByteBufferRead for SocketAddrV6 {
+    #[inline]
+    fn read_from_mbuffer_with<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<Self> {
+        let ip = buffer.read_with::<O, Ipv6Addr>()?;
+        let port = buffer.read_with::<Big, u16>()?;
+        let flowinfo = buffer.read_with::<Big, u32>()?;
+        let scope_id = buffer.read_with::<Big, u32>()?;
+
+        Ok(SocketAddrV6::new(ip, port, flowinfo, scope_id))
+    }
+}
+
+impl MByteBufferRead for SocketAddr {
+    #[inline]
+    fn read_from_mbuffer_with<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<Self> {
+        match buffer.read_with::<O, u8>()? {
+            IPV4_TAG => Ok(SocketAddr::V4(buffer.read_with::<O, SocketAddrV4>()?)),
+            IPV6_TAG => Ok(SocketAddr::V6(buffer.read_with::<O, SocketAddrV6>()?)),
+            tag => Err(invalid_tag::<Self>(tag)),
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use crate::{
+    byte_buffer::MByteBuffer,
+    byte_buffer_read::MByteBufferRead,
+    byte_order::ByteOrder,
+    error::{MByteBufferError, Result},
+};
+use ::time::{OffsetDateTime, UtcOffset};
+
+impl MByteBufferRead for OffsetDateTime {
+    #[inline]
+    fn read_from_mbuffer_with<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<Self> {
+        let secs = buffer.read_with::<O, i64>()?;
+        let nanos = buffer.read_with::<O, u32>()?;
+        let offset = buffer.read_with::<O, i32>()?;
+
+        OffsetDateTime::from_unix_timestamp(secs)
+            .and_then(|time| time.replace_nanosecond(nanos))
+            .ok()
+            .zip(UtcOffset::from_whole_seconds(offset).ok())
+            .and_then(|(time, offset)| time.checked_to_offset(offset))
+            .ok_or_else(|| MByteBufferError::OtherError {
+                error: format!(
+                    "Invalid OffsetDateTime: secs: {} nanos: {} offset: {}",
+                    secs, nanos, offset
+                ),
+            })
+    }
+}
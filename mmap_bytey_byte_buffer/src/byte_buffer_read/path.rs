@@ -0,0 +1,12 @@
+use crate::{
+    byte_buffer::MByteBuffer, byte_buffer_read::MByteBufferRead, byte_order::ByteOrder,
+    error::Result,
+};
+use std::{ffi::OsString, path::PathBuf};
+
+impl MByteBufferRead for PathBuf {
+    #[inline]
+    fn read_from_mbuffer_with<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<Self> {
+        Ok(buffer.read_with::<O, OsString>()?.into())
+    }
+}
@@ -1,19 +1,12 @@
-use crate::{byte_buffer::MByteBuffer, byte_buffer_read::MByteBufferRead, error::Result};
+use crate::{
+    byte_buffer::MByteBuffer, byte_buffer_read::MByteBufferRead, byte_order::ByteOrder,
+    error::Result,
+};
 use std::marker::PhantomData;
 
 impl<T> MByteBufferRead for PhantomData<T> {
     #[inline]
-    fn read_from_mbuffer(_buffer: &mut MByteBuffer) -> Result<Self> {
-        Ok(core::marker::PhantomData)
-    }
-
-    #[inline]
-    fn read_from_mbuffer_le(_buffer: &mut MByteBuffer) -> Result<Self> {
-        Ok(core::marker::PhantomData)
-    }
-
-    #[inline]
-    fn read_from_mbuffer_be(_buffer: &mut MByteBuffer) -> Result<Self> {
+    fn read_from_mbuffer_with<O: ByteOrder>(_buffer: &mut MByteBuffer) -> Result<Self> {
         Ok(core::marker::PhantomData)
     }
 }
@@ -0,0 +1,79 @@
+use crate::{
+    byte_buffer::MByteBuffer,
+    byte_buffer_read::MByteBufferRead,
+    byte_order::ByteOrder,
+    error::{MByteBufferError, Result},
+    quantized::{FixedInt, FixedPoint, QuantizeFloat, Quantized, SmallestThree, BF16, F16},
+};
+
+/// Reads an unsigned integer of `bytes` bytes written by `write_uint`.
+#[inline]
+pub(crate) fn read_uint<O: ByteOrder>(bytes: usize, buffer: &mut MByteBuffer) -> Result<u64> {
+    let source = buffer.read_slice(bytes)?;
+    let mut value = [0u8; 8];
+
+    if cfg!(target_endian = "little") != O::SWAP {
+        value[..bytes].copy_from_slice(source);
+        Ok(u64::from_le_bytes(value))
+    } else {
+        value[8 - bytes..].copy_from_slice(source);
+        Ok(u64::from_be_bytes(value))
+    }
+}
+
+impl<T: QuantizeFloat, const MIN: i32, const MAX: i32, const BITS: u32> MByteBufferRead
+    for Quantized<T, MIN, MAX, BITS>
+{
+    #[inline]
+    fn read_from_mbuffer_with<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<Self> {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID;
+        let steps = read_uint::<O>(Self::BYTES, buffer)?;
+
+        if steps > Self::STEPS {
+            return Err(MByteBufferError::OtherError {
+                error: format!(
+                    "{} is past the {} bits of {}",
+                    steps,
+                    BITS,
+                    core::any::type_name::<Self>()
+                ),
+            });
+        }
+
+        Ok(Self::from_steps(steps))
+    }
+}
+
+impl<I: FixedInt + MByteBufferRead, const FRAC_BITS: u32> MByteBufferRead
+    for FixedPoint<I, FRAC_BITS>
+{
+    #[inline]
+    fn read_from_mbuffer_with<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<Self> {
+        Ok(FixedPoint::from_raw(buffer.read_with::<O, I>()?))
+    }
+}
+
+impl MByteBufferRead for F16 {
+    #[inline]
+    fn read_from_mbuffer_with<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<Self> {
+        Ok(F16::from_bits(buffer.read_with::<O, u16>()?))
+    }
+}
+
+impl MByteBufferRead for BF16 {
+    #[inline]
+    fn read_from_mbuffer_with<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<Self> {
+        Ok(BF16::from_bits(buffer.read_with::<O, u16>()?))
+    }
+}
+
+impl<const BITS: u32> MByteBufferRead for SmallestThree<BITS> {
+    #[inline]
+    fn read_from_mbuffer_with<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<Self> {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID;
+
+        Ok(SmallestThree::unpack(read_uint::<O>(Self::BYTES, buffer)?))
+    }
+}
@@ -1,45 +1,50 @@
-use crate::{byte_buffer::MByteBuffer, byte_buffer_read::MByteBufferRead, error::Result};
-use std::ops::{Range, RangeInclusive};
+use crate::{
+    byte_buffer::MByteBuffer, byte_buffer_read::MByteBufferRead, byte_order::ByteOrder,
+    error::Result,
+};
+use std::ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
 
 impl<T: MByteBufferRead> MByteBufferRead for Range<T> {
     #[inline]
-    fn read_from_mbuffer(buffer: &mut MByteBuffer) -> Result<Self> {
-        Ok(buffer.read::<T>()?..buffer.read::<T>()?)
+    fn read_from_mbuffer_with<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<Self> {
+        Ok(buffer.read_with::<O, T>()?..buffer.read_with::<O, T>()?)
     }
+}
 
+impl<T: MByteBufferRead> MByteBufferRead for RangeInclusive<T> {
     #[inline]
-    fn read_from_mbuffer_le(buffer: &mut MByteBuffer) -> Result<Self> {
-        Ok(buffer.read::<T>()?..buffer.read::<T>()?)
+    fn read_from_mbuffer_with<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<Self> {
+        Ok(RangeInclusive::new(
+            buffer.read_with::<O, T>()?,
+            buffer.read_with::<O, T>()?,
+        ))
     }
+}
 
+impl<T: MByteBufferRead> MByteBufferRead for RangeFrom<T> {
     #[inline]
-    fn read_from_mbuffer_be(buffer: &mut MByteBuffer) -> Result<Self> {
-        Ok(buffer.read::<T>()?..buffer.read::<T>()?)
+    fn read_from_mbuffer_with<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<Self> {
+        Ok(buffer.read_with::<O, T>()?..)
     }
 }
 
-impl<T: MByteBufferRead> MByteBufferRead for RangeInclusive<T> {
+impl<T: MByteBufferRead> MByteBufferRead for RangeTo<T> {
     #[inline]
-    fn read_from_mbuffer(buffer: &mut MByteBuffer) -> Result<bool> {
-        Ok(RangeInclusive::new(
-            buffer.read::<T>()?,
-            buffer.read::<T>()?,
-        ))
+    fn read_from_mbuffer_with<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<Self> {
+        Ok(..buffer.read_with::<O, T>()?)
     }
+}
 
+impl<T: MByteBufferRead> MByteBufferRead for RangeToInclusive<T> {
     #[inline]
-    fn read_from_mbuffer_le(buffer: &mut MByteBuffer) -> Result<bool> {
-        Ok(RangeInclusive::new(
-            buffer.read::<T>()?,
-            buffer.read::<T>()?,
-        ))
+    fn read_from_mbuffer_with<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<Self> {
+        Ok(..=buffer.read_with::<O, T>()?)
     }
+}
 
+impl MByteBufferRead for RangeFull {
     #[inline]
-    fn read_from_mbuffer_be(buffer: &mut MByteBuffer) -> Result<bool> {
-        Ok(RangeInclusive::new(
-            buffer.read::<T>()?,
-            buffer.read::<T>()?,
-        ))
+    fn read_from_mbuffer_with<O: ByteOrder>(_buffer: &mut MByteBuffer) -> Result<Self> {
+        Ok(..)
     }
 }
@@ -1,82 +1,18 @@
 use crate::{
     byte_buffer::MByteBuffer,
     byte_buffer_read::MByteBufferRead,
+    byte_order::ByteOrder,
     error::{MByteBufferError, Result},
 };
 
 impl<T: MByteBufferRead, E: MByteBufferRead> MByteBufferRead for std::result::Result<T, E> {
     #[inline]
-    fn read_from_mbuffer(buffer: &mut MByteBuffer) -> Result<std::result::Result<T, E>> {
-        Ok(match buffer.read::<u8>()? {
-            1 => Ok(buffer.read::<T>()?),
-            2 => Err(buffer.read::<E>()?),
-            _ => {
-                return Err(MByteBufferError::OtherError {
-                    error: "Invalid Read to Result".to_owned(),
-                })
-            }
-        })
-    }
-
-    #[inline]
-    fn read_from_mbuffer_le(buffer: &mut MByteBuffer) -> Result<std::result::Result<T, E>> {
-        Ok(match buffer.read_le::<u8>()? {
-            1 => Ok(buffer.read_le::<T>()?),
-            2 => Err(buffer.read_le::<E>()?),
-            _ => {
-                return Err(MByteBufferError::OtherError {
-                    error: "Invalid Read to Result".to_owned(),
-                })
-            }
-        })
-    }
-
-    #[inline]
-    fn read_from_mbuffer_be(buffer: &mut MByteBuffer) -> Result<std::result::Result<T, E>> {
-        Ok(match buffer.read_be::<u8>()? {
-            1 => Ok(buffer.read_be::<T>()?),
-            2 => Err(buffer.read_be::<E>()?),
-            _ => {
-                return Err(MByteBufferError::OtherError {
-                    error: "Invalid Read to Result".to_owned(),
-                })
-            }
-        })
-    }
-}
-
-impl<E: MByteBufferRead> MByteBufferRead for std::result::Result<(), E> {
-    #[inline]
-    fn read_from_mbuffer(buffer: &mut MByteBuffer) -> Result<std::result::Result<(), E>> {
-        Ok(match buffer.read::<u8>()? {
-            1 => Ok(()),
-            2 => Err(buffer.read::<E>()?),
-            _ => {
-                return Err(MByteBufferError::OtherError {
-                    error: "Invalid Read to Result".to_owned(),
-                })
-            }
-        })
-    }
-
-    #[inline]
-    fn read_from_mbuffer_le(buffer: &mut MByteBuffer) -> Result<std::result::Result<(), E>> {
-        Ok(match buffer.read_le::<u8>()? {
-            1 => Ok(()),
-            2 => Err(buffer.read_le::<E>()?),
-            _ => {
-                return Err(MByteBufferError::OtherError {
-                    error: "Invalid Read to Result".to_owned(),
-                })
-            }
-        })
-    }
-
-    #[inline]
-    fn read_from_mbuffer_be(buffer: &mut MByteBuffer) -> Result<std::result::Result<(), E>> {
-        Ok(match buffer.read_be::<u8>()? {
-            1 => Ok(()),
-            2 => Err(buffer.read_be::<E>()?),
+    fn read_from_mbuffer_with<O: ByteOrder>(
+        buffer: &mut MByteBuffer,
+    ) -> Result<std::result::Result<T, E>> {
+        Ok(match buffer.read_with::<O, u8>()? {
+            1 => Ok(buffer.read_with::<O, T>()?),
+            2 => Err(buffer.read_with::<O, E>()?),
             _ => {
                 return Err(MByteBufferError::OtherError {
                     error: "Invalid Read to Result".to_owned(),
@@ -1,36 +1,23 @@
-use crate::{byte_buffer::MByteBuffer, byte_buffer_read::MByteBufferRead, error::Result};
+use crate::{
+    byte_buffer::MByteBuffer, byte_buffer_read::MByteBufferRead, byte_order::ByteOrder,
+    error::Result,
+};
 
 impl MByteBufferRead for String {
     #[inline]
-    fn read_from_mbuffer(buffer: &mut MByteBuffer) -> Result<Self> {
-        let len = buffer.read::<usize>()?;
-
-        if len == 0 {
-            Ok(String::new())
-        } else {
-            Ok(std::str::from_utf8(buffer.read_slice(len)?)?.to_owned())
-        }
-    }
-
-    #[inline]
-    fn read_from_mbuffer_le(buffer: &mut MByteBuffer) -> Result<Self> {
-        let len = buffer.read_le::<usize>()?;
-
-        if len == 0 {
-            Ok(String::new())
-        } else {
-            Ok(std::str::from_utf8(buffer.read_slice(len)?)?.to_owned())
-        }
+    fn read_from_mbuffer_with<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<Self> {
+        Ok(std::str::from_utf8(read_len_prefixed::<O>(buffer)?)?.to_owned())
     }
+}
 
-    #[inline]
-    fn read_from_mbuffer_be(buffer: &mut MByteBuffer) -> Result<Self> {
-        let len = buffer.read_be::<usize>()?;
+/// Reads the bytes of a string-like type written after a `usize` length prefix.
+#[inline]
+pub(crate) fn read_len_prefixed<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<&[u8]> {
+    let len = buffer.read_with::<O, usize>()?;
 
-        if len == 0 {
-            Ok(String::new())
-        } else {
-            Ok(std::str::from_utf8(buffer.read_slice(len)?)?.to_owned())
-        }
+    if len == 0 {
+        Ok(&[])
+    } else {
+        buffer.read_slice(len)
     }
 }
@@ -0,0 +1,76 @@
+use crate::{
+    byte_buffer::MByteBuffer,
+    byte_buffer_read::MByteBufferRead,
+    byte_order::ByteOrder,
+    error::Result,
+    string_encoding::{FixedStr, NulTerminated, Utf16},
+};
+use std::ffi::{CStr, CString};
+
+impl MByteBufferRead for NulTerminated<String> {
+    #[inline]
+    fn read_from_mbuffer_with<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<Self> {
+        let NulTerminated(text) = buffer.reader().read_with::<O, NulTerminated<&str>>()?;
+
+        Ok(NulTerminated(text.to_owned()))
+    }
+}
+
+impl MByteBufferRead for NulTerminated<Vec<u8>> {
+    #[inline]
+    fn read_from_mbuffer_with<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<Self> {
+        let NulTerminated(bytes) = buffer.reader().read_with::<O, NulTerminated<&[u8]>>()?;
+
+        Ok(NulTerminated(bytes.to_vec()))
+    }
+}
+
+impl MByteBufferRead for NulTerminated<CString> {
+    #[inline]
+    fn read_from_mbuffer_with<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<Self> {
+        let NulTerminated(text) = buffer.reader().read_with::<O, NulTerminated<&CStr>>()?;
+
+        Ok(NulTerminated(text.to_owned()))
+    }
+}
+
+impl<const N: usize> MByteBufferRead for FixedStr<N, String> {
+    #[inline]
+    fn read_from_mbuffer_with<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<Self> {
+        let FixedStr(text) = buffer.reader().read_with::<O, FixedStr<N, &str>>()?;
+
+        Ok(FixedStr(text.to_owned()))
+    }
+}
+
+impl<const N: usize> MByteBufferRead for FixedStr<N, Vec<u8>> {
+    #[inline]
+    fn read_from_mbuffer_with<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<Self> {
+        let FixedStr(bytes) = buffer.reader().read_with::<O, FixedStr<N, &[u8]>>()?;
+
+        Ok(FixedStr(bytes.to_vec()))
+    }
+}
+
+impl<const N: usize> MByteBufferRead for FixedStr<N, CString> {
+    #[inline]
+    fn read_from_mbuffer_with<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<Self> {
+        let FixedStr(bytes) = buffer.reader().read_with::<O, FixedStr<N, &[u8]>>()?;
+
+        Ok(FixedStr(CString::new(bytes)?))
+    }
+}
+
+impl MByteBufferRead for Utf16<String> {
+    #[inline]
+    fn read_from_mbuffer_with<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<Self> {
+        let count = buffer.read_with::<O, usize>()?;
+        let mut units = Vec::with_capacity(count.min((buffer.length() - buffer.cursor()) / 2));
+
+        for _ in 0..count {
+            units.push(buffer.read_with::<O, u16>()?);
+        }
+
+        Ok(Utf16(String::from_utf16(&units)?))
+    }
+}
@@ -1,33 +1,28 @@
 use crate::{
     byte_buffer::MByteBuffer,
     byte_buffer_read::MByteBufferRead,
+    byte_order::ByteOrder,
     error::{MByteBufferError, Result},
 };
-use std::time::Duration;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 impl MByteBufferRead for Duration {
     #[inline]
-    fn read_from_mbuffer(buffer: &mut MByteBuffer) -> Result<Self> {
-        let secs = buffer.read::<u64>()?;
-        let nanos = buffer.read::<u32>()?;
-
-        new_duration(secs, nanos)
-    }
-
-    #[inline]
-    fn read_from_mbuffer_le(buffer: &mut MByteBuffer) -> Result<Self> {
-        let secs = buffer.read_le::<u64>()?;
-        let nanos = buffer.read_le::<u32>()?;
+    fn read_from_mbuffer_with<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<Self> {
+        let secs = buffer.read_with::<O, u64>()?;
+        let nanos = buffer.read_with::<O, u32>()?;
 
         new_duration(secs, nanos)
     }
+}
 
+impl MByteBufferRead for SystemTime {
     #[inline]
-    fn read_from_mbuffer_be(buffer: &mut MByteBuffer) -> Result<Self> {
-        let secs = buffer.read_be::<u64>()?;
-        let nanos = buffer.read_be::<u32>()?;
+    fn read_from_mbuffer_with<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<Self> {
+        let secs = buffer.read_with::<O, i64>()?;
+        let nanos = buffer.read_with::<O, u32>()?;
 
-        new_duration(secs, nanos)
+        UNIX_EPOCH.offset_by(secs, nanos)
     }
 }
 
@@ -43,3 +38,60 @@ fn new_duration(secs: u64, nanos: u32) -> Result<Duration> {
 
     Ok(Duration::new(secs, nanos))
 }
+
+/// Points in time that a signed offset, as written by `signed_offset`, can be applied to.
+pub(crate) trait OffsetBy: Sized + Copy {
+    fn checked_add(self, duration: Duration) -> Option<Self>;
+
+    fn checked_sub(self, duration: Duration) -> Option<Self>;
+
+    /// Moves `self` by `secs` seconds, rounded down, plus `nanos` nanoseconds.
+    #[inline]
+    fn offset_by(self, secs: i64, nanos: u32) -> Result<Self> {
+        let invalid = || MByteBufferError::OtherError {
+            error: format!(
+                "Invalid offset: secs: {} nanos: {} for type: {}",
+                secs,
+                nanos,
+                core::any::type_name::<Self>()
+            ),
+        };
+
+        if nanos >= 1_000_000_000 {
+            return Err(invalid());
+        }
+
+        let moved = if secs >= 0 {
+            self.checked_add(Duration::new(secs as u64, nanos))
+        } else {
+            self.checked_sub(Duration::from_secs(secs.unsigned_abs()))
+                .and_then(|time| time.checked_add(Duration::from_nanos(u64::from(nanos))))
+        };
+
+        moved.ok_or_else(invalid)
+    }
+}
+
+impl OffsetBy for SystemTime {
+    #[inline]
+    fn checked_add(self, duration: Duration) -> Option<Self> {
+        SystemTime::checked_add(&self, duration)
+    }
+
+    #[inline]
+    fn checked_sub(self, duration: Duration) -> Option<Self> {
+        SystemTime::checked_sub(&self, duration)
+    }
+}
+
+impl OffsetBy for Instant {
+    #[inline]
+    fn checked_add(self, duration: Duration) -> Option<Self> {
+        Instant::checked_add(&self, duration)
+    }
+
+    #[inline]
+    fn checked_sub(self, duration: Duration) -> Option<Self> {
+        Instant::checked_sub(&self, duration)
+    }
+}
@@ -1,6 +1,7 @@
 use crate::{
     byte_buffer::MByteBuffer,
     byte_buffer_read::MByteBufferRead,
+    byte_order::ByteOrder,
     error::{MByteBufferError, Result},
 };
 
@@ -14,28 +15,8 @@ macro_rules! tuple_impls {
             impl<$($T: MByteBufferRead),+> MByteBufferRead for ($($T,)+)
             {
                 #[inline]
-                fn read_from_mbuffer(buffer: &mut MByteBuffer) -> Result<Self> {
-                    Ok(($(match buffer.read::<$T>() {
-                        Ok(v) => v,
-                        Err(e) => return Err(MByteBufferError::OtherError {
-                            error: format!("{} occured at tuple read location {}", e, $idx),
-                        })
-                    },)+))
-                }
-
-                #[inline]
-                fn read_from_mbuffer_le(buffer: &mut MByteBuffer) -> Result<Self> {
-                    Ok(($(match buffer.read_le::<$T>() {
-                        Ok(v) => v,
-                        Err(e) => return Err(MByteBufferError::OtherError {
-                            error: format!("{} occured at tuple read location {}", e, $idx),
-                        })
-                    },)+))
-                }
-
-                #[inline]
-                fn read_from_mbuffer_be(buffer: &mut MByteBuffer) -> Result<Self> {
-                    Ok(($(match buffer.read_be::<$T>() {
+                fn read_from_mbuffer_with<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<Self> {
+                    Ok(($(match buffer.read_with::<O, $T>() {
                         Ok(v) => v,
                         Err(e) => return Err(MByteBufferError::OtherError {
                             error: format!("{} occured at tuple read location {}", e, $idx),
@@ -151,3 +132,10 @@ tuple_impls! {
         (11) -> L
     }
 }
+
+impl MByteBufferRead for () {
+    #[inline]
+    fn read_from_mbuffer_with<O: ByteOrder>(_buffer: &mut MByteBuffer) -> Result<Self> {
+        Ok(())
+    }
+}
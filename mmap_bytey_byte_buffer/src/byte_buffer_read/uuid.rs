@@ -0,0 +1,15 @@
+use crate::{
+    byte_buffer::MByteBuffer, byte_buffer_read::MByteBufferRead, byte_order::ByteOrder,
+    error::Result,
+};
+use ::uuid::Uuid;
+
+impl MByteBufferRead for Uuid {
+    #[inline]
+    fn read_from_mbuffer_with<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<Self> {
+        let mut bytes = [0u8; 16];
+
+        bytes.copy_from_slice(buffer.read_slice(16)?);
+        Ok(Uuid::from_bytes(bytes))
+    }
+}
@@ -0,0 +1,13 @@
+use crate::{
+    byte_buffer::MByteBuffer, byte_buffer_read::MByteBufferRead, byte_order::ByteOrder,
+    error::Result, varint::VarInt,
+};
+
+impl MByteBufferRead for VarInt {
+    #[inline]
+    fn read_from_mbuffer_with<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<Self> {
+        let mode = buffer.decode_mode();
+
+        VarInt::decode(mode, || buffer.read::<u8>())
+    }
+}
@@ -0,0 +1,73 @@
+use crate::{
+    byte_buffer::MByteBufferReader,
+    byte_order::{Big, ByteOrder, Endianness, Little, Native},
+    error::Result,
+};
+
+mod arrays;
+mod boxed;
+mod byte;
+mod char;
+mod option;
+mod string;
+mod string_encoding;
+mod tuple;
+mod varint;
+
+/// Reads a value that may borrow from the [`MByteBuffer`](crate::byte_buffer::MByteBuffer) it is read from.
+///
+/// The lifetime `'a` is the lifetime of the buffer's borrow, so `&'a str` and `&'a [u8]`
+/// can point straight into the buffer instead of being copied into a new allocation.
+///
+/// Like [`MByteBufferRead`](crate::byte_buffer_read::MByteBufferRead), implementors should override
+/// [`read_ref_from_mbuffer_with`](Self::read_ref_from_mbuffer_with) or all three of the other methods.
+pub trait MByteBufferReadRef<'a>: Sized {
+    #[inline]
+    fn read_ref_from_mbuffer_with<O: ByteOrder>(
+        reader: &mut MByteBufferReader<'a>,
+    ) -> Result<Self> {
+        match O::ENDIANNESS {
+            Endianness::Native => Self::read_ref_from_mbuffer(reader),
+            Endianness::Little => Self::read_ref_from_mbuffer_le(reader),
+            Endianness::Big => Self::read_ref_from_mbuffer_be(reader),
+        }
+    }
+
+    #[inline]
+    fn read_ref_from_mbuffer(reader: &mut MByteBufferReader<'a>) -> Result<Self> {
+        Self::read_ref_from_mbuffer_with::<Native>(reader)
+    }
+
+    #[inline]
+    fn read_ref_from_mbuffer_le(reader: &mut MByteBufferReader<'a>) -> Result<Self> {
+        Self::read_ref_from_mbuffer_with::<Little>(reader)
+    }
+
+    #[inline]
+    fn read_ref_from_mbuffer_be(reader: &mut MByteBufferReader<'a>) -> Result<Self> {
+        Self::read_ref_from_mbuffer_with::<Big>(reader)
+    }
+}
+
+macro_rules! impl_byte_buffer_read_ref_types {
+    ($($type:ty),*) => {
+        $(
+            impl MByteBufferReadRef<'_> for $type {
+                #[inline]
+                fn read_ref_from_mbuffer_with<O: ByteOrder>(reader: &mut MByteBufferReader<'_>) -> Result<$type> {
+                    let mut bytes: [u8; std::mem::size_of::<$type>()] = reader.read_slice(std::mem::size_of::<$type>())?.try_into().expect("This really shouldn't fail");
+
+                    if O::SWAP {
+                        bytes.reverse();
+                    }
+
+                    Ok(<$type>::from_ne_bytes(bytes))
+                }
+            }
+        )*
+    }
+}
+
+impl_byte_buffer_read_ref_types!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64
+);
@@ -0,0 +1,74 @@
+use crate::{
+    byte_buffer::MByteBufferReader,
+    byte_buffer_read_ref::MByteBufferReadRef,
+    byte_order::ByteOrder,
+    error::{MByteBufferError, Result},
+};
+
+impl<'a: 'b, 'b> MByteBufferReadRef<'a> for &'b [u8] {
+    #[inline]
+    fn read_ref_from_mbuffer_with<O: ByteOrder>(
+        reader: &mut MByteBufferReader<'a>,
+    ) -> Result<Self> {
+        let len = reader.read_with::<O, u64>()? as usize;
+
+        reader.read_slice(len)
+    }
+}
+
+impl<'a, T: MByteBufferReadRef<'a>, const N: usize> MByteBufferReadRef<'a> for [T; N] {
+    #[inline]
+    fn read_ref_from_mbuffer_with<O: ByteOrder>(
+        reader: &mut MByteBufferReader<'a>,
+    ) -> Result<Self> {
+        if N == 0 {
+            return Err(MByteBufferError::OtherError {
+                error: "Can not read to an [T;0]. The array must have a size.".to_owned(),
+            });
+        }
+
+        let size = reader.read_with::<O, u64>()? as usize;
+
+        if size != N {
+            return Err(MByteBufferError::OtherError {
+                error: format!(
+                    "Invalid size in buffer for [T; {}]. Should be [T; {}]?",
+                    N, size
+                ),
+            });
+        }
+
+        let items = read_items::<O, T>(reader, N)?;
+
+        match items.try_into() {
+            Ok(array) => Ok(array),
+            Err(_) => unreachable!("exactly N elements were read"),
+        }
+    }
+}
+
+impl<'a, T: MByteBufferReadRef<'a>> MByteBufferReadRef<'a> for Vec<T> {
+    #[inline]
+    fn read_ref_from_mbuffer_with<O: ByteOrder>(
+        reader: &mut MByteBufferReader<'a>,
+    ) -> Result<Self> {
+        let size = reader.read_with::<O, u64>()? as usize;
+
+        read_items::<O, T>(reader, size)
+    }
+}
+
+/// Reads `count` elements one after another. The capacity reserved up front is capped by the
+/// bytes left, so a corrupt count fails on reading instead of allocating.
+fn read_items<'a, O: ByteOrder, T: MByteBufferReadRef<'a>>(
+    reader: &mut MByteBufferReader<'a>,
+    count: usize,
+) -> Result<Vec<T>> {
+    let mut items = Vec::with_capacity(count.min(reader.remaining().len()));
+
+    for _ in 0..count {
+        items.push(reader.read_with::<O, T>()?);
+    }
+
+    Ok(items)
+}
@@ -0,0 +1,32 @@
+use crate::{
+    byte_buffer::MByteBufferReader, byte_buffer_read_ref::MByteBufferReadRef,
+    byte_order::ByteOrder, error::Result,
+};
+use std::{rc::Rc, sync::Arc};
+
+impl<'a, T: MByteBufferReadRef<'a>> MByteBufferReadRef<'a> for Box<T> {
+    #[inline]
+    fn read_ref_from_mbuffer_with<O: ByteOrder>(
+        reader: &mut MByteBufferReader<'a>,
+    ) -> Result<Box<T>> {
+        Ok(Box::new(reader.read_with::<O, T>()?))
+    }
+}
+
+impl<'a, T: MByteBufferReadRef<'a>> MByteBufferReadRef<'a> for Rc<T> {
+    #[inline]
+    fn read_ref_from_mbuffer_with<O: ByteOrder>(
+        reader: &mut MByteBufferReader<'a>,
+    ) -> Result<Rc<T>> {
+        Ok(Rc::new(reader.read_with::<O, T>()?))
+    }
+}
+
+impl<'a, T: MByteBufferReadRef<'a>> MByteBufferReadRef<'a> for Arc<T> {
+    #[inline]
+    fn read_ref_from_mbuffer_with<O: ByteOrder>(
+        reader: &mut MByteBufferReader<'a>,
+    ) -> Result<Arc<T>> {
+        Ok(Arc::new(reader.read_with::<O, T>()?))
+    }
+}
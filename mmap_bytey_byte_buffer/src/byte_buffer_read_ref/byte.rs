@@ -0,0 +1,13 @@
+use crate::{
+    byte_buffer::MByteBufferReader, byte_buffer_read_ref::MByteBufferReadRef,
+    byte_order::ByteOrder, error::Result,
+};
+
+impl MByteBufferReadRef<'_> for bool {
+    #[inline]
+    fn read_ref_from_mbuffer_with<O: ByteOrder>(
+        reader: &mut MByteBufferReader<'_>,
+    ) -> Result<bool> {
+        reader.decode_mode().decode_bool(reader.read::<u8>()?)
+    }
+}
@@ -0,0 +1,15 @@
+use crate::{
+    byte_buffer::MByteBufferReader, byte_buffer_read_ref::MByteBufferReadRef,
+    byte_order::ByteOrder, error::Result,
+};
+
+impl MByteBufferReadRef<'_> for char {
+    #[inline]
+    fn read_ref_from_mbuffer_with<O: ByteOrder>(
+        reader: &mut MByteBufferReader<'_>,
+    ) -> Result<char> {
+        reader
+            .decode_mode()
+            .decode_char(reader.read_with::<O, u32>()?)
+    }
+}
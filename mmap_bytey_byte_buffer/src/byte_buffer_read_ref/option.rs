@@ -0,0 +1,25 @@
+use crate::{
+    byte_buffer::MByteBufferReader,
+    byte_buffer_read_ref::MByteBufferReadRef,
+    byte_order::ByteOrder,
+    error::{MByteBufferError, Result},
+};
+
+impl<'a, T: MByteBufferReadRef<'a>> MByteBufferReadRef<'a> for Option<T> {
+    #[inline]
+    fn read_ref_from_mbuffer_with<O: ByteOrder>(
+        reader: &mut MByteBufferReader<'a>,
+    ) -> Result<Option<T>> {
+        let data = match reader.read_with::<O, u8>()? {
+            1 => Some(reader.read_with::<O, T>()?),
+            2 => None,
+            _ => {
+                return Err(MByteBufferError::OtherError {
+                    error: "Invalid Read to Option".to_owned(),
+                })
+            }
+        };
+
+        Ok(data)
+    }
+}
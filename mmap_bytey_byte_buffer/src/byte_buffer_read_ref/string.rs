@@ -0,0 +1,25 @@
+use crate::{
+    byte_buffer::MByteBufferReader, byte_buffer_read_ref::MByteBufferReadRef,
+    byte_order::ByteOrder, error::Result,
+};
+use std::borrow::Cow;
+
+impl<'a: 'b, 'b> MByteBufferReadRef<'a> for &'b str {
+    #[inline]
+    fn read_ref_from_mbuffer_with<O: ByteOrder>(
+        reader: &mut MByteBufferReader<'a>,
+    ) -> Result<Self> {
+        let len = reader.read_with::<O, usize>()?;
+
+        Ok(std::str::from_utf8(reader.read_slice(len)?)?)
+    }
+}
+
+impl<'a: 'b, 'b> MByteBufferReadRef<'a> for Cow<'b, str> {
+    #[inline]
+    fn read_ref_from_mbuffer_with<O: ByteOrder>(
+        reader: &mut MByteBufferReader<'a>,
+    ) -> Result<Self> {
+        Ok(Cow::Borrowed(reader.read_with::<O, &str>()?))
+    }
+}
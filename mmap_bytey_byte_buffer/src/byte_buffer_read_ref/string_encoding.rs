@@ -0,0 +1,83 @@
+use crate::{
+    byte_buffer::MByteBufferReader,
+    byte_buffer_read_ref::MByteBufferReadRef,
+    byte_order::ByteOrder,
+    error::{MByteBufferError, Result},
+    string_encoding::{FixedStr, NulTerminated},
+};
+use std::ffi::CStr;
+
+/// Reads the bytes up to the next nul, along with the nul itself.
+#[inline]
+fn read_with_nul<'a>(reader: &mut MByteBufferReader<'a>) -> Result<&'a [u8]> {
+    match reader.remaining().iter().position(|&byte| byte == 0) {
+        Some(position) => reader.read_slice(position + 1),
+        None => Err(MByteBufferError::ReadOutOfBounds {
+            length: reader.length(),
+            start: reader.cursor(),
+            end: reader.length() + 1,
+        }),
+    }
+}
+
+/// Reads `N` bytes and cuts them off at the first nul.
+#[inline]
+fn read_fixed<'a, const N: usize>(reader: &mut MByteBufferReader<'a>) -> Result<&'a [u8]> {
+    let bytes = reader.read_slice(N)?;
+    let end = bytes.iter().position(|&byte| byte == 0).unwrap_or(N);
+
+    Ok(&bytes[..end])
+}
+
+impl<'a: 'b, 'b> MByteBufferReadRef<'a> for NulTerminated<&'b [u8]> {
+    #[inline]
+    fn read_ref_from_mbuffer_with<O: ByteOrder>(
+        reader: &mut MByteBufferReader<'a>,
+    ) -> Result<Self> {
+        let bytes = read_with_nul(reader)?;
+
+        Ok(NulTerminated(&bytes[..bytes.len() - 1]))
+    }
+}
+
+impl<'a: 'b, 'b> MByteBufferReadRef<'a> for NulTerminated<&'b str> {
+    #[inline]
+    fn read_ref_from_mbuffer_with<O: ByteOrder>(
+        reader: &mut MByteBufferReader<'a>,
+    ) -> Result<Self> {
+        let NulTerminated(bytes) = reader.read_with::<O, NulTerminated<&[u8]>>()?;
+
+        Ok(NulTerminated(std::str::from_utf8(bytes)?))
+    }
+}
+
+impl<'a: 'b, 'b> MByteBufferReadRef<'a> for NulTerminated<&'b CStr> {
+    #[inline]
+    fn read_ref_from_mbuffer_with<O: ByteOrder>(
+        reader: &mut MByteBufferReader<'a>,
+    ) -> Result<Self> {
+        let bytes = read_with_nul(reader)?;
+
+        Ok(NulTerminated(
+            CStr::from_bytes_with_nul(bytes).expect("the only nul is the last byte"),
+        ))
+    }
+}
+
+impl<'a: 'b, 'b, const N: usize> MByteBufferReadRef<'a> for FixedStr<N, &'b [u8]> {
+    #[inline]
+    fn read_ref_from_mbuffer_with<O: ByteOrder>(
+        reader: &mut MByteBufferReader<'a>,
+    ) -> Result<Self> {
+        Ok(FixedStr(read_fixed::<N>(reader)?))
+    }
+}
+
+impl<'a: 'b, 'b, const N: usize> MByteBufferReadRef<'a> for FixedStr<N, &'b str> {
+    #[inline]
+    fn read_ref_from_mbuffer_with<O: ByteOrder>(
+        reader: &mut MByteBufferReader<'a>,
+    ) -> Result<Self> {
+        Ok(FixedStr(std::str::from_utf8(read_fixed::<N>(reader)?)?))
+    }
+}
@@ -0,0 +1,143 @@
+use crate::{
+    byte_buffer::MByteBufferReader,
+    byte_buffer_read_ref::MByteBufferReadRef,
+    byte_order::ByteOrder,
+    error::{MByteBufferError, Result},
+};
+
+macro_rules! tuple_impls {
+    ($(
+        $Tuple:ident {
+            $(($idx:tt) -> $T:ident)+
+        }
+    )+) => {
+        $(
+            impl<'a, $($T: MByteBufferReadRef<'a>),+> MByteBufferReadRef<'a> for ($($T,)+)
+            {
+                #[inline]
+                fn read_ref_from_mbuffer_with<O: ByteOrder>(reader: &mut MByteBufferReader<'a>) -> Result<Self> {
+                    Ok(($(match reader.read_with::<O, $T>() {
+                        Ok(v) => v,
+                        Err(e) => return Err(MByteBufferError::OtherError {
+                            error: format!("{} occured at tuple read location {}", e, $idx),
+                        })
+                    },)+))
+                }
+            }
+        )+
+    }
+}
+
+tuple_impls! {
+    Tuple1 {
+        (0) -> A
+    }
+    Tuple2 {
+        (0) -> A
+        (1) -> B
+    }
+    Tuple3 {
+        (0) -> A
+        (1) -> B
+        (2) -> C
+    }
+    Tuple4 {
+        (0) -> A
+        (1) -> B
+        (2) -> C
+        (3) -> D
+    }
+    Tuple5 {
+        (0) -> A
+        (1) -> B
+        (2) -> C
+        (3) -> D
+        (4) -> E
+    }
+    Tuple6 {
+        (0) -> A
+        (1) -> B
+        (2) -> C
+        (3) -> D
+        (4) -> E
+        (5) -> F
+    }
+    Tuple7 {
+        (0) -> A
+        (1) -> B
+        (2) -> C
+        (3) -> D
+        (4) -> E
+        (5) -> F
+        (6) -> G
+    }
+    Tuple8 {
+        (0) -> A
+        (1) -> B
+        (2) -> C
+        (3) -> D
+        (4) -> E
+        (5) -> F
+        (6) -> G
+        (7) -> H
+    }
+    Tuple9 {
+        (0) -> A
+        (1) -> B
+        (2) -> C
+        (3) -> D
+        (4) -> E
+        (5) -> F
+        (6) -> G
+        (7) -> H
+        (8) -> I
+    }
+    Tuple10 {
+        (0) -> A
+        (1) -> B
+        (2) -> C
+        (3) -> D
+        (4) -> E
+        (5) -> F
+        (6) -> G
+        (7) -> H
+        (8) -> I
+        (9) -> J
+    }
+    Tuple11 {
+        (0) -> A
+        (1) -> B
+        (2) -> C
+        (3) -> D
+        (4) -> E
+        (5) -> F
+        (6) -> G
+        (7) -> H
+        (8) -> I
+        (9) -> J
+        (10) -> K
+    }
+    Tuple12 {
+        (0) -> A
+        (1) -> B
+        (2) -> C
+        (3) -> D
+        (4) -> E
+        (5) -> F
+        (6) -> G
+        (7) -> H
+        (8) -> I
+        (9) -> J
+        (10) -> K
+        (11) -> L
+    }
+}
+
+impl MByteBufferReadRef<'_> for () {
+    #[inline]
+    fn read_ref_from_mbuffer_with<O: ByteOrder>(
+        _reader: &mut MByteBufferReader<'_>,
+    ) -> Result<Self> {
+        Ok(())
+    }
+}
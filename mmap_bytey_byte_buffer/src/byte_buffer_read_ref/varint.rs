@@ -0,0 +1,15 @@
+use crate::{
+    byte_buffer::MByteBufferReader, byte_buffer_read_ref::MByteBufferReadRef,
+    byte_order::ByteOrder, error::Result, varint::VarInt,
+};
+
+impl MByteBufferReadRef<'_> for VarInt {
+    #[inline]
+    fn read_ref_from_mbuffer_with<O: ByteOrder>(
+        reader: &mut MByteBufferReader<'_>,
+    ) -> Result<Self> {
+        let mode = reader.decode_mode();
+
+        VarInt::decode(mode, || reader.read::<u8>())
+    }
+}
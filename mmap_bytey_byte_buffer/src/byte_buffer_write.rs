@@ -1,4 +1,8 @@
-use crate::{byte_buffer::MByteBuffer, error::Result};
+use crate::{
+    byte_buffer::MByteBuffer,
+    byte_order::{Big, ByteOrder, Endianness, Little, Native},
+    error::Result,
+};
 
 mod arrays;
 mod bound;
@@ -6,108 +10,221 @@ mod boxed;
 mod byte;
 mod cell;
 mod char;
+mod cmp;
+mod collections;
 mod cow;
+mod ffi;
+pub(crate) mod net;
 mod num;
 mod option;
+mod path;
 mod phantom;
+mod quantized;
 mod range;
 mod result;
 mod string;
-mod time;
+mod string_encoding;
+pub(crate) mod time;
 mod tuple;
-
+mod varint;
+
+#[cfg(feature = "chrono")]
+mod chrono;
+#[cfg(feature = "glam")]
+mod glam;
+#[cfg(feature = "mint")]
+mod mint;
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
+#[cfg(feature = "time")]
+mod offset_date_time;
+#[cfg(feature = "uuid")]
+mod uuid;
+
+pub use collections::Sorted;
+
+/// Writes a value into a [`MByteBuffer`].
+///
+/// Implementors should only override [`write_to_mbuffer_with`](Self::write_to_mbuffer_with), which is
+/// generic over the [`ByteOrder`]. The three `write_to_mbuffer*` methods are kept so older
+/// implementations that override all three of them instead keep working.
+/// At least one of the two sets has to be overridden, else the defaults call each other forever.
 pub trait MByteBufferWrite {
-    fn write_to_mbuffer(&self, buffer: &mut MByteBuffer) -> Result<()>;
-    fn write_to_mbuffer_le(&self, buffer: &mut MByteBuffer) -> Result<()>;
-    fn write_to_mbuffer_be(&self, buffer: &mut MByteBuffer) -> Result<()>;
+    /// The number of bytes every value of this type is written as, or `None` if it depends on the value.
+    const FIXED_SIZE: Option<usize> = None;
+
+    #[inline]
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        match O::ENDIANNESS {
+            Endianness::Native => self.write_to_mbuffer(buffer),
+            Endianness::Little => self.write_to_mbuffer_le(buffer),
+            Endianness::Big => self.write_to_mbuffer_be(buffer),
+        }
+    }
+
+    #[inline]
+    fn write_to_mbuffer(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        self.write_to_mbuffer_with::<Native>(buffer)
+    }
+
+    #[inline]
+    fn write_to_mbuffer_le(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        self.write_to_mbuffer_with::<Little>(buffer)
+    }
+
+    #[inline]
+    fn write_to_mbuffer_be(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        self.write_to_mbuffer_with::<Big>(buffer)
+    }
+
+    /// Returns the number of bytes this value will take up once written, in any byte order.
+    ///
+    /// Defaults to [`FIXED_SIZE`](Self::FIXED_SIZE) when set, otherwise the value is written
+    /// to a [counter](MByteBuffer::counter) to measure it.
+    #[inline]
+    fn encoded_len(&self) -> Result<usize> {
+        if let Some(size) = Self::FIXED_SIZE {
+            return Ok(size);
+        }
+
+        let mut counter = MByteBuffer::counter();
+
+        self.write_to_mbuffer(&mut counter)?;
+        Ok(counter.length())
+    }
+
+    /// Returns the number of bytes this value will take up once written with the buffer's cursor
+    /// at `position`.
+    ///
+    /// This only differs from [`encoded_len`](Self::encoded_len) for values that pad themselves
+    /// to the buffer's alignment, such as derived types with `align` fields, which override it.
+    /// Defaults to [`encoded_len`](Self::encoded_len).
+    #[inline]
+    fn encoded_len_at(&self, position: usize) -> Result<usize> {
+        let _ = position;
+
+        self.encoded_len()
+    }
+
+    /// Writes every element of the slice without a length prefix.
+    /// Primitive numbers override this to copy the whole slice at once.
+    #[inline]
+    fn write_slice_to_mbuffer_with<O: ByteOrder>(
+        slice: &[Self],
+        buffer: &mut MByteBuffer,
+    ) -> Result<()>
+    where
+        Self: Sized,
+    {
+        for e in slice {
+            e.write_to_mbuffer_with::<O>(buffer)?;
+        }
+
+        Ok(())
+    }
 }
 
-macro_rules! impl_byte_buffer_write_types {
-    ($($type:ty),*) => {
-        $(
-            impl MByteBufferWrite for $type {
-                #[inline]
-                fn write_to_mbuffer(&self, buffer: &mut MByteBuffer) -> Result<()> {
-                    unsafe {
-                        buffer.write_slice(std::slice::from_raw_parts(self as *const $type as *const u8, std::mem::size_of::<$type>()))?;
-                    }
+/// The number of bytes from `position` to the next multiple of `alignment`, 0 if `alignment` is 0.
+pub const fn padding_to(position: usize, alignment: usize) -> usize {
+    match alignment {
+        0 => 0,
+        _ => (alignment - position % alignment) % alignment,
+    }
+}
 
-                    Ok(())
-                }
+/// Sums the fixed sizes of consecutive values, `None` if any of them is not fixed.
+pub const fn fixed_size_sum(sizes: &[Option<usize>]) -> Option<usize> {
+    let mut total = 0;
+    let mut i = 0;
 
-                #[inline]
-                fn write_to_mbuffer_le(&self, buffer: &mut MByteBuffer) -> Result<()> {
-                    #[cfg(target_endian = "little")]
-                    unsafe {
-                        buffer.write_slice(std::slice::from_raw_parts(self as *const $type as *const u8, std::mem::size_of::<$type>()))?;
-                    }
+    while i < sizes.len() {
+        match sizes[i] {
+            Some(size) => total += size,
+            None => return None,
+        }
 
-                    #[cfg(not(target_endian = "little"))]
-                    unsafe {
-                        let o = self.to_le_bytes();
-                        buffer.write_slice(std::slice::from_raw_parts(&o as *const u8, std::mem::size_of::<$type>()))?;
-                    }
+        i += 1;
+    }
 
-                    Ok(())
-                }
+    Some(total)
+}
 
-                #[inline]
-                fn write_to_mbuffer_be(&self, buffer: &mut MByteBuffer) -> Result<()> {
-                    #[cfg(target_endian = "big")]
-                    unsafe {
-                        buffer.write_slice(std::slice::from_raw_parts(self as *const $type as *const u8, std::mem::size_of::<$type>()))?;
-                    }
+/// The fixed size shared by every alternative, `None` if any of them is not fixed or they differ.
+pub const fn fixed_size_same(sizes: &[Option<usize>]) -> Option<usize> {
+    if sizes.is_empty() {
+        return None;
+    }
 
-                    #[cfg(not(target_endian = "big"))]
-                    unsafe {
-                        let o = self.to_be_bytes();
-                        buffer.write_slice(std::slice::from_raw_parts(&o as *const u8, std::mem::size_of::<$type>()))?;
-                    }
+    let first = match sizes[0] {
+        Some(size) => size,
+        None => return None,
+    };
+    let mut i = 1;
 
-                    Ok(())
-                }
-            }
+    while i < sizes.len() {
+        match sizes[i] {
+            Some(size) if size == first => {}
+            _ => return None,
+        }
 
-            impl MByteBufferWrite for &$type {
-                #[inline]
-                fn write_to_mbuffer(&self, buffer: &mut MByteBuffer) -> Result<()> {
-                    unsafe {
-                        buffer.write_slice(std::slice::from_raw_parts(*self as *const $type as *const u8, std::mem::size_of::<$type>()))?;
-                    }
+        i += 1;
+    }
 
-                    Ok(())
-                }
+    Some(first)
+}
+
+/// Copies a slice of numbers into the buffer in one go, reversing the bytes of each element if `swap` is set.
+///
+/// # Safety
+/// `T` must be a primitive number without any padding bytes.
+#[inline]
+pub(crate) unsafe fn write_numbers<T>(
+    slice: &[T],
+    buffer: &mut MByteBuffer,
+    swap: bool,
+) -> Result<()> {
+    let bytes =
+        std::slice::from_raw_parts(slice.as_ptr() as *const u8, std::mem::size_of_val(slice));
+
+    if swap && std::mem::size_of::<T>() > 1 {
+        buffer.write_slice_swapped(bytes, std::mem::size_of::<T>())?;
+    } else {
+        buffer.write_slice(bytes)?;
+    }
+
+    Ok(())
+}
+
+macro_rules! impl_byte_buffer_write_types {
+    ($($type:ty),*) => {
+        $(
+            impl MByteBufferWrite for $type {
+                const FIXED_SIZE: Option<usize> = Some(std::mem::size_of::<$type>());
 
                 #[inline]
-                fn write_to_mbuffer_le(&self, buffer: &mut MByteBuffer) -> Result<()> {
-                    #[cfg(target_endian = "little")]
-                    unsafe {
-                        buffer.write_slice(std::slice::from_raw_parts(*self as *const $type as *const u8, std::mem::size_of::<$type>()))?;
-                    }
+                fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+                    let mut bytes = self.to_ne_bytes();
 
-                    #[cfg(not(target_endian = "little"))]
-                    unsafe {
-                        let o = self.to_le_bytes();
-                        buffer.write_slice(std::slice::from_raw_parts(&o as *const u8, std::mem::size_of::<$type>()))?;
+                    if O::SWAP {
+                        bytes.reverse();
                     }
 
+                    buffer.write_slice(&bytes)?;
                     Ok(())
                 }
 
                 #[inline]
-                fn write_to_mbuffer_be(&self, buffer: &mut MByteBuffer) -> Result<()> {
-                    #[cfg(target_endian = "big")]
-                    unsafe {
-                        buffer.write_slice(std::slice::from_raw_parts(*self as *const $type as *const u8, std::mem::size_of::<$type>()))?;
-                    }
+                fn write_slice_to_mbuffer_with<O: ByteOrder>(slice: &[$type], buffer: &mut MByteBuffer) -> Result<()> {
+                    unsafe { write_numbers(slice, buffer, O::SWAP) }
+                }
+            }
 
-                    #[cfg(not(target_endian = "big"))]
-                    unsafe {
-                        let o = self.to_be_bytes();
-                        buffer.write_slice(std::slice::from_raw_parts(&o as *const u8, std::mem::size_of::<$type>()))?;
-                    }
+            impl MByteBufferWrite for &$type {
+                const FIXED_SIZE: Option<usize> = Some(std::mem::size_of::<$type>());
 
-                    Ok(())
+                #[inline]
+                fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+                    (**self).write_to_mbuffer_with::<O>(buffer)
                 }
             }
         )*
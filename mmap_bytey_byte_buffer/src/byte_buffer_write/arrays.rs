@@ -1,176 +1,107 @@
-use crate::{byte_buffer::MByteBuffer, byte_buffer_write::MByteBufferWrite, error::Result};
+use crate::{
+    byte_buffer::MByteBuffer, byte_buffer_write::MByteBufferWrite, byte_order::ByteOrder,
+    error::Result,
+};
 
 impl<T: MByteBufferWrite, const N: usize> MByteBufferWrite for [T; N] {
-    #[inline]
-    fn write_to_mbuffer(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        (self.len() as u64).write_to_mbuffer(buffer)?;
-
-        for e in self {
-            e.write_to_mbuffer(buffer)?;
-        }
+    const FIXED_SIZE: Option<usize> = match T::FIXED_SIZE {
+        Some(size) => Some(8 + size * N),
+        None => None,
+    };
 
-        Ok(())
+    #[inline]
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        self.as_slice().write_to_mbuffer_with::<O>(buffer)
     }
 
     #[inline]
-    fn write_to_mbuffer_le(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        (self.len() as u64).write_to_mbuffer_le(buffer)?;
-
-        for e in self {
-            e.write_to_mbuffer_le(buffer)?;
-        }
-
-        Ok(())
+    fn encoded_len(&self) -> Result<usize> {
+        self.encoded_len_at(0)
     }
 
     #[inline]
-    fn write_to_mbuffer_be(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        (self.len() as u64).write_to_mbuffer_be(buffer)?;
-
-        for e in self {
-            e.write_to_mbuffer_be(buffer)?;
-        }
-
-        Ok(())
+    fn encoded_len_at(&self, position: usize) -> Result<usize> {
+        slice_encoded_len(self, position)
     }
 }
 
 impl<T: MByteBufferWrite> MByteBufferWrite for [T] {
     #[inline]
-    fn write_to_mbuffer(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        (self.len() as u64).write_to_mbuffer(buffer)?;
-
-        for e in self {
-            e.write_to_mbuffer(buffer)?;
-        }
-
-        Ok(())
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        (self.len() as u64).write_to_mbuffer_with::<O>(buffer)?;
+        T::write_slice_to_mbuffer_with::<O>(self, buffer)
     }
 
     #[inline]
-    fn write_to_mbuffer_le(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        (self.len() as u64).write_to_mbuffer_le(buffer)?;
-
-        for e in self {
-            e.write_to_mbuffer_le(buffer)?;
-        }
-
-        Ok(())
+    fn encoded_len(&self) -> Result<usize> {
+        self.encoded_len_at(0)
     }
 
     #[inline]
-    fn write_to_mbuffer_be(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        (self.len() as u64).write_to_mbuffer_be(buffer)?;
-
-        for e in self {
-            e.write_to_mbuffer_be(buffer)?;
-        }
-
-        Ok(())
+    fn encoded_len_at(&self, position: usize) -> Result<usize> {
+        slice_encoded_len(self, position)
     }
 }
 
-impl<'a, T: MByteBufferWrite> MByteBufferWrite for &'a [T] {
+impl<T: MByteBufferWrite> MByteBufferWrite for &[T] {
     #[inline]
-    fn write_to_mbuffer(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        (self.len() as u64).write_to_mbuffer(buffer)?;
-
-        for e in *self {
-            e.write_to_mbuffer(buffer)?;
-        }
-
-        Ok(())
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        (**self).write_to_mbuffer_with::<O>(buffer)
     }
 
     #[inline]
-    fn write_to_mbuffer_le(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        (self.len() as u64).write_to_mbuffer_le(buffer)?;
-
-        for e in *self {
-            e.write_to_mbuffer_le(buffer)?;
-        }
-
-        Ok(())
+    fn encoded_len(&self) -> Result<usize> {
+        self.encoded_len_at(0)
     }
 
     #[inline]
-    fn write_to_mbuffer_be(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        (self.len() as u64).write_to_mbuffer_be(buffer)?;
-
-        for e in *self {
-            e.write_to_mbuffer_be(buffer)?;
-        }
-
-        Ok(())
+    fn encoded_len_at(&self, position: usize) -> Result<usize> {
+        slice_encoded_len(self, position)
     }
 }
 
 impl<T: MByteBufferWrite> MByteBufferWrite for Vec<T> {
     #[inline]
-    fn write_to_mbuffer(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        (self.len() as u64).write_to_mbuffer(buffer)?;
-
-        for e in self {
-            e.write_to_mbuffer(buffer)?;
-        }
-
-        Ok(())
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        self.as_slice().write_to_mbuffer_with::<O>(buffer)
     }
 
     #[inline]
-    fn write_to_mbuffer_le(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        (self.len() as u64).write_to_mbuffer_le(buffer)?;
-
-        for e in self {
-            e.write_to_mbuffer_le(buffer)?;
-        }
-
-        Ok(())
+    fn encoded_len(&self) -> Result<usize> {
+        self.encoded_len_at(0)
     }
 
     #[inline]
-    fn write_to_mbuffer_be(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        (self.len() as u64).write_to_mbuffer_be(buffer)?;
-
-        for e in self {
-            e.write_to_mbuffer_be(buffer)?;
-        }
-
-        Ok(())
+    fn encoded_len_at(&self, position: usize) -> Result<usize> {
+        slice_encoded_len(self, position)
     }
 }
 
 impl<T: MByteBufferWrite> MByteBufferWrite for &Vec<T> {
     #[inline]
-    fn write_to_mbuffer(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        (self.len() as u64).write_to_mbuffer(buffer)?;
-
-        for e in *self {
-            e.write_to_mbuffer(buffer)?;
-        }
-
-        Ok(())
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        self.as_slice().write_to_mbuffer_with::<O>(buffer)
     }
 
     #[inline]
-    fn write_to_mbuffer_le(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        (self.len() as u64).write_to_mbuffer_le(buffer)?;
-
-        for e in *self {
-            e.write_to_mbuffer_le(buffer)?;
-        }
-
-        Ok(())
+    fn encoded_len(&self) -> Result<usize> {
+        self.encoded_len_at(0)
     }
 
     #[inline]
-    fn write_to_mbuffer_be(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        (self.len() as u64).write_to_mbuffer_be(buffer)?;
-
-        for e in *self {
-            e.write_to_mbuffer_be(buffer)?;
-        }
+    fn encoded_len_at(&self, position: usize) -> Result<usize> {
+        slice_encoded_len(self, position)
+    }
+}
 
-        Ok(())
+/// Length prefix plus every element, multiplied out directly for fixed size elements.
+/// Otherwise each element is measured at the position it is written to.
+#[inline]
+fn slice_encoded_len<T: MByteBufferWrite>(slice: &[T], position: usize) -> Result<usize> {
+    match T::FIXED_SIZE {
+        Some(size) => Ok(8 + size * slice.len()),
+        None => slice
+            .iter()
+            .try_fold(8, |len, e| Ok(len + e.encoded_len_at(position + len)?)),
     }
 }
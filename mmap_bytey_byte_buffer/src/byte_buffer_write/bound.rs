@@ -1,49 +1,43 @@
-use crate::{byte_buffer::MByteBuffer, byte_buffer_write::MByteBufferWrite, error::Result};
+use crate::{
+    byte_buffer::MByteBuffer,
+    byte_buffer_write::{fixed_size_same, fixed_size_sum, MByteBufferWrite},
+    byte_order::ByteOrder,
+    error::Result,
+};
 use std::ops::Bound;
 
 impl<T: MByteBufferWrite> MByteBufferWrite for Bound<T> {
+    const FIXED_SIZE: Option<usize> = fixed_size_same(&[
+        Some(1),
+        fixed_size_sum(&[Some(1), T::FIXED_SIZE]),
+        fixed_size_sum(&[Some(1), T::FIXED_SIZE]),
+    ]);
+
     #[inline]
-    fn write_to_mbuffer(&self, buffer: &mut MByteBuffer) -> Result<()> {
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
         match self {
-            Self::Unbounded => 0u8.write_to_mbuffer(buffer),
+            Self::Unbounded => 0u8.write_to_mbuffer_with::<O>(buffer),
             Self::Included(val) => {
-                1u8.write_to_mbuffer(buffer)?;
-                val.write_to_mbuffer(buffer)
+                1u8.write_to_mbuffer_with::<O>(buffer)?;
+                val.write_to_mbuffer_with::<O>(buffer)
             }
             Self::Excluded(val) => {
-                2u8.write_to_mbuffer(buffer)?;
-                val.write_to_mbuffer(buffer)
+                2u8.write_to_mbuffer_with::<O>(buffer)?;
+                val.write_to_mbuffer_with::<O>(buffer)
             }
         }
     }
 
     #[inline]
-    fn write_to_mbuffer_le(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        match self {
-            Self::Unbounded => 0u8.write_to_mbuffer_le(buffer),
-            Self::Included(val) => {
-                1u8.write_to_mbuffer_le(buffer)?;
-                val.write_to_mbuffer_le(buffer)
-            }
-            Self::Excluded(val) => {
-                2u8.write_to_mbuffer_le(buffer)?;
-                val.write_to_mbuffer_le(buffer)
-            }
-        }
+    fn encoded_len(&self) -> Result<usize> {
+        self.encoded_len_at(0)
     }
 
     #[inline]
-    fn write_to_mbuffer_be(&self, buffer: &mut MByteBuffer) -> Result<()> {
+    fn encoded_len_at(&self, position: usize) -> Result<usize> {
         match self {
-            Self::Unbounded => 0u8.write_to_mbuffer_be(buffer),
-            Self::Included(val) => {
-                1u8.write_to_mbuffer_be(buffer)?;
-                val.write_to_mbuffer_be(buffer)
-            }
-            Self::Excluded(val) => {
-                2u8.write_to_mbuffer_be(buffer)?;
-                val.write_to_mbuffer_be(buffer)
-            }
+            Self::Unbounded => Ok(1),
+            Self::Included(val) | Self::Excluded(val) => Ok(1 + val.encoded_len_at(position + 1)?),
         }
     }
 }
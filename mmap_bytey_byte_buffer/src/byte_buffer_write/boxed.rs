@@ -1,18 +1,62 @@
-use crate::{byte_buffer::MByteBuffer, byte_buffer_write::MByteBufferWrite, error::Result};
+use crate::{
+    byte_buffer::MByteBuffer, byte_buffer_write::MByteBufferWrite, byte_order::ByteOrder,
+    error::Result,
+};
+use std::{rc::Rc, sync::Arc};
+
+impl<T: MByteBufferWrite + ?Sized> MByteBufferWrite for Box<T> {
+    const FIXED_SIZE: Option<usize> = T::FIXED_SIZE;
+
+    #[inline]
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        self.as_ref().write_to_mbuffer_with::<O>(buffer)
+    }
+
+    #[inline]
+    fn encoded_len(&self) -> Result<usize> {
+        self.as_ref().encoded_len()
+    }
+
+    #[inline]
+    fn encoded_len_at(&self, position: usize) -> Result<usize> {
+        self.as_ref().encoded_len_at(position)
+    }
+}
+
+impl<T: MByteBufferWrite + ?Sized> MByteBufferWrite for Rc<T> {
+    const FIXED_SIZE: Option<usize> = T::FIXED_SIZE;
+
+    #[inline]
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        self.as_ref().write_to_mbuffer_with::<O>(buffer)
+    }
+
+    #[inline]
+    fn encoded_len(&self) -> Result<usize> {
+        self.as_ref().encoded_len()
+    }
+
+    #[inline]
+    fn encoded_len_at(&self, position: usize) -> Result<usize> {
+        self.as_ref().encoded_len_at(position)
+    }
+}
+
+impl<T: MByteBufferWrite + ?Sized> MByteBufferWrite for Arc<T> {
+    const FIXED_SIZE: Option<usize> = T::FIXED_SIZE;
 
-impl<T: MByteBufferWrite> MByteBufferWrite for Box<T> {
     #[inline]
-    fn write_to_mbuffer(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        self.as_ref().write_to_mbuffer(buffer)
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        self.as_ref().write_to_mbuffer_with::<O>(buffer)
     }
 
     #[inline]
-    fn write_to_mbuffer_le(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        self.as_ref().write_to_mbuffer_le(buffer)
+    fn encoded_len(&self) -> Result<usize> {
+        self.as_ref().encoded_len()
     }
 
     #[inline]
-    fn write_to_mbuffer_be(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        self.as_ref().write_to_mbuffer_be(buffer)
+    fn encoded_len_at(&self, position: usize) -> Result<usize> {
+        self.as_ref().encoded_len_at(position)
     }
 }
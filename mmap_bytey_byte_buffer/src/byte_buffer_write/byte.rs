@@ -1,126 +1,83 @@
-use crate::{byte_buffer::MByteBuffer, byte_buffer_write::MByteBufferWrite, error::Result};
-use std::slice;
+use crate::{
+    byte_buffer::MByteBuffer,
+    byte_buffer_write::{write_numbers, MByteBufferWrite},
+    byte_order::ByteOrder,
+    error::Result,
+};
 
 impl MByteBufferWrite for u8 {
+    const FIXED_SIZE: Option<usize> = Some(1);
+
     #[inline]
-    fn write_to_mbuffer(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        unsafe {
-            buffer.write_slice(slice::from_raw_parts(self as *const u8, 1))?;
-        }
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        buffer.write_slice(&[*self])?;
 
         Ok(())
     }
 
     #[inline]
-    fn write_to_mbuffer_le(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        self.write_to_mbuffer(buffer)
-    }
-
-    #[inline]
-    fn write_to_mbuffer_be(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        self.write_to_mbuffer(buffer)
+    fn write_slice_to_mbuffer_with<O: ByteOrder>(
+        slice: &[u8],
+        buffer: &mut MByteBuffer,
+    ) -> Result<()> {
+        unsafe { write_numbers(slice, buffer, false) }
     }
 }
 
 impl MByteBufferWrite for &u8 {
-    #[inline]
-    fn write_to_mbuffer(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        unsafe {
-            buffer.write_slice(slice::from_raw_parts(*self as *const u8, 1))?;
-        }
-
-        Ok(())
-    }
+    const FIXED_SIZE: Option<usize> = Some(1);
 
     #[inline]
-    fn write_to_mbuffer_le(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        self.write_to_mbuffer(buffer)
-    }
-
-    #[inline]
-    fn write_to_mbuffer_be(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        self.write_to_mbuffer(buffer)
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        (**self).write_to_mbuffer_with::<O>(buffer)
     }
 }
 
 impl MByteBufferWrite for i8 {
+    const FIXED_SIZE: Option<usize> = Some(1);
+
     #[inline]
-    fn write_to_mbuffer(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        unsafe {
-            buffer.write_slice(slice::from_raw_parts(self as *const i8 as *const u8, 1))?;
-        }
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        buffer.write_slice(&self.to_ne_bytes())?;
 
         Ok(())
     }
 
     #[inline]
-    fn write_to_mbuffer_le(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        self.write_to_mbuffer(buffer)
-    }
-
-    #[inline]
-    fn write_to_mbuffer_be(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        self.write_to_mbuffer(buffer)
+    fn write_slice_to_mbuffer_with<O: ByteOrder>(
+        slice: &[i8],
+        buffer: &mut MByteBuffer,
+    ) -> Result<()> {
+        unsafe { write_numbers(slice, buffer, false) }
     }
 }
 
 impl MByteBufferWrite for &i8 {
-    #[inline]
-    fn write_to_mbuffer(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        unsafe {
-            buffer.write_slice(slice::from_raw_parts(*self as *const i8 as *const u8, 1))?;
-        }
-
-        Ok(())
-    }
+    const FIXED_SIZE: Option<usize> = Some(1);
 
     #[inline]
-    fn write_to_mbuffer_le(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        self.write_to_mbuffer(buffer)
-    }
-
-    #[inline]
-    fn write_to_mbuffer_be(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        self.write_to_mbuffer(buffer)
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        (**self).write_to_mbuffer_with::<O>(buffer)
     }
 }
 
 impl MByteBufferWrite for bool {
+    const FIXED_SIZE: Option<usize> = Some(1);
+
     #[inline]
-    fn write_to_mbuffer(&self, buffer: &mut MByteBuffer) -> Result<()> {
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
         let data = if *self { 1u8 } else { 0u8 };
         buffer.write(data)?;
 
         Ok(())
     }
-
-    #[inline]
-    fn write_to_mbuffer_le(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        self.write_to_mbuffer(buffer)
-    }
-
-    #[inline]
-    fn write_to_mbuffer_be(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        self.write_to_mbuffer(buffer)
-    }
 }
 
 impl MByteBufferWrite for &bool {
-    #[inline]
-    fn write_to_mbuffer(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        let data = if **self { 1u8 } else { 0u8 };
-        buffer.write(data)?;
-
-        Ok(())
-    }
-
-    #[inline]
-    fn write_to_mbuffer_le(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        self.write_to_mbuffer(buffer)
-    }
+    const FIXED_SIZE: Option<usize> = Some(1);
 
     #[inline]
-    fn write_to_mbuffer_be(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        self.write_to_mbuffer(buffer)
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        (**self).write_to_mbuffer_with::<O>(buffer)
     }
 }
@@ -1,55 +1,54 @@
 use crate::{
     byte_buffer::MByteBuffer,
     byte_buffer_write::MByteBufferWrite,
+    byte_order::ByteOrder,
     error::{MByteBufferError, Result},
 };
-use std::cell::{Cell, RefCell};
+use std::cell::{Cell, Ref, RefCell};
 
 impl<T: MByteBufferWrite + Copy> MByteBufferWrite for Cell<T> {
+    const FIXED_SIZE: Option<usize> = T::FIXED_SIZE;
+
     #[inline]
-    fn write_to_mbuffer(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        self.get().write_to_mbuffer(buffer)
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        self.get().write_to_mbuffer_with::<O>(buffer)
     }
 
     #[inline]
-    fn write_to_mbuffer_le(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        self.get().write_to_mbuffer_le(buffer)
+    fn encoded_len(&self) -> Result<usize> {
+        self.get().encoded_len()
     }
 
     #[inline]
-    fn write_to_mbuffer_be(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        self.get().write_to_mbuffer_be(buffer)
+    fn encoded_len_at(&self, position: usize) -> Result<usize> {
+        self.get().encoded_len_at(position)
     }
 }
 
 impl<T: MByteBufferWrite + ?Sized> MByteBufferWrite for RefCell<T> {
+    const FIXED_SIZE: Option<usize> = T::FIXED_SIZE;
+
     #[inline]
-    fn write_to_mbuffer(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        self.try_borrow()
-            .map_err(|e| MByteBufferError::RefCellAlreadyBorrowed {
-                error: e.to_string(),
-                type_name: core::any::type_name::<RefCell<T>>(),
-            })?
-            .write_to_mbuffer(buffer)
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        try_borrow(self)?.write_to_mbuffer_with::<O>(buffer)
     }
 
     #[inline]
-    fn write_to_mbuffer_le(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        self.try_borrow()
-            .map_err(|e| MByteBufferError::RefCellAlreadyBorrowed {
-                error: e.to_string(),
-                type_name: core::any::type_name::<RefCell<T>>(),
-            })?
-            .write_to_mbuffer_le(buffer)
+    fn encoded_len(&self) -> Result<usize> {
+        try_borrow(self)?.encoded_len()
     }
 
     #[inline]
-    fn write_to_mbuffer_be(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        self.try_borrow()
-            .map_err(|e| MByteBufferError::RefCellAlreadyBorrowed {
-                error: e.to_string(),
-                type_name: core::any::type_name::<RefCell<T>>(),
-            })?
-            .write_to_mbuffer_be(buffer)
+    fn encoded_len_at(&self, position: usize) -> Result<usize> {
+        try_borrow(self)?.encoded_len_at(position)
     }
 }
+
+#[inline]
+fn try_borrow<T: ?Sized>(cell: &RefCell<T>) -> Result<Ref<'_, T>> {
+    cell.try_borrow()
+        .map_err(|e| MByteBufferError::RefCellAlreadyBorrowed {
+            error: e.to_string(),
+            type_name: core::any::type_name::<RefCell<T>>(),
+        })
+}
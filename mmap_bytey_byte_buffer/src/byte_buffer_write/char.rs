@@ -1,18 +1,13 @@
-use crate::{byte_buffer::MByteBuffer, byte_buffer_write::MByteBufferWrite, error::Result};
+use crate::{
+    byte_buffer::MByteBuffer, byte_buffer_write::MByteBufferWrite, byte_order::ByteOrder,
+    error::Result,
+};
 
 impl MByteBufferWrite for char {
-    #[inline]
-    fn write_to_mbuffer(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        u32::from(*self).write_to_mbuffer(buffer)
-    }
-
-    #[inline]
-    fn write_to_mbuffer_le(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        u32::from(*self).write_to_mbuffer_le(buffer)
-    }
+    const FIXED_SIZE: Option<usize> = Some(4);
 
     #[inline]
-    fn write_to_mbuffer_be(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        u32::from(*self).write_to_mbuffer_be(buffer)
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        u32::from(*self).write_to_mbuffer_with::<O>(buffer)
     }
 }
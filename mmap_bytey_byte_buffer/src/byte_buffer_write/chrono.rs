@@ -0,0 +1,28 @@
+use crate::{
+    byte_buffer::MByteBuffer, byte_buffer_write::MByteBufferWrite, byte_order::ByteOrder,
+    error::Result,
+};
+use ::chrono::{DateTime, Datelike, NaiveDate, Utc};
+
+/// Written like a [`SystemTime`](std::time::SystemTime): an `i64` of seconds from the unix
+/// epoch followed by a `u32` of nanoseconds. Leap seconds carry nanoseconds past one second.
+impl MByteBufferWrite for DateTime<Utc> {
+    const FIXED_SIZE: Option<usize> = Some(12);
+
+    #[inline]
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        self.timestamp().write_to_mbuffer_with::<O>(buffer)?;
+        self.timestamp_subsec_nanos()
+            .write_to_mbuffer_with::<O>(buffer)
+    }
+}
+
+/// Written as an `i32` of days since January 1st of the year 1 in the proleptic Gregorian calendar.
+impl MByteBufferWrite for NaiveDate {
+    const FIXED_SIZE: Option<usize> = Some(4);
+
+    #[inline]
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        self.num_days_from_ce().write_to_mbuffer_with::<O>(buffer)
+    }
+}
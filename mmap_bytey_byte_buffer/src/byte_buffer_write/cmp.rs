@@ -0,0 +1,34 @@
+use crate::{
+    byte_buffer::MByteBuffer, byte_buffer_write::MByteBufferWrite, byte_order::ByteOrder,
+    error::Result,
+};
+use std::cmp::{Ordering, Reverse};
+
+/// Written as an `i8` of `-1`, `0` or `1`.
+impl MByteBufferWrite for Ordering {
+    const FIXED_SIZE: Option<usize> = Some(1);
+
+    #[inline]
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        (*self as i8).write_to_mbuffer_with::<O>(buffer)
+    }
+}
+
+impl<T: MByteBufferWrite> MByteBufferWrite for Reverse<T> {
+    const FIXED_SIZE: Option<usize> = T::FIXED_SIZE;
+
+    #[inline]
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        self.0.write_to_mbuffer_with::<O>(buffer)
+    }
+
+    #[inline]
+    fn encoded_len(&self) -> Result<usize> {
+        self.0.encoded_len()
+    }
+
+    #[inline]
+    fn encoded_len_at(&self, position: usize) -> Result<usize> {
+        self.0.encoded_len_at(position)
+    }
+}
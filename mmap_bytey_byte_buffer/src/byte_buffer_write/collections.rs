@@ -0,0 +1,327 @@
+use crate::{
+    byte_buffer::MByteBuffer, byte_buffer_write::MByteBufferWrite, byte_order::ByteOrder,
+    error::Result,
+};
+use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque};
+
+/// Writes the entries of a [`HashMap`] or [`HashSet`] sorted by key, so the same contents
+/// always produce the same bytes. Reads back like the wrapped collection.
+///
+/// # Examples
+/// ```
+/// use mmap_bytey_byte_buffer::{byte_buffer::MByteBuffer, byte_buffer_write::Sorted};
+/// use std::collections::HashMap;
+///
+/// let mut buffer = MByteBuffer::new().unwrap();
+/// let map: HashMap<u32, u8> = (0..16).map(|i| (i, i as u8)).collect();
+///
+/// buffer.write(Sorted(&map)).unwrap();
+/// buffer.move_cursor(0).unwrap();
+///
+/// assert_eq!(buffer.read::<HashMap<u32, u8>>().unwrap(), map);
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Sorted<T>(pub T);
+
+/// Length prefix then every item, the same layout as a slice.
+#[inline]
+fn write_items<'i, O: ByteOrder, T: MByteBufferWrite + 'i>(
+    len: usize,
+    items: impl Iterator<Item = &'i T>,
+    buffer: &mut MByteBuffer,
+) -> Result<()> {
+    (len as u64).write_to_mbuffer_with::<O>(buffer)?;
+
+    for item in items {
+        item.write_to_mbuffer_with::<O>(buffer)?;
+    }
+
+    Ok(())
+}
+
+#[inline]
+fn write_entries<'i, O: ByteOrder, K: MByteBufferWrite + 'i, V: MByteBufferWrite + 'i>(
+    len: usize,
+    entries: impl Iterator<Item = (&'i K, &'i V)>,
+    buffer: &mut MByteBuffer,
+) -> Result<()> {
+    (len as u64).write_to_mbuffer_with::<O>(buffer)?;
+
+    for (key, value) in entries {
+        key.write_to_mbuffer_with::<O>(buffer)?;
+        value.write_to_mbuffer_with::<O>(buffer)?;
+    }
+
+    Ok(())
+}
+
+#[inline]
+fn items_encoded_len<'i, T: MByteBufferWrite + 'i>(
+    len: usize,
+    mut items: impl Iterator<Item = &'i T>,
+    position: usize,
+) -> Result<usize> {
+    match T::FIXED_SIZE {
+        Some(size) => Ok(8 + size * len),
+        None => items.try_fold(8, |total, item| {
+            Ok(total + item.encoded_len_at(position + total)?)
+        }),
+    }
+}
+
+#[inline]
+fn entries_encoded_len<'i, K: MByteBufferWrite + 'i, V: MByteBufferWrite + 'i>(
+    len: usize,
+    mut entries: impl Iterator<Item = (&'i K, &'i V)>,
+    position: usize,
+) -> Result<usize> {
+    match (K::FIXED_SIZE, V::FIXED_SIZE) {
+        (Some(key), Some(value)) => Ok(8 + (key + value) * len),
+        _ => entries.try_fold(8, |total, (key, value)| {
+            let total = total + key.encoded_len_at(position + total)?;
+
+            Ok(total + value.encoded_len_at(position + total)?)
+        }),
+    }
+}
+
+/// Implements the trait for a reference by forwarding to the referenced collection.
+macro_rules! impl_ref_write {
+    ($([$($generics:tt)*] $type:ty),* $(,)?) => {
+        $(
+            impl<$($generics)*> MByteBufferWrite for &$type {
+                #[inline]
+                fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+                    (**self).write_to_mbuffer_with::<O>(buffer)
+                }
+
+                #[inline]
+                fn encoded_len(&self) -> Result<usize> {
+                    (**self).encoded_len()
+                }
+
+                #[inline]
+                fn encoded_len_at(&self, position: usize) -> Result<usize> {
+                    (**self).encoded_len_at(position)
+                }
+            }
+        )*
+    };
+}
+
+impl<K: MByteBufferWrite, V: MByteBufferWrite, S> MByteBufferWrite for HashMap<K, V, S> {
+    #[inline]
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        write_entries::<O, K, V>(self.len(), self.iter(), buffer)
+    }
+
+    #[inline]
+    fn encoded_len(&self) -> Result<usize> {
+        self.encoded_len_at(0)
+    }
+
+    #[inline]
+    fn encoded_len_at(&self, position: usize) -> Result<usize> {
+        entries_encoded_len(self.len(), self.iter(), position)
+    }
+}
+
+impl<K: MByteBufferWrite, V: MByteBufferWrite> MByteBufferWrite for BTreeMap<K, V> {
+    #[inline]
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        write_entries::<O, K, V>(self.len(), self.iter(), buffer)
+    }
+
+    #[inline]
+    fn encoded_len(&self) -> Result<usize> {
+        self.encoded_len_at(0)
+    }
+
+    #[inline]
+    fn encoded_len_at(&self, position: usize) -> Result<usize> {
+        entries_encoded_len(self.len(), self.iter(), position)
+    }
+}
+
+impl<T: MByteBufferWrite, S> MByteBufferWrite for HashSet<T, S> {
+    #[inline]
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        write_items::<O, T>(self.len(), self.iter(), buffer)
+    }
+
+    #[inline]
+    fn encoded_len(&self) -> Result<usize> {
+        self.encoded_len_at(0)
+    }
+
+    #[inline]
+    fn encoded_len_at(&self, position: usize) -> Result<usize> {
+        items_encoded_len(self.len(), self.iter(), position)
+    }
+}
+
+impl<T: MByteBufferWrite> MByteBufferWrite for BTreeSet<T> {
+    #[inline]
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        write_items::<O, T>(self.len(), self.iter(), buffer)
+    }
+
+    #[inline]
+    fn encoded_len(&self) -> Result<usize> {
+        self.encoded_len_at(0)
+    }
+
+    #[inline]
+    fn encoded_len_at(&self, position: usize) -> Result<usize> {
+        items_encoded_len(self.len(), self.iter(), position)
+    }
+}
+
+impl<T: MByteBufferWrite> MByteBufferWrite for VecDeque<T> {
+    #[inline]
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        let (front, back) = self.as_slices();
+
+        (self.len() as u64).write_to_mbuffer_with::<O>(buffer)?;
+        T::write_slice_to_mbuffer_with::<O>(front, buffer)?;
+        T::write_slice_to_mbuffer_with::<O>(back, buffer)
+    }
+
+    #[inline]
+    fn encoded_len(&self) -> Result<usize> {
+        self.encoded_len_at(0)
+    }
+
+    #[inline]
+    fn encoded_len_at(&self, position: usize) -> Result<usize> {
+        items_encoded_len(self.len(), self.iter(), position)
+    }
+}
+
+impl<T: MByteBufferWrite> MByteBufferWrite for BinaryHeap<T> {
+    #[inline]
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        self.as_slice().write_to_mbuffer_with::<O>(buffer)
+    }
+
+    #[inline]
+    fn encoded_len(&self) -> Result<usize> {
+        self.encoded_len_at(0)
+    }
+
+    #[inline]
+    fn encoded_len_at(&self, position: usize) -> Result<usize> {
+        self.as_slice().encoded_len_at(position)
+    }
+}
+
+impl<T: MByteBufferWrite> MByteBufferWrite for LinkedList<T> {
+    #[inline]
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        write_items::<O, T>(self.len(), self.iter(), buffer)
+    }
+
+    #[inline]
+    fn encoded_len(&self) -> Result<usize> {
+        self.encoded_len_at(0)
+    }
+
+    #[inline]
+    fn encoded_len_at(&self, position: usize) -> Result<usize> {
+        items_encoded_len(self.len(), self.iter(), position)
+    }
+}
+
+impl_ref_write!(
+    [K: MByteBufferWrite, V: MByteBufferWrite, S] HashMap<K, V, S>,
+    [K: MByteBufferWrite, V: MByteBufferWrite] BTreeMap<K, V>,
+    [T: MByteBufferWrite, S] HashSet<T, S>,
+    [T: MByteBufferWrite] BTreeSet<T>,
+    [T: MByteBufferWrite] VecDeque<T>,
+    [T: MByteBufferWrite] BinaryHeap<T>,
+    [T: MByteBufferWrite] LinkedList<T>,
+);
+
+impl<K: MByteBufferWrite + Ord, V: MByteBufferWrite, S> MByteBufferWrite
+    for Sorted<&HashMap<K, V, S>>
+{
+    #[inline]
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        let mut entries: Vec<(&K, &V)> = self.0.iter().collect();
+
+        entries.sort_unstable_by_key(|(key, _)| *key);
+        write_entries::<O, K, V>(entries.len(), entries.into_iter(), buffer)
+    }
+
+    #[inline]
+    fn encoded_len(&self) -> Result<usize> {
+        self.encoded_len_at(0)
+    }
+
+    #[inline]
+    fn encoded_len_at(&self, position: usize) -> Result<usize> {
+        let mut entries: Vec<(&K, &V)> = self.0.iter().collect();
+
+        entries.sort_unstable_by_key(|(key, _)| *key);
+        entries_encoded_len(entries.len(), entries.into_iter(), position)
+    }
+}
+
+impl<T: MByteBufferWrite + Ord, S> MByteBufferWrite for Sorted<&HashSet<T, S>> {
+    #[inline]
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        let mut items: Vec<&T> = self.0.iter().collect();
+
+        items.sort_unstable();
+        write_items::<O, T>(items.len(), items.into_iter(), buffer)
+    }
+
+    #[inline]
+    fn encoded_len(&self) -> Result<usize> {
+        self.encoded_len_at(0)
+    }
+
+    #[inline]
+    fn encoded_len_at(&self, position: usize) -> Result<usize> {
+        let mut items: Vec<&T> = self.0.iter().collect();
+
+        items.sort_unstable();
+        items_encoded_len(items.len(), items.into_iter(), position)
+    }
+}
+
+impl<K: MByteBufferWrite + Ord, V: MByteBufferWrite, S> MByteBufferWrite
+    for Sorted<HashMap<K, V, S>>
+{
+    #[inline]
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        Sorted(&self.0).write_to_mbuffer_with::<O>(buffer)
+    }
+
+    #[inline]
+    fn encoded_len(&self) -> Result<usize> {
+        self.encoded_len_at(0)
+    }
+
+    #[inline]
+    fn encoded_len_at(&self, position: usize) -> Result<usize> {
+        Sorted(&self.0).encoded_len_at(position)
+    }
+}
+
+impl<T: MByteBufferWrite + Ord, S> MByteBufferWrite for Sorted<HashSet<T, S>> {
+    #[inline]
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        Sorted(&self.0).write_to_mbuffer_with::<O>(buffer)
+    }
+
+    #[inline]
+    fn encoded_len(&self) -> Result<usize> {
+        self.encoded_len_at(0)
+    }
+
+    #[inline]
+    fn encoded_len_at(&self, position: usize) -> Result<usize> {
+        Sorted(&self.0).encoded_len_at(position)
+    }
+}
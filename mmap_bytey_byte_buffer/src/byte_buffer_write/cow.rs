@@ -1,19 +1,24 @@
-use crate::{byte_buffer::MByteBuffer, byte_buffer_write::MByteBufferWrite, error::Result};
+use crate::{
+    byte_buffer::MByteBuffer, byte_buffer_write::MByteBufferWrite, byte_order::ByteOrder,
+    error::Result,
+};
 use std::borrow::Cow;
 
-impl<T: MByteBufferWrite + Clone> MByteBufferWrite for Cow<'_, T> {
+impl<T: MByteBufferWrite + ToOwned + ?Sized> MByteBufferWrite for Cow<'_, T> {
+    const FIXED_SIZE: Option<usize> = T::FIXED_SIZE;
+
     #[inline]
-    fn write_to_mbuffer(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        self.as_ref().write_to_mbuffer(buffer)
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        self.as_ref().write_to_mbuffer_with::<O>(buffer)
     }
 
     #[inline]
-    fn write_to_mbuffer_le(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        self.as_ref().write_to_mbuffer_le(buffer)
+    fn encoded_len(&self) -> Result<usize> {
+        self.as_ref().encoded_len()
     }
 
     #[inline]
-    fn write_to_mbuffer_be(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        self.as_ref().write_to_mbuffer_be(buffer)
+    fn encoded_len_at(&self, position: usize) -> Result<usize> {
+        self.as_ref().encoded_len_at(position)
     }
 }
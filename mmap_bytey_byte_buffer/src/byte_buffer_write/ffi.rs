@@ -0,0 +1,83 @@
+use crate::{
+    byte_buffer::MByteBuffer,
+    byte_buffer_write::{string::write_len_prefixed, MByteBufferWrite},
+    byte_order::ByteOrder,
+    error::Result,
+};
+use std::ffi::{CStr, CString, OsStr, OsString};
+
+/// Written like a `str` without the trailing nul.
+impl MByteBufferWrite for CStr {
+    #[inline]
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        write_len_prefixed::<O>(self.to_bytes(), buffer)
+    }
+
+    #[inline]
+    fn encoded_len(&self) -> Result<usize> {
+        Ok(std::mem::size_of::<usize>() + self.to_bytes().len())
+    }
+}
+
+/// Written like a `str`. On unix the raw bytes are kept, on other platforms the string has to
+/// be valid unicode.
+impl MByteBufferWrite for OsStr {
+    #[inline]
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        write_len_prefixed::<O>(os_str_bytes(self)?, buffer)
+    }
+
+    #[inline]
+    fn encoded_len(&self) -> Result<usize> {
+        Ok(std::mem::size_of::<usize>() + os_str_bytes(self)?.len())
+    }
+}
+
+#[cfg(unix)]
+#[inline]
+fn os_str_bytes(value: &OsStr) -> Result<&[u8]> {
+    use std::os::unix::ffi::OsStrExt;
+
+    Ok(value.as_bytes())
+}
+
+#[cfg(not(unix))]
+#[inline]
+fn os_str_bytes(value: &OsStr) -> Result<&[u8]> {
+    value
+        .to_str()
+        .map(str::as_bytes)
+        .ok_or(crate::error::MByteBufferError::NotUnicode {
+            type_name: core::any::type_name::<OsStr>(),
+        })
+}
+
+/// Implements the trait for the owned type and the references by forwarding to the borrowed type.
+macro_rules! impl_forward_write {
+    ($($type:ty => $borrowed:ty),* $(,)?) => {
+        $(
+            impl MByteBufferWrite for $type {
+                #[inline]
+                fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+                    <$borrowed as MByteBufferWrite>::write_to_mbuffer_with::<O>(self, buffer)
+                }
+
+                #[inline]
+                fn encoded_len(&self) -> Result<usize> {
+                    <$borrowed as MByteBufferWrite>::encoded_len(self)
+                }
+            }
+        )*
+    };
+}
+
+pub(crate) use impl_forward_write;
+
+impl_forward_write!(
+    &CStr => CStr,
+    CString => CStr,
+    &CString => CStr,
+    &OsStr => OsStr,
+    OsString => OsStr,
+    &OsString => OsStr,
+);
@@ -0,0 +1,50 @@
+use crate::{
+    byte_buffer::MByteBuffer, byte_buffer_write::MByteBufferWrite, byte_order::ByteOrder,
+    error::Result,
+};
+use ::glam::{
+    DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4, IVec2, IVec3, IVec4, Mat2, Mat3, Mat3A, Mat4,
+    Quat, UVec2, UVec3, UVec4, Vec2, Vec3, Vec3A, Vec4,
+};
+
+/// Implements the trait by writing the components in order, without a length prefix.
+/// Vectors and quaternions write `x, y, z, w` and matrices their columns one after another.
+macro_rules! impl_glam_write {
+    ($($type:ty: [$scalar:ty; $len:literal] => $to_array:ident),* $(,)?) => {
+        $(
+            impl MByteBufferWrite for $type {
+                const FIXED_SIZE: Option<usize> = Some($len * std::mem::size_of::<$scalar>());
+
+                #[inline]
+                fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+                    <$scalar>::write_slice_to_mbuffer_with::<O>(&self.$to_array(), buffer)
+                }
+            }
+        )*
+    };
+}
+
+impl_glam_write!(
+    Vec2: [f32; 2] => to_array,
+    Vec3: [f32; 3] => to_array,
+    Vec3A: [f32; 3] => to_array,
+    Vec4: [f32; 4] => to_array,
+    DVec2: [f64; 2] => to_array,
+    DVec3: [f64; 3] => to_array,
+    DVec4: [f64; 4] => to_array,
+    IVec2: [i32; 2] => to_array,
+    IVec3: [i32; 3] => to_array,
+    IVec4: [i32; 4] => to_array,
+    UVec2: [u32; 2] => to_array,
+    UVec3: [u32; 3] => to_array,
+    UVec4: [u32; 4] => to_array,
+    Quat: [f32; 4] => to_array,
+    DQuat: [f64; 4] => to_array,
+    Mat2: [f32; 4] => to_cols_array,
+    Mat3: [f32; 9] => to_cols_array,
+    Mat3A: [f32; 9] => to_cols_array,
+    Mat4: [f32; 16] => to_cols_array,
+    DMat2: [f64; 4] => to_cols_array,
+    DMat3: [f64; 9] => to_cols_array,
+    DMat4: [f64; 16] => to_cols_array,
+);
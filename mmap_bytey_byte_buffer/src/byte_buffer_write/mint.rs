@@ -0,0 +1,47 @@
+use crate::{
+    byte_buffer::MByteBuffer,
+    byte_buffer_write::{fixed_size_sum, MByteBufferWrite},
+    byte_order::ByteOrder,
+    error::Result,
+};
+use ::mint::{
+    ColumnMatrix2, ColumnMatrix3, ColumnMatrix4, Point2, Point3, Quaternion, RowMatrix2,
+    RowMatrix3, RowMatrix4, Vector2, Vector3, Vector4,
+};
+
+/// Implements the trait by writing the fields in order, without a length prefix.
+macro_rules! impl_mint_write {
+    ($($type:ident { $($field:ident: $field_type:ty),+ }),* $(,)?) => {
+        $(
+            impl<T: MByteBufferWrite> MByteBufferWrite for $type<T> {
+                const FIXED_SIZE: Option<usize> = fixed_size_sum(&[$(<$field_type>::FIXED_SIZE),+]);
+
+                #[inline]
+                fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+                    $(self.$field.write_to_mbuffer_with::<O>(buffer)?;)+
+                    Ok(())
+                }
+
+                #[inline]
+                fn encoded_len(&self) -> Result<usize> {
+                    Ok(0 $(+ self.$field.encoded_len()?)+)
+                }
+            }
+        )*
+    };
+}
+
+impl_mint_write!(
+    Vector2 { x: T, y: T },
+    Vector3 { x: T, y: T, z: T },
+    Vector4 { x: T, y: T, z: T, w: T },
+    Point2 { x: T, y: T },
+    Point3 { x: T, y: T, z: T },
+    Quaternion { v: Vector3<T>, s: T },
+    ColumnMatrix2 { x: Vector2<T>, y: Vector2<T> },
+    ColumnMatrix3 { x: Vector3<T>, y: Vector3<T>, z: Vector3<T> },
+    ColumnMatrix4 { x: Vector4<T>, y: Vector4<T>, z: Vector4<T>, w: Vector4<T> },
+    RowMatrix2 { x: Vector2<T>, y: Vector2<T> },
+    RowMatrix3 { x: Vector3<T>, y: Vector3<T>, z: Vector3<T> },
+    RowMatrix4 { x: Vector4<T>, y: Vector4<T>, z: Vector4<T>, w: Vector4<T> },
+);
@@ -0,0 +1,73 @@
+use crate::{
+    byte_buffer::MByteBuffer, byte_buffer_write::MByteBufferWrite, byte_order::ByteOrder,
+    error::Result,
+};
+use ::nalgebra::{Point, Quaternion, SMatrix, Scalar, Unit};
+
+/// Written as its components in column-major order, without a length prefix.
+/// This covers the fixed-size vector aliases such as `Vector3` as well.
+impl<T: Scalar + MByteBufferWrite, const R: usize, const C: usize> MByteBufferWrite
+    for SMatrix<T, R, C>
+{
+    const FIXED_SIZE: Option<usize> = match T::FIXED_SIZE {
+        Some(size) => Some(size * R * C),
+        None => None,
+    };
+
+    #[inline]
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        T::write_slice_to_mbuffer_with::<O>(self.as_slice(), buffer)
+    }
+
+    #[inline]
+    fn encoded_len(&self) -> Result<usize> {
+        match T::FIXED_SIZE {
+            Some(size) => Ok(size * R * C),
+            None => self.iter().try_fold(0, |len, e| Ok(len + e.encoded_len()?)),
+        }
+    }
+}
+
+impl<T: Scalar + MByteBufferWrite, const D: usize> MByteBufferWrite for Point<T, D> {
+    const FIXED_SIZE: Option<usize> = <SMatrix<T, D, 1>>::FIXED_SIZE;
+
+    #[inline]
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        self.coords.write_to_mbuffer_with::<O>(buffer)
+    }
+
+    #[inline]
+    fn encoded_len(&self) -> Result<usize> {
+        self.coords.encoded_len()
+    }
+}
+
+/// Written as `i, j, k, w`, the order of its coordinates.
+impl<T: Scalar + MByteBufferWrite> MByteBufferWrite for Quaternion<T> {
+    const FIXED_SIZE: Option<usize> = <SMatrix<T, 4, 1>>::FIXED_SIZE;
+
+    #[inline]
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        self.coords.write_to_mbuffer_with::<O>(buffer)
+    }
+
+    #[inline]
+    fn encoded_len(&self) -> Result<usize> {
+        self.coords.encoded_len()
+    }
+}
+
+/// Written like the value it wraps, so `UnitQuaternion` and `Unit<Vector3>` need no extra bytes.
+impl<T: MByteBufferWrite> MByteBufferWrite for Unit<T> {
+    const FIXED_SIZE: Option<usize> = T::FIXED_SIZE;
+
+    #[inline]
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        self.as_ref().write_to_mbuffer_with::<O>(buffer)
+    }
+
+    #[inline]
+    fn encoded_len(&self) -> Result<usize> {
+        self.as_ref().encoded_len()
+    }
+}
@@ -0,0 +1,105 @@
+use crate::{
+    byte_buffer::MByteBuffer,
+    byte_buffer_write::{fixed_size_sum, MByteBufferWrite},
+    byte_order::{Big, ByteOrder},
+    error::Result,
+};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+
+// Addresses are always written in network byte order, whatever byte order the rest of the
+// message uses. Octets are copied as they are and ports, `flowinfo` and `scope_id` are big endian.
+
+/// Tag written before an [`IpAddr`] or [`SocketAddr`] holding a version 4 address.
+pub(crate) const IPV4_TAG: u8 = 4;
+/// Tag written before an [`IpAddr`] or [`SocketAddr`] holding a version 6 address.
+pub(crate) const IPV6_TAG: u8 = 6;
+
+impl MByteBufferWrite for Ipv4Addr {
+    const FIXED_SIZE: Option<usize> = Some(4);
+
+    #[inline]
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        buffer.write_slice(&self.octets())?;
+        Ok(())
+    }
+}
+
+impl MByteBufferWrite for Ipv6Addr {
+    const FIXED_SIZE: Option<usize> = Some(16);
+
+    #[inline]
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        buffer.write_slice(&self.octets())?;
+        Ok(())
+    }
+}
+
+impl MByteBufferWrite for IpAddr {
+    #[inline]
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        match self {
+            IpAddr::V4(addr) => {
+                IPV4_TAG.write_to_mbuffer_with::<O>(buffer)?;
+                addr.write_to_mbuffer_with::<O>(buffer)
+            }
+            IpAddr::V6(addr) => {
+                IPV6_TAG.write_to_mbuffer_with::<O>(buffer)?;
+                addr.write_to_mbuffer_with::<O>(buffer)
+            }
+        }
+    }
+
+    #[inline]
+    fn encoded_len(&self) -> Result<usize> {
+        match self {
+            IpAddr::V4(addr) => Ok(1 + addr.encoded_len()?),
+            IpAddr::V6(addr) => Ok(1 + addr.encoded_len()?),
+        }
+    }
+}
+
+impl MByteBufferWrite for SocketAddrV4 {
+    const FIXED_SIZE: Option<usize> = fixed_size_sum(&[Ipv4Addr::FIXED_SIZE, Some(2)]);
+
+    #[inline]
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        self.ip().write_to_mbuffer_with::<O>(buffer)?;
+        self.port().write_to_mbuffer_with::<Big>(buffer)
+    }
+}
+
+impl MByteBufferWrite for SocketAddrV6 {
+    const FIXED_SIZE: Option<usize> = fixed_size_sum(&[Ipv6Addr::FIXED_SIZE, Some(10)]);
+
+    #[inline]
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        self.ip().write_to_mbuffer_with::<O>(buffer)?;
+        self.port().write_to_mbuffer_with::<Big>(buffer)?;
+        self.flowinfo().write_to_mbuffer_with::<Big>(buffer)?;
+        self.scope_id().write_to_mbuffer_with::<Big>(buffer)
+    }
+}
+
+impl MByteBufferWrite for SocketAddr {
+    #[inline]
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        match self {
+            SocketAddr::V4(addr) => {
+                IPV4_TAG.write_to_mbuffer_with::<O>(buffer)?;
+                addr.write_to_mbuffer_with::<O>(buffer)
+            }
+            SocketAddr::V6(addr) => {
+                IPV6_TAG.write_to_mbuffer_with::<O>(buffer)?;
+                addr.write_to_mbuffer_with::<O>(buffer)
+            }
+        }
+    }
+
+    #[inline]
+    fn encoded_len(&self) -> Result<usize> {
+        match self {
+            SocketAddr::V4(addr) => Ok(1 + addr.encoded_len()?),
+            SocketAddr::V6(addr) => Ok(1 + addr.encoded_len()?),
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use crate::{
+    byte_buffer::MByteBuffer, byte_buffer_write::MByteBufferWrite, byte_order::ByteOrder,
+    error::Result,
+};
+use ::time::OffsetDateTime;
+
+/// Written like a [`SystemTime`](std::time::SystemTime), an `i64` of seconds from the unix epoch
+/// and a `u32` of nanoseconds, followed by the UTC offset as an `i32` of seconds.
+impl MByteBufferWrite for OffsetDateTime {
+    const FIXED_SIZE: Option<usize> = Some(16);
+
+    #[inline]
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        self.unix_timestamp().write_to_mbuffer_with::<O>(buffer)?;
+        self.nanosecond().write_to_mbuffer_with::<O>(buffer)?;
+        self.offset()
+            .whole_seconds()
+            .write_to_mbuffer_with::<O>(buffer)
+    }
+}
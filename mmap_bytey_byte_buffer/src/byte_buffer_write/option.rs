@@ -1,71 +1,54 @@
-use crate::{byte_buffer::MByteBuffer, byte_buffer_write::MByteBufferWrite, error::Result};
+use crate::{
+    byte_buffer::MByteBuffer,
+    byte_buffer_write::{fixed_size_same, fixed_size_sum, MByteBufferWrite},
+    byte_order::ByteOrder,
+    error::Result,
+};
 
 impl<T: MByteBufferWrite> MByteBufferWrite for Option<T> {
+    const FIXED_SIZE: Option<usize> =
+        fixed_size_same(&[fixed_size_sum(&[Some(1), T::FIXED_SIZE]), Some(1)]);
+
     #[inline]
-    fn write_to_mbuffer(&self, buffer: &mut MByteBuffer) -> Result<()> {
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
         match self {
             Some(v) => {
-                1u8.write_to_mbuffer(buffer)?;
-                v.write_to_mbuffer(buffer)
+                1u8.write_to_mbuffer_with::<O>(buffer)?;
+                v.write_to_mbuffer_with::<O>(buffer)
             }
-            None => 2u8.write_to_mbuffer(buffer),
+            None => 2u8.write_to_mbuffer_with::<O>(buffer),
         }
     }
 
     #[inline]
-    fn write_to_mbuffer_le(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        match self {
-            Some(v) => {
-                1u8.write_to_mbuffer_le(buffer)?;
-                v.write_to_mbuffer_le(buffer)
-            }
-            None => 2u8.write_to_mbuffer_le(buffer),
-        }
+    fn encoded_len(&self) -> Result<usize> {
+        self.encoded_len_at(0)
     }
 
     #[inline]
-    fn write_to_mbuffer_be(&self, buffer: &mut MByteBuffer) -> Result<()> {
+    fn encoded_len_at(&self, position: usize) -> Result<usize> {
         match self {
-            Some(v) => {
-                1u8.write_to_mbuffer_be(buffer)?;
-                v.write_to_mbuffer_be(buffer)
-            }
-            None => 2u8.write_to_mbuffer_be(buffer),
+            Some(v) => Ok(1 + v.encoded_len_at(position + 1)?),
+            None => Ok(1),
         }
     }
 }
 
 impl<T: MByteBufferWrite> MByteBufferWrite for &Option<T> {
+    const FIXED_SIZE: Option<usize> = Option::<T>::FIXED_SIZE;
+
     #[inline]
-    fn write_to_mbuffer(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        match *self {
-            Some(v) => {
-                1u8.write_to_mbuffer(buffer)?;
-                v.write_to_mbuffer(buffer)
-            }
-            None => 2u8.write_to_mbuffer(buffer),
-        }
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        (**self).write_to_mbuffer_with::<O>(buffer)
     }
 
     #[inline]
-    fn write_to_mbuffer_le(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        match *self {
-            Some(v) => {
-                1u8.write_to_mbuffer_le(buffer)?;
-                v.write_to_mbuffer_le(buffer)
-            }
-            None => 2u8.write_to_mbuffer_le(buffer),
-        }
+    fn encoded_len(&self) -> Result<usize> {
+        (**self).encoded_len()
     }
 
     #[inline]
-    fn write_to_mbuffer_be(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        match *self {
-            Some(v) => {
-                1u8.write_to_mbuffer_be(buffer)?;
-                v.write_to_mbuffer_be(buffer)
-            }
-            None => 2u8.write_to_mbuffer_be(buffer),
-        }
+    fn encoded_len_at(&self, position: usize) -> Result<usize> {
+        (**self).encoded_len_at(position)
     }
 }
@@ -0,0 +1,26 @@
+use crate::{
+    byte_buffer::MByteBuffer,
+    byte_buffer_write::{ffi::impl_forward_write, MByteBufferWrite},
+    byte_order::ByteOrder,
+    error::Result,
+};
+use std::path::{Path, PathBuf};
+
+/// Written like its [`OsStr`](std::ffi::OsStr).
+impl MByteBufferWrite for Path {
+    #[inline]
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        self.as_os_str().write_to_mbuffer_with::<O>(buffer)
+    }
+
+    #[inline]
+    fn encoded_len(&self) -> Result<usize> {
+        self.as_os_str().encoded_len()
+    }
+}
+
+impl_forward_write!(
+    &Path => Path,
+    PathBuf => Path,
+    &PathBuf => Path,
+);
@@ -1,19 +1,14 @@
-use crate::{byte_buffer::MByteBuffer, byte_buffer_write::MByteBufferWrite, error::Result};
+use crate::{
+    byte_buffer::MByteBuffer, byte_buffer_write::MByteBufferWrite, byte_order::ByteOrder,
+    error::Result,
+};
 use std::marker::PhantomData;
 
 impl<T> MByteBufferWrite for PhantomData<T> {
-    #[inline]
-    fn write_to_mbuffer(&self, _buffer: &mut MByteBuffer) -> Result<()> {
-        Ok(())
-    }
-
-    #[inline]
-    fn write_to_mbuffer_le(&self, _buffer: &mut MByteBuffer) -> Result<()> {
-        Ok(())
-    }
+    const FIXED_SIZE: Option<usize> = Some(0);
 
     #[inline]
-    fn write_to_mbuffer_be(&self, _buffer: &mut MByteBuffer) -> Result<()> {
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, _buffer: &mut MByteBuffer) -> Result<()> {
         Ok(())
     }
 }
@@ -0,0 +1,87 @@
+use crate::{
+    byte_buffer::MByteBuffer,
+    byte_buffer_write::MByteBufferWrite,
+    byte_order::ByteOrder,
+    error::{MByteBufferError, Result},
+    quantized::{FixedInt, FixedPoint, QuantizeFloat, Quantized, SmallestThree, BF16, F16},
+};
+
+/// Writes the low `bytes` bytes of `value` in the byte order `O`.
+#[inline]
+pub(crate) fn write_uint<O: ByteOrder>(
+    value: u64,
+    bytes: usize,
+    buffer: &mut MByteBuffer,
+) -> Result<()> {
+    if cfg!(target_endian = "little") != O::SWAP {
+        buffer.write_slice(&value.to_le_bytes()[..bytes])?;
+    } else {
+        buffer.write_slice(&value.to_be_bytes()[8 - bytes..])?;
+    }
+
+    Ok(())
+}
+
+#[inline]
+fn nan_error<T>() -> MByteBufferError {
+    MByteBufferError::OtherError {
+        error: format!("Can not write NaN as {}", core::any::type_name::<T>()),
+    }
+}
+
+impl<T: QuantizeFloat, const MIN: i32, const MAX: i32, const BITS: u32> MByteBufferWrite
+    for Quantized<T, MIN, MAX, BITS>
+{
+    const FIXED_SIZE: Option<usize> = Some(Self::BYTES);
+
+    #[inline]
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID;
+        let steps = self.to_steps().ok_or_else(nan_error::<Self>)?;
+
+        write_uint::<O>(steps, Self::BYTES, buffer)
+    }
+}
+
+impl<I: FixedInt + MByteBufferWrite, const FRAC_BITS: u32> MByteBufferWrite
+    for FixedPoint<I, FRAC_BITS>
+{
+    const FIXED_SIZE: Option<usize> = I::FIXED_SIZE;
+
+    #[inline]
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        self.raw().write_to_mbuffer_with::<O>(buffer)
+    }
+}
+
+impl MByteBufferWrite for F16 {
+    const FIXED_SIZE: Option<usize> = Some(2);
+
+    #[inline]
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        self.to_bits().write_to_mbuffer_with::<O>(buffer)
+    }
+}
+
+impl MByteBufferWrite for BF16 {
+    const FIXED_SIZE: Option<usize> = Some(2);
+
+    #[inline]
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        self.to_bits().write_to_mbuffer_with::<O>(buffer)
+    }
+}
+
+impl<const BITS: u32> MByteBufferWrite for SmallestThree<BITS> {
+    const FIXED_SIZE: Option<usize> = Some(Self::BYTES);
+
+    #[inline]
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID;
+        let packed = self.pack().ok_or_else(nan_error::<Self>)?;
+
+        write_uint::<O>(packed, Self::BYTES, buffer)
+    }
+}
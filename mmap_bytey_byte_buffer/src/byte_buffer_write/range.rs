@@ -1,42 +1,117 @@
-use crate::{byte_buffer::MByteBuffer, byte_buffer_write::MByteBufferWrite, error::Result};
-use std::ops::{Range, RangeInclusive};
+use crate::{
+    byte_buffer::MByteBuffer,
+    byte_buffer_write::{fixed_size_sum, MByteBufferWrite},
+    byte_order::ByteOrder,
+    error::Result,
+};
+use std::ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
 
 impl<T: MByteBufferWrite> MByteBufferWrite for Range<T> {
+    const FIXED_SIZE: Option<usize> = fixed_size_sum(&[T::FIXED_SIZE, T::FIXED_SIZE]);
+
     #[inline]
-    fn write_to_mbuffer(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        self.start.write_to_mbuffer(buffer)?;
-        self.end.write_to_mbuffer(buffer)
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        self.start.write_to_mbuffer_with::<O>(buffer)?;
+        self.end.write_to_mbuffer_with::<O>(buffer)
     }
 
     #[inline]
-    fn write_to_mbuffer_le(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        self.start.write_to_mbuffer_le(buffer)?;
-        self.end.write_to_mbuffer_le(buffer)
+    fn encoded_len(&self) -> Result<usize> {
+        self.encoded_len_at(0)
     }
 
     #[inline]
-    fn write_to_mbuffer_be(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        self.start.write_to_mbuffer_be(buffer)?;
-        self.end.write_to_mbuffer_be(buffer)
+    fn encoded_len_at(&self, position: usize) -> Result<usize> {
+        let start = self.start.encoded_len_at(position)?;
+
+        Ok(start + self.end.encoded_len_at(position + start)?)
     }
 }
 
 impl<T: MByteBufferWrite> MByteBufferWrite for RangeInclusive<T> {
+    const FIXED_SIZE: Option<usize> = fixed_size_sum(&[T::FIXED_SIZE, T::FIXED_SIZE]);
+
+    #[inline]
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        self.start().write_to_mbuffer_with::<O>(buffer)?;
+        self.end().write_to_mbuffer_with::<O>(buffer)
+    }
+
+    #[inline]
+    fn encoded_len(&self) -> Result<usize> {
+        self.encoded_len_at(0)
+    }
+
+    #[inline]
+    fn encoded_len_at(&self, position: usize) -> Result<usize> {
+        let start = self.start().encoded_len_at(position)?;
+
+        Ok(start + self.end().encoded_len_at(position + start)?)
+    }
+}
+
+impl<T: MByteBufferWrite> MByteBufferWrite for RangeFrom<T> {
+    const FIXED_SIZE: Option<usize> = T::FIXED_SIZE;
+
+    #[inline]
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        self.start.write_to_mbuffer_with::<O>(buffer)
+    }
+
+    #[inline]
+    fn encoded_len(&self) -> Result<usize> {
+        self.start.encoded_len()
+    }
+
     #[inline]
-    fn write_to_mbuffer(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        self.start().write_to_mbuffer(buffer)?;
-        self.end().write_to_mbuffer(buffer)
+    fn encoded_len_at(&self, position: usize) -> Result<usize> {
+        self.start.encoded_len_at(position)
+    }
+}
+
+impl<T: MByteBufferWrite> MByteBufferWrite for RangeTo<T> {
+    const FIXED_SIZE: Option<usize> = T::FIXED_SIZE;
+
+    #[inline]
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        self.end.write_to_mbuffer_with::<O>(buffer)
     }
 
     #[inline]
-    fn write_to_mbuffer_le(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        self.start().write_to_mbuffer_le(buffer)?;
-        self.end().write_to_mbuffer_le(buffer)
+    fn encoded_len(&self) -> Result<usize> {
+        self.end.encoded_len()
     }
 
     #[inline]
-    fn write_to_mbuffer_be(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        self.start().write_to_mbuffer_be(buffer)?;
-        self.end().write_to_mbuffer_be(buffer)
+    fn encoded_len_at(&self, position: usize) -> Result<usize> {
+        self.end.encoded_len_at(position)
+    }
+}
+
+impl<T: MByteBufferWrite> MByteBufferWrite for RangeToInclusive<T> {
+    const FIXED_SIZE: Option<usize> = T::FIXED_SIZE;
+
+    #[inline]
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        self.end.write_to_mbuffer_with::<O>(buffer)
+    }
+
+    #[inline]
+    fn encoded_len(&self) -> Result<usize> {
+        self.end.encoded_len()
+    }
+
+    #[inline]
+    fn encoded_len_at(&self, position: usize) -> Result<usize> {
+        self.end.encoded_len_at(position)
+    }
+}
+
+impl MByteBufferWrite for RangeFull {
+    const FIXED_SIZE: Option<usize> = Some(0);
+
+    #[inline]
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, _buffer: &mut MByteBuffer) -> Result<()> {
+        Ok(())
     }
 }
@@ -1,159 +1,59 @@
-use crate::{byte_buffer::MByteBuffer, byte_buffer_write::MByteBufferWrite, error::Result};
+use crate::{
+    byte_buffer::MByteBuffer,
+    byte_buffer_write::{fixed_size_same, fixed_size_sum, MByteBufferWrite},
+    byte_order::ByteOrder,
+    error::Result,
+};
 
 impl<T: MByteBufferWrite, E: MByteBufferWrite> MByteBufferWrite for std::result::Result<T, E> {
+    const FIXED_SIZE: Option<usize> = fixed_size_same(&[
+        fixed_size_sum(&[Some(1), T::FIXED_SIZE]),
+        fixed_size_sum(&[Some(1), E::FIXED_SIZE]),
+    ]);
+
     #[inline]
-    fn write_to_mbuffer(&self, buffer: &mut MByteBuffer) -> Result<()> {
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
         match self {
             Ok(v) => {
-                1u8.write_to_mbuffer(buffer)?;
-                v.write_to_mbuffer(buffer)
+                1u8.write_to_mbuffer_with::<O>(buffer)?;
+                v.write_to_mbuffer_with::<O>(buffer)
             }
             Err(e) => {
-                2u8.write_to_mbuffer(buffer)?;
-                e.write_to_mbuffer(buffer)
+                2u8.write_to_mbuffer_with::<O>(buffer)?;
+                e.write_to_mbuffer_with::<O>(buffer)
             }
         }
     }
 
     #[inline]
-    fn write_to_mbuffer_le(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        match self {
-            Ok(v) => {
-                1u8.write_to_mbuffer_le(buffer)?;
-                v.write_to_mbuffer_le(buffer)
-            }
-            Err(e) => {
-                2u8.write_to_mbuffer_le(buffer)?;
-                e.write_to_mbuffer_le(buffer)
-            }
-        }
+    fn encoded_len(&self) -> Result<usize> {
+        self.encoded_len_at(0)
     }
 
     #[inline]
-    fn write_to_mbuffer_be(&self, buffer: &mut MByteBuffer) -> Result<()> {
+    fn encoded_len_at(&self, position: usize) -> Result<usize> {
         match self {
-            Ok(v) => {
-                1u8.write_to_mbuffer_be(buffer)?;
-                v.write_to_mbuffer_be(buffer)
-            }
-            Err(e) => {
-                2u8.write_to_mbuffer_be(buffer)?;
-                e.write_to_mbuffer_be(buffer)
-            }
+            Ok(v) => Ok(1 + v.encoded_len_at(position + 1)?),
+            Err(e) => Ok(1 + e.encoded_len_at(position + 1)?),
         }
     }
 }
 
 impl<T: MByteBufferWrite, E: MByteBufferWrite> MByteBufferWrite for &std::result::Result<T, E> {
-    #[inline]
-    fn write_to_mbuffer(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        match *self {
-            Ok(v) => {
-                1u8.write_to_mbuffer(buffer)?;
-                v.write_to_mbuffer(buffer)
-            }
-            Err(e) => {
-                2u8.write_to_mbuffer(buffer)?;
-                e.write_to_mbuffer(buffer)
-            }
-        }
-    }
-
-    #[inline]
-    fn write_to_mbuffer_le(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        match *self {
-            Ok(v) => {
-                1u8.write_to_mbuffer_le(buffer)?;
-                v.write_to_mbuffer_le(buffer)
-            }
-            Err(e) => {
-                2u8.write_to_mbuffer_le(buffer)?;
-                e.write_to_mbuffer_le(buffer)
-            }
-        }
-    }
-
-    #[inline]
-    fn write_to_mbuffer_be(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        match *self {
-            Ok(v) => {
-                1u8.write_to_mbuffer_be(buffer)?;
-                v.write_to_mbuffer_be(buffer)
-            }
-            Err(e) => {
-                2u8.write_to_mbuffer_be(buffer)?;
-                e.write_to_mbuffer_be(buffer)
-            }
-        }
-    }
-}
+    const FIXED_SIZE: Option<usize> = std::result::Result::<T, E>::FIXED_SIZE;
 
-impl<E: MByteBufferWrite> MByteBufferWrite for std::result::Result<(), E> {
     #[inline]
-    fn write_to_mbuffer(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        match self {
-            Ok(()) => 1u8.write_to_mbuffer(buffer),
-            Err(e) => {
-                2u8.write_to_mbuffer(buffer)?;
-                e.write_to_mbuffer(buffer)
-            }
-        }
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        (**self).write_to_mbuffer_with::<O>(buffer)
     }
 
     #[inline]
-    fn write_to_mbuffer_le(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        match self {
-            Ok(()) => 1u8.write_to_mbuffer_le(buffer),
-            Err(e) => {
-                2u8.write_to_mbuffer_le(buffer)?;
-                e.write_to_mbuffer_le(buffer)
-            }
-        }
+    fn encoded_len(&self) -> Result<usize> {
+        (**self).encoded_len()
     }
 
     #[inline]
-    fn write_to_mbuffer_be(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        match self {
-            Ok(()) => 1u8.write_to_mbuffer_be(buffer),
-            Err(e) => {
-                2u8.write_to_mbuffer_be(buffer)?;
-                e.write_to_mbuffer_be(buffer)
-            }
-        }
-    }
-}
-
-impl<E: MByteBufferWrite> MByteBufferWrite for &std::result::Result<(), E> {
-    #[inline]
-    fn write_to_mbuffer(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        match *self {
-            Ok(()) => 1u8.write_to_mbuffer(buffer),
-            Err(e) => {
-                2u8.write_to_mbuffer(buffer)?;
-                e.write_to_mbuffer(buffer)
-            }
-        }
-    }
-
-    #[inline]
-    fn write_to_mbuffer_le(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        match *self {
-            Ok(()) => 1u8.write_to_mbuffer_le(buffer),
-            Err(e) => {
-                2u8.write_to_mbuffer_le(buffer)?;
-                e.write_to_mbuffer_le(buffer)
-            }
-        }
-    }
-
-    #[inline]
-    fn write_to_mbuffer_be(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        match *self {
-            Ok(()) => 1u8.write_to_mbuffer_be(buffer),
-            Err(e) => {
-                2u8.write_to_mbuffer_be(buffer)?;
-                e.write_to_mbuffer_be(buffer)
-            }
-        }
+    fn encoded_len_at(&self, position: usize) -> Result<usize> {
+        (**self).encoded_len_at(position)
     }
 }
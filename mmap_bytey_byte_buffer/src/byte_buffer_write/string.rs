@@ -1,165 +1,68 @@
-use crate::{byte_buffer::MByteBuffer, byte_buffer_write::MByteBufferWrite, error::Result};
+use crate::{
+    byte_buffer::MByteBuffer, byte_buffer_write::MByteBufferWrite, byte_order::ByteOrder,
+    error::Result,
+};
 
 impl MByteBufferWrite for str {
     #[inline]
-    fn write_to_mbuffer(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        let bytestr = self.as_bytes();
-        let len = bytestr.len();
-        len.write_to_mbuffer(buffer)?;
-
-        if len > 0 {
-            buffer.write_slice(bytestr)?;
-        }
-
-        Ok(())
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        // UTF-8 is a byte stream, only the length prefix depends on the byte order.
+        write_len_prefixed::<O>(self.as_bytes(), buffer)
     }
 
     #[inline]
-    fn write_to_mbuffer_le(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        let bytestr = self.as_bytes();
-        let len = bytestr.len();
-        len.write_to_mbuffer_le(buffer)?;
-
-        if len > 0 {
-            buffer.write_slice(bytestr)?;
-        }
-
-        Ok(())
-    }
-
-    #[inline]
-    fn write_to_mbuffer_be(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        let bytestr = self.as_bytes();
-        let len = bytestr.len();
-        len.write_to_mbuffer_be(buffer)?;
-
-        if len > 0 {
-            buffer.write_slice(bytestr)?;
-        }
-
-        Ok(())
+    fn encoded_len(&self) -> Result<usize> {
+        Ok(std::mem::size_of::<usize>() + self.len())
     }
 }
 
-impl<'a> MByteBufferWrite for &'a str {
+impl MByteBufferWrite for &str {
     #[inline]
-    fn write_to_mbuffer(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        let bytestr = self.as_bytes();
-        let len = bytestr.len();
-        len.write_to_mbuffer(buffer)?;
-
-        if len > 0 {
-            buffer.write_slice(bytestr)?;
-        }
-
-        Ok(())
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        (**self).write_to_mbuffer_with::<O>(buffer)
     }
 
     #[inline]
-    fn write_to_mbuffer_le(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        let bytestr = self.as_bytes();
-        let len = bytestr.len();
-        len.write_to_mbuffer_le(buffer)?;
-
-        if len > 0 {
-            buffer.write_slice(bytestr)?;
-        }
-
-        Ok(())
-    }
-
-    #[inline]
-    fn write_to_mbuffer_be(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        let bytestr = self.as_bytes();
-        let len = bytestr.len();
-        len.write_to_mbuffer_be(buffer)?;
-
-        if len > 0 {
-            buffer.write_slice(bytestr)?;
-        }
-
-        Ok(())
+    fn encoded_len(&self) -> Result<usize> {
+        (**self).encoded_len()
     }
 }
 
 impl MByteBufferWrite for String {
     #[inline]
-    fn write_to_mbuffer(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        let bytestr = self.as_bytes();
-        let len = bytestr.len();
-        len.write_to_mbuffer(buffer)?;
-
-        if len > 0 {
-            buffer.write_slice(bytestr)?;
-        }
-
-        Ok(())
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        self.as_str().write_to_mbuffer_with::<O>(buffer)
     }
 
     #[inline]
-    fn write_to_mbuffer_le(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        let bytestr = self.as_bytes();
-        let len = bytestr.len();
-        len.write_to_mbuffer_le(buffer)?;
-
-        if len > 0 {
-            buffer.write_slice(bytestr)?;
-        }
-
-        Ok(())
-    }
-
-    #[inline]
-    fn write_to_mbuffer_be(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        let bytestr = self.as_bytes();
-        let len = bytestr.len();
-        len.write_to_mbuffer_be(buffer)?;
-
-        if len > 0 {
-            buffer.write_slice(bytestr)?;
-        }
-
-        Ok(())
+    fn encoded_len(&self) -> Result<usize> {
+        self.as_str().encoded_len()
     }
 }
 
 impl MByteBufferWrite for &String {
     #[inline]
-    fn write_to_mbuffer(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        let bytestr = self.as_bytes();
-        let len = bytestr.len();
-        len.write_to_mbuffer(buffer)?;
-
-        if len > 0 {
-            buffer.write_slice(bytestr)?;
-        }
-
-        Ok(())
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        self.as_str().write_to_mbuffer_with::<O>(buffer)
     }
 
     #[inline]
-    fn write_to_mbuffer_le(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        let bytestr = self.as_bytes();
-        let len = bytestr.len();
-        len.write_to_mbuffer_le(buffer)?;
-
-        if len > 0 {
-            buffer.write_slice(bytestr)?;
-        }
-
-        Ok(())
+    fn encoded_len(&self) -> Result<usize> {
+        self.as_str().encoded_len()
     }
+}
 
-    #[inline]
-    fn write_to_mbuffer_be(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        let bytestr = self.as_bytes();
-        let len = bytestr.len();
-        len.write_to_mbuffer_be(buffer)?;
-
-        if len > 0 {
-            buffer.write_slice(bytestr)?;
-        }
+/// Writes `bytes` after a `usize` length prefix, the layout shared by every string-like type.
+#[inline]
+pub(crate) fn write_len_prefixed<O: ByteOrder>(
+    bytes: &[u8],
+    buffer: &mut MByteBuffer,
+) -> Result<()> {
+    bytes.len().write_to_mbuffer_with::<O>(buffer)?;
 
-        Ok(())
+    if !bytes.is_empty() {
+        buffer.write_slice(bytes)?;
     }
+
+    Ok(())
 }
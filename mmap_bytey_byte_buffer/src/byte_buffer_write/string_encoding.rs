@@ -0,0 +1,114 @@
+use crate::{
+    byte_buffer::MByteBuffer,
+    byte_buffer_write::MByteBufferWrite,
+    byte_order::ByteOrder,
+    error::{MByteBufferError, Result},
+    string_encoding::{FixedStr, NulTerminated, StrBytes, Utf16},
+};
+
+/// Returns the bytes of the string, failing if they contain a nul.
+#[inline]
+fn checked_bytes<'a, T: StrBytes>(value: &'a T, type_name: &'static str) -> Result<&'a [u8]> {
+    let bytes = value.str_bytes();
+
+    match bytes.iter().position(|&byte| byte == 0) {
+        Some(position) => Err(MByteBufferError::EmbeddedNul {
+            position,
+            type_name,
+        }),
+        None => Ok(bytes),
+    }
+}
+
+impl<T: StrBytes> MByteBufferWrite for NulTerminated<T> {
+    #[inline]
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        let bytes = checked_bytes(&self.0, core::any::type_name::<Self>())?;
+
+        buffer.write_slice(bytes)?;
+        buffer.write_slice(&[0])?;
+
+        Ok(())
+    }
+
+    #[inline]
+    fn encoded_len(&self) -> Result<usize> {
+        Ok(checked_bytes(&self.0, core::any::type_name::<Self>())?.len() + 1)
+    }
+}
+
+impl<T: StrBytes> MByteBufferWrite for &NulTerminated<T> {
+    #[inline]
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        (**self).write_to_mbuffer_with::<O>(buffer)
+    }
+
+    #[inline]
+    fn encoded_len(&self) -> Result<usize> {
+        (**self).encoded_len()
+    }
+}
+
+impl<const N: usize, T: StrBytes> MByteBufferWrite for FixedStr<N, T> {
+    const FIXED_SIZE: Option<usize> = Some(N);
+
+    #[inline]
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        let type_name = core::any::type_name::<Self>();
+        let bytes = checked_bytes(&self.0, type_name)?;
+
+        if bytes.len() > N {
+            return Err(MByteBufferError::StringTooLong {
+                len: bytes.len(),
+                max: N,
+                type_name,
+            });
+        }
+
+        buffer.write_slice(bytes)?;
+        buffer.write_zeros(N - bytes.len())?;
+
+        Ok(())
+    }
+}
+
+impl<const N: usize, T: StrBytes> MByteBufferWrite for &FixedStr<N, T> {
+    const FIXED_SIZE: Option<usize> = Some(N);
+
+    #[inline]
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        (**self).write_to_mbuffer_with::<O>(buffer)
+    }
+}
+
+impl<T: AsRef<str>> MByteBufferWrite for Utf16<T> {
+    #[inline]
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        let text = self.0.as_ref();
+
+        buffer.write_with::<O, usize>(text.encode_utf16().count())?;
+
+        for unit in text.encode_utf16() {
+            buffer.write_with::<O, u16>(unit)?;
+        }
+
+        Ok(())
+    }
+
+    #[inline]
+    fn encoded_len(&self) -> Result<usize> {
+        Ok(std::mem::size_of::<usize>() + 2 * self.0.as_ref().encode_utf16().count())
+    }
+}
+
+impl<T: AsRef<str>> MByteBufferWrite for &Utf16<T> {
+    #[inline]
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        (**self).write_to_mbuffer_with::<O>(buffer)
+    }
+
+    #[inline]
+    fn encoded_len(&self) -> Result<usize> {
+        (**self).encoded_len()
+    }
+}
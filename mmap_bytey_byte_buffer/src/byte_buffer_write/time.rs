@@ -1,22 +1,49 @@
-use crate::{byte_buffer::MByteBuffer, byte_buffer_write::MByteBufferWrite, error::Result};
-use std::time::Duration;
+use crate::{
+    byte_buffer::MByteBuffer,
+    byte_buffer_write::MByteBufferWrite,
+    byte_order::ByteOrder,
+    error::{MByteBufferError, Result},
+};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 impl MByteBufferWrite for Duration {
-    #[inline]
-    fn write_to_mbuffer(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        self.as_secs().write_to_mbuffer(buffer)?;
-        self.subsec_nanos().write_to_mbuffer(buffer)
-    }
+    const FIXED_SIZE: Option<usize> = Some(12);
 
     #[inline]
-    fn write_to_mbuffer_le(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        self.as_secs().write_to_mbuffer_le(buffer)?;
-        self.subsec_nanos().write_to_mbuffer_le(buffer)
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        self.as_secs().write_to_mbuffer_with::<O>(buffer)?;
+        self.subsec_nanos().write_to_mbuffer_with::<O>(buffer)
     }
+}
+
+/// Written as an `i64` of whole seconds from [`UNIX_EPOCH`], rounded down, followed by a `u32`
+/// of nanoseconds past them. Times before the epoch have negative seconds.
+impl MByteBufferWrite for SystemTime {
+    const FIXED_SIZE: Option<usize> = Some(12);
 
     #[inline]
-    fn write_to_mbuffer_be(&self, buffer: &mut MByteBuffer) -> Result<()> {
-        self.as_secs().write_to_mbuffer_be(buffer)?;
-        self.subsec_nanos().write_to_mbuffer_be(buffer)
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        let (secs, nanos) = match self.duration_since(UNIX_EPOCH) {
+            Ok(after) => signed_offset(false, after)?,
+            Err(before) => signed_offset(true, before.duration())?,
+        };
+
+        secs.write_to_mbuffer_with::<O>(buffer)?;
+        nanos.write_to_mbuffer_with::<O>(buffer)
     }
 }
+
+/// Splits an offset from some origin into whole seconds, rounded down, and the nanoseconds past them.
+#[inline]
+pub(crate) fn signed_offset(negative: bool, offset: Duration) -> Result<(i64, u32)> {
+    let secs = i64::try_from(offset.as_secs()).map_err(|_| MByteBufferError::OtherError {
+        error: format!("Offset of {:?} does not fit in i64 seconds", offset),
+    })?;
+    let nanos = offset.subsec_nanos();
+
+    Ok(match (negative, nanos) {
+        (false, _) => (secs, nanos),
+        (true, 0) => (-secs, 0),
+        (true, _) => (-secs - 1, 1_000_000_000 - nanos),
+    })
+}
@@ -1,6 +1,7 @@
 use crate::{
     byte_buffer::MByteBuffer,
-    byte_buffer_write::MByteBufferWrite,
+    byte_buffer_write::{fixed_size_sum, MByteBufferWrite},
+    byte_order::ByteOrder,
     error::{MByteBufferError, Result},
 };
 
@@ -13,9 +14,11 @@ macro_rules! tuple_impls {
         $(
             impl<$($T: MByteBufferWrite),+> MByteBufferWrite for ($($T,)+)
             {
+                const FIXED_SIZE: Option<usize> = fixed_size_sum(&[$($T::FIXED_SIZE),+]);
+
                 #[inline]
-                fn write_to_mbuffer(&self, buffer: &mut MByteBuffer) -> Result<()> {
-                    $(match self.$idx.write_to_mbuffer(buffer) {
+                fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+                    $(match self.$idx.write_to_mbuffer_with::<O>(buffer) {
                         Ok(_) => {}
                         Err(e) => return Err(MByteBufferError::OtherError {
                             error: format!("{} occured at tuple write location {}", e, $idx),
@@ -25,25 +28,16 @@ macro_rules! tuple_impls {
                 }
 
                 #[inline]
-                fn write_to_mbuffer_le(&self, buffer: &mut MByteBuffer) -> Result<()> {
-                    $(match self.$idx.write_to_mbuffer_le(buffer) {
-                        Ok(_) => {}
-                        Err(e) => return Err(MByteBufferError::OtherError {
-                            error: format!("{} occured at tuple write location {}", e, $idx),
-                        })
-                    });+
-                    Ok(())
+                fn encoded_len(&self) -> Result<usize> {
+                    self.encoded_len_at(0)
                 }
 
                 #[inline]
-                fn write_to_mbuffer_be(&self, buffer: &mut MByteBuffer) -> Result<()> {
-                    $(match self.$idx.write_to_mbuffer_be(buffer) {
-                        Ok(_) => {}
-                        Err(e) => return Err(MByteBufferError::OtherError {
-                            error: format!("{} occured at tuple write location {}", e, $idx),
-                        })
-                    });+
-                    Ok(())
+                fn encoded_len_at(&self, position: usize) -> Result<usize> {
+                    let mut len = 0;
+
+                    $(len += self.$idx.encoded_len_at(position + len)?;)+
+                    Ok(len)
                 }
             }
         )+
@@ -154,3 +148,12 @@ tuple_impls! {
         (11) -> L
     }
 }
+
+impl MByteBufferWrite for () {
+    const FIXED_SIZE: Option<usize> = Some(0);
+
+    #[inline]
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, _buffer: &mut MByteBuffer) -> Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,16 @@
+use crate::{
+    byte_buffer::MByteBuffer, byte_buffer_write::MByteBufferWrite, byte_order::ByteOrder,
+    error::Result,
+};
+use ::uuid::Uuid;
+
+/// Written as its 16 bytes in RFC 4122 order, whatever the byte order.
+impl MByteBufferWrite for Uuid {
+    const FIXED_SIZE: Option<usize> = Some(16);
+
+    #[inline]
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        buffer.write_slice(self.as_bytes())?;
+        Ok(())
+    }
+}
@@ -0,0 +1,33 @@
+use crate::{
+    byte_buffer::MByteBuffer, byte_buffer_write::MByteBufferWrite, byte_order::ByteOrder,
+    error::Result, varint::VarInt,
+};
+
+impl MByteBufferWrite for VarInt {
+    #[inline]
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        let mut bytes = [0u8; VarInt::MAX_LEN];
+        let len = self.encode(&mut bytes);
+
+        buffer.write_slice(&bytes[..len])?;
+
+        Ok(())
+    }
+
+    #[inline]
+    fn encoded_len(&self) -> Result<usize> {
+        Ok(self.byte_len())
+    }
+}
+
+impl MByteBufferWrite for &VarInt {
+    #[inline]
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        (**self).write_to_mbuffer_with::<O>(buffer)
+    }
+
+    #[inline]
+    fn encoded_len(&self) -> Result<usize> {
+        Ok(self.byte_len())
+    }
+}
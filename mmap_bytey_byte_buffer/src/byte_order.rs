@@ -0,0 +1,69 @@
+/// The byte order values are written in and read back with.
+///
+/// This is implemented by the three marker types [`Native`], [`Little`] and [`Big`] and can not be
+/// implemented outside of this crate. Implementations of
+/// [`MByteBufferWrite`](crate::byte_buffer_write::MByteBufferWrite) and
+/// [`MByteBufferRead`](crate::byte_buffer_read::MByteBufferRead) take it as a type parameter,
+/// so a single generic method covers every byte order.
+///
+/// # Examples
+/// ```
+/// use mmap_bytey_byte_buffer::{byte_buffer::MByteBuffer, byte_order::Big};
+///
+/// let mut buffer = MByteBuffer::new().unwrap();
+///
+/// buffer.write_with::<Big, _>(0x0102u16).unwrap();
+/// buffer.move_cursor(0).unwrap();
+///
+/// assert_eq!(buffer.read_slice(2).unwrap(), [1, 2]);
+/// ```
+pub trait ByteOrder: private::Sealed + Copy + Default + Send + Sync + 'static {
+    const ENDIANNESS: Endianness;
+
+    /// Whether values have to be byte swapped to go from the machine's byte order to this one.
+    const SWAP: bool = match Self::ENDIANNESS {
+        Endianness::Native => false,
+        Endianness::Little => cfg!(target_endian = "big"),
+        Endianness::Big => cfg!(target_endian = "little"),
+    };
+}
+
+/// Runtime value of a [`ByteOrder`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Endianness {
+    Native,
+    Little,
+    Big,
+}
+
+/// The byte order of the machine the code runs on.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Native;
+
+/// Little endian byte order.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Little;
+
+/// Big endian byte order, also known as network byte order.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Big;
+
+impl ByteOrder for Native {
+    const ENDIANNESS: Endianness = Endianness::Native;
+}
+
+impl ByteOrder for Little {
+    const ENDIANNESS: Endianness = Endianness::Little;
+}
+
+impl ByteOrder for Big {
+    const ENDIANNESS: Endianness = Endianness::Big;
+}
+
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for super::Native {}
+    impl Sealed for super::Little {}
+    impl Sealed for super::Big {}
+}
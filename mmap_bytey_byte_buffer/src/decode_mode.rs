@@ -0,0 +1,61 @@
+use crate::error::{MByteBufferError, Result};
+
+/// How strictly values are validated while they are read from a
+/// [`MByteBuffer`](crate::byte_buffer::MByteBuffer).
+///
+/// Every buffer starts out [`Lenient`](DecodeMode::Lenient), which reads data the way earlier
+/// versions did, and can be switched to [`Strict`](DecodeMode::Strict) with
+/// [`set_decode_mode`](crate::byte_buffer::MByteBuffer::set_decode_mode). Read implementations
+/// that validate their input look the mode up with
+/// [`decode_mode`](crate::byte_buffer::MByteBuffer::decode_mode).
+///
+/// # Examples
+/// ```
+/// use mmap_bytey_byte_buffer::{byte_buffer::MByteBuffer, decode_mode::DecodeMode};
+///
+/// let mut buffer = MByteBuffer::new().unwrap();
+///
+/// buffer.write(7u8).unwrap();
+/// buffer.move_cursor(0).unwrap();
+///
+/// assert!(buffer.read::<bool>().unwrap());
+///
+/// buffer.set_decode_mode(DecodeMode::Strict).move_cursor(0).unwrap();
+///
+/// assert!(buffer.read::<bool>().is_err());
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum DecodeMode {
+    /// Bytes that are not a valid encoding of the type are rejected with an error.
+    Strict,
+    /// Invalid bytes are decoded into the closest value where there is one, so any non-zero
+    /// byte is `true`. A `char` that is not a unicode scalar value has none and is still
+    /// rejected.
+    #[default]
+    Lenient,
+}
+
+impl DecodeMode {
+    /// Decodes a `bool` from its byte.
+    ///
+    /// # Errors
+    /// - [`MByteBufferError::InvalidBool`] is returned in strict mode if the byte is not 0 or 1.
+    #[inline]
+    pub fn decode_bool(self, byte: u8) -> Result<bool> {
+        match (self, byte) {
+            (_, 0) => Ok(false),
+            (_, 1) | (DecodeMode::Lenient, _) => Ok(true),
+            (DecodeMode::Strict, byte) => Err(MByteBufferError::InvalidBool(byte)),
+        }
+    }
+
+    /// Decodes a `char` from its `u32` value.
+    ///
+    /// # Errors
+    /// - [`MByteBufferError::NotAChar`] is returned in either mode if the value is not a unicode
+    ///   scalar value.
+    #[inline]
+    pub fn decode_char(self, value: u32) -> Result<char> {
+        char::from_u32(value).ok_or(MByteBufferError::NotAChar)
+    }
+}
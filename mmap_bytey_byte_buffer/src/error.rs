@@ -46,6 +46,151 @@ pub enum MByteBufferError {
         "the value can not be a char. Maybe you have it set to the wrong position in the struct?"
     )]
     NotAChar,
+    #[error("Invalid bool byte {0}, expected 0 or 1")]
+    InvalidBool(u8),
+    #[error("Duplicate key at index {index} while reading {type_name}")]
+    DuplicateKey {
+        /// The index of the entry whose key was already read.
+        index: usize,
+        /// The type name of the map or set being decoded.
+        type_name: &'static str,
+    },
+    #[error("Invalid tag {tag} while reading {type_name}")]
+    InvalidTag {
+        /// The tag that was read.
+        tag: u64,
+        /// The type name of the value being decoded.
+        type_name: &'static str,
+    },
+    #[error("Read {read} bytes of the {expected} byte payload of {type_name}")]
+    PayloadOverrun {
+        /// The length the payload was prefixed with.
+        expected: usize,
+        /// The number of bytes the fields took up.
+        read: usize,
+        type_name: &'static str,
+    },
+    #[error("Version {version} of {type_name} is newer than the latest known version {latest}")]
+    UnsupportedVersion {
+        version: u32,
+        latest: u32,
+        type_name: &'static str,
+    },
+    #[error("Magic bytes {found:?} of {type_name} do not match {expected:?}")]
+    MagicMismatch {
+        /// The magic bytes the type starts with.
+        expected: &'static [u8],
+        /// The bytes that were read instead.
+        found: Vec<u8>,
+        type_name: &'static str,
+    },
+    #[error("{field} holds {len} elements but its count is {count}")]
+    CountMismatch {
+        /// The field, for example `Packet.items`.
+        field: &'static str,
+        count: usize,
+        len: usize,
+    },
+    #[error("Invalid varint: {reason}")]
+    InvalidVarInt {
+        /// Why the bytes are not a valid varint.
+        reason: &'static str,
+    },
+    #[error("{value} does not fit in {bits} bits")]
+    BitOverflow {
+        /// The value that was written or read.
+        value: i128,
+        /// The number of bits of the bit field.
+        bits: u32,
+    },
+    #[error("Nul byte at {position} in {type_name}")]
+    EmbeddedNul {
+        /// The position of the nul in the string's bytes.
+        position: usize,
+        /// The type name of the string being encoded.
+        type_name: &'static str,
+    },
+    #[error("String of {len} bytes does not fit in the {max} bytes of {type_name}")]
+    StringTooLong {
+        /// The length of the string in bytes.
+        len: usize,
+        /// The number of bytes the string has to fit in.
+        max: usize,
+        /// The type name of the string being encoded.
+        type_name: &'static str,
+    },
+    #[error(transparent)]
+    Utf16Error(#[from] std::string::FromUtf16Error),
+    #[error(transparent)]
+    NulError(#[from] std::ffi::NulError),
+    #[error("{type_name} is not valid unicode and can not be encoded on this platform")]
+    NotUnicode {
+        /// The type name of the OS string or path being encoded.
+        type_name: &'static str,
+    },
     #[error(transparent)]
     Mmap(#[from] mmap_rs::Error),
+    #[error("{path}: {source}")]
+    FieldError {
+        /// The path to the field that failed to decode, for example `Packet.player.items[3].id`.
+        path: String,
+        /// The error the field returned.
+        source: Box<MByteBufferError>,
+    },
+}
+
+impl MByteBufferError {
+    /// Wraps the error with the field of `type_name` it occurred in.
+    ///
+    /// If the error already carries a path, the type name at its start is replaced by
+    /// `type_name.field`, so nested types build up a path such as `Packet.player.items[3].id`.
+    #[cold]
+    pub fn in_field(self, type_name: &str, field: &str) -> Self {
+        let (rest, source) = self.split_path();
+
+        MByteBufferError::FieldError {
+            path: format!("{}.{}{}", type_name, field, rest),
+            source,
+        }
+    }
+
+    /// Wraps the error with the index of the element it occurred in.
+    #[cold]
+    pub fn at_index(self, index: usize) -> Self {
+        let (rest, source) = self.split_path();
+
+        MByteBufferError::FieldError {
+            path: format!("[{}]{}", index, rest),
+            source,
+        }
+    }
+
+    /// Returns the field path of the error if it occurred while decoding a field.
+    pub fn path(&self) -> Option<&str> {
+        match self {
+            MByteBufferError::FieldError { path, .. } => Some(path),
+            _ => None,
+        }
+    }
+
+    /// Returns the underlying error without any field path context.
+    pub fn root_cause(&self) -> &MByteBufferError {
+        match self {
+            MByteBufferError::FieldError { source, .. } => source.root_cause(),
+            error => error,
+        }
+    }
+
+    /// Splits the error into its path without the leading type name and the inner error.
+    fn split_path(self) -> (String, Box<MByteBufferError>) {
+        match self {
+            MByteBufferError::FieldError { mut path, source } => {
+                let start = path.find(['.', '[']).unwrap_or(path.len());
+
+                path.replace_range(..start, "");
+                (path, source)
+            }
+            error => (String::new(), Box::new(error)),
+        }
+    }
 }
@@ -1,5 +1,12 @@
 pub mod allocator;
+pub mod bits;
 pub mod byte_buffer;
 pub mod byte_buffer_read;
+pub mod byte_buffer_read_ref;
 pub mod byte_buffer_write;
+pub mod byte_order;
+pub mod decode_mode;
 pub mod error;
+pub mod quantized;
+pub mod string_encoding;
+pub mod varint;
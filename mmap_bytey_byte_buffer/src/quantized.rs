@@ -0,0 +1,494 @@
+//! Lossy encodings that trade precision for bandwidth.
+//!
+//! Every type here wraps the value it encodes and can be written and read like any other type.
+//! They can also be used on a field of a derived type with `#[mbytey(encode_as = "Type")]`, which
+//! converts the field with [`From`] on the way in and out.
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// Float types that [`Quantized`] can wrap.
+pub trait QuantizeFloat: private::Sealed + Copy {
+    fn to_f64(self) -> f64;
+
+    fn from_f64(value: f64) -> Self;
+}
+
+impl private::Sealed for f32 {}
+impl private::Sealed for f64 {}
+
+impl QuantizeFloat for f32 {
+    #[inline]
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+
+    #[inline]
+    fn from_f64(value: f64) -> Self {
+        value as f32
+    }
+}
+
+impl QuantizeFloat for f64 {
+    #[inline]
+    fn to_f64(self) -> f64 {
+        self
+    }
+
+    #[inline]
+    fn from_f64(value: f64) -> Self {
+        value
+    }
+}
+
+/// A float in the range `MIN..=MAX` written as an unsigned integer of `BITS` bits.
+///
+/// The value is clamped to the range and rounded to the nearest of the `2^BITS` evenly spaced
+/// steps, so the value read back is at most `(MAX - MIN) / (2 * (2^BITS - 1))` away from the
+/// value written. `MIN` and `MAX` themselves are exact. The integer takes up `BITS / 8` bytes,
+/// rounded up. `BITS` has to be between 1 and 32 and `MIN` below `MAX`, else using the type
+/// fails to compile. Writing NaN fails.
+///
+/// # Examples
+/// ```
+/// use mmap_bytey_byte_buffer::{byte_buffer::MByteBuffer, quantized::Quantized};
+///
+/// let mut buffer = MByteBuffer::new().unwrap();
+///
+/// // Steps of 2048 / 65535, about 0.03 units, stored in 2 bytes.
+/// buffer.write(Quantized::<f32, -1024, 1024, 16>(12.34)).unwrap();
+/// buffer.move_cursor(0).unwrap();
+///
+/// let value: Quantized<f32, -1024, 1024, 16> = buffer.read().unwrap();
+///
+/// assert_eq!(buffer.length(), 2);
+/// assert!((value.0 - 12.34).abs() <= 2048.0 / 65535.0 / 2.0);
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd)]
+pub struct Quantized<T, const MIN: i32, const MAX: i32, const BITS: u32>(pub T);
+
+impl<T: QuantizeFloat, const MIN: i32, const MAX: i32, const BITS: u32>
+    Quantized<T, MIN, MAX, BITS>
+{
+    pub(crate) const VALID: () = assert!(
+        BITS >= 1 && BITS <= 32 && MIN < MAX,
+        "Quantized needs 1 to 32 BITS and MIN below MAX"
+    );
+
+    /// The number of bytes the value is written as.
+    pub const BYTES: usize = BITS.div_ceil(8) as usize;
+
+    /// The largest integer the value is written as.
+    pub(crate) const STEPS: u64 = (1u64 << BITS) - 1;
+
+    /// The largest distance between a value and the value it reads back as, inside the range.
+    pub fn max_error() -> f64 {
+        (MAX as f64 - MIN as f64) / (2.0 * Self::STEPS as f64)
+    }
+
+    /// The integer the value is written as, or `None` for NaN.
+    pub(crate) fn to_steps(self) -> Option<u64> {
+        let value = self.0.to_f64();
+
+        if value.is_nan() {
+            return None;
+        }
+
+        let (min, max) = (MIN as f64, MAX as f64);
+        let normalized = (value.clamp(min, max) - min) / (max - min);
+
+        Some((normalized * Self::STEPS as f64).round() as u64)
+    }
+
+    pub(crate) fn from_steps(steps: u64) -> Self {
+        let (min, max) = (MIN as f64, MAX as f64);
+
+        Quantized(T::from_f64(
+            min + (steps as f64 / Self::STEPS as f64) * (max - min),
+        ))
+    }
+}
+
+/// Integer types that [`FixedPoint`] can store its value in.
+pub trait FixedInt: private::Sealed + Copy {
+    fn to_f64(self) -> f64;
+
+    /// Rounds to the nearest integer, saturating at the type's bounds.
+    fn from_f64(value: f64) -> Self;
+}
+
+macro_rules! impl_fixed_int {
+    ($($type:ty),*) => {
+        $(
+            impl private::Sealed for $type {}
+
+            impl FixedInt for $type {
+                #[inline]
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+
+                #[inline]
+                fn from_f64(value: f64) -> Self {
+                    value.round() as $type
+                }
+            }
+        )*
+    };
+}
+
+impl_fixed_int!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+/// A number stored as an integer `I` counting steps of `2^-FRAC_BITS`.
+///
+/// Converting a float rounds it to the nearest step, so inside the range of `I` the value read
+/// back is at most `2^-(FRAC_BITS + 1)` away from it. Values past the range saturate and NaN
+/// becomes 0. It is written exactly like `I`.
+///
+/// # Examples
+/// ```
+/// use mmap_bytey_byte_buffer::quantized::FixedPoint;
+///
+/// // 1/256 steps in an i16, covering -128.0 to just under 128.0.
+/// let speed = FixedPoint::<i16, 8>::from_f64(3.14159);
+///
+/// assert_eq!(speed.raw(), 804);
+/// assert!((speed.to_f64() - 3.14159).abs() <= 1.0 / 512.0);
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FixedPoint<I, const FRAC_BITS: u32>(I);
+
+impl<I: FixedInt, const FRAC_BITS: u32> FixedPoint<I, FRAC_BITS> {
+    const SCALE: f64 = (1u64 << FRAC_BITS) as f64;
+
+    /// Wraps the integer as it is written.
+    pub const fn from_raw(raw: I) -> Self {
+        FixedPoint(raw)
+    }
+
+    /// The integer as it is written.
+    pub const fn raw(self) -> I {
+        self.0
+    }
+
+    pub fn from_f64(value: f64) -> Self {
+        FixedPoint(I::from_f64(value * Self::SCALE))
+    }
+
+    pub fn to_f64(self) -> f64 {
+        self.0.to_f64() / Self::SCALE
+    }
+
+    pub fn from_f32(value: f32) -> Self {
+        Self::from_f64(value as f64)
+    }
+
+    pub fn to_f32(self) -> f32 {
+        self.to_f64() as f32
+    }
+}
+
+/// An `f32` written as an IEEE 754 half precision float in 2 bytes.
+///
+/// Rounds to the nearest value with an 11 bit significand, about 3 significant decimal digits,
+/// so the relative error is at most `2^-11`. Magnitudes from `2^-14` up to `65504` keep that
+/// precision, larger ones become infinity and smaller ones lose precision down to `2^-24`.
+/// Infinities and NaN are kept.
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd)]
+pub struct F16(pub f32);
+
+/// An `f32` written as a bfloat16 in 2 bytes.
+///
+/// Keeps the full range of an `f32` but only an 8 bit significand, so the relative error is at
+/// most `2^-8`. Infinities and NaN are kept.
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd)]
+pub struct BF16(pub f32);
+
+impl F16 {
+    /// The half precision bits of the value, rounded to nearest even.
+    pub fn to_bits(self) -> u16 {
+        let bits = self.0.to_bits();
+        let sign = ((bits >> 16) & 0x8000) as u16;
+        let exp = ((bits >> 23) & 0xff) as i32;
+        let man = bits & 0x7f_ffff;
+
+        if exp == 0xff {
+            // Keep NaN a NaN even if its payload is in the bits that get cut off.
+            let nan = if man != 0 {
+                0x0200 | (man >> 13) as u16
+            } else {
+                0
+            };
+
+            return sign | 0x7c00 | nan;
+        }
+
+        let half_exp = exp - 127 + 15;
+
+        if half_exp >= 0x1f {
+            return sign | 0x7c00;
+        }
+
+        if half_exp <= 0 {
+            if half_exp < -10 {
+                return sign;
+            }
+
+            let man = man | 0x80_0000;
+            let shift = (14 - half_exp) as u32;
+            let round_bit = 1 << (shift - 1);
+            let mut half_man = (man >> shift) as u16;
+
+            if man & round_bit != 0 && man & (3 * round_bit - 1) != 0 {
+                half_man += 1;
+            }
+
+            return sign | half_man;
+        }
+
+        let round_bit = 0x1000;
+        let mut half = (sign as u32) | ((half_exp as u32) << 10) | (man >> 13);
+
+        // A carry out of the significand correctly moves on to the next exponent or infinity.
+        if man & round_bit != 0 && man & (3 * round_bit - 1) != 0 {
+            half += 1;
+        }
+
+        half as u16
+    }
+
+    pub fn from_bits(half: u16) -> Self {
+        let sign = ((half & 0x8000) as u32) << 16;
+        let exp = ((half >> 10) & 0x1f) as u32;
+        let man = (half & 0x3ff) as u32;
+
+        let bits = match (exp, man) {
+            (0, 0) => sign,
+            (0, _) => {
+                // Subnormal, shift the highest set bit up to the implicit bit.
+                let shift = man.leading_zeros() - 21;
+
+                sign | ((113 - shift) << 23) | (((man << shift) & 0x3ff) << 13)
+            }
+            (0x1f, _) => sign | 0x7f80_0000 | (man << 13),
+            _ => sign | ((exp + 127 - 15) << 23) | (man << 13),
+        };
+
+        F16(f32::from_bits(bits))
+    }
+}
+
+impl BF16 {
+    /// The bfloat16 bits of the value, rounded to nearest even.
+    pub fn to_bits(self) -> u16 {
+        let bits = self.0.to_bits();
+
+        if self.0.is_nan() {
+            return ((bits >> 16) | 0x0040) as u16;
+        }
+
+        let round = ((bits >> 16) & 1) + 0x7fff;
+
+        (bits.wrapping_add(round) >> 16) as u16
+    }
+
+    pub fn from_bits(bits: u16) -> Self {
+        BF16(f32::from_bits((bits as u32) << 16))
+    }
+}
+
+/// A unit quaternion `[x, y, z, w]` written with the smallest-three method.
+///
+/// The component with the largest magnitude is dropped and rebuilt on read from the other three,
+/// whose magnitude is at most `1/sqrt(2)`. Those are quantized to `BITS` bits each and written
+/// with the 2 bit index of the dropped one in `(2 + 3 * BITS) / 8` bytes, rounded up. The default
+/// of 10 bits fits in 4 bytes. Each of the three is read back at most
+/// `1 / (sqrt(2) * (2^BITS - 1))` away from the written value, about `0.0007` at 10 bits.
+///
+/// The quaternion is expected to be normalized. `q` and `-q` are the same rotation, so the sign
+/// may flip on the way through. `BITS` has to be between 2 and 20, else using the type fails to
+/// compile. Writing NaN fails.
+///
+/// # Examples
+/// ```
+/// use mmap_bytey_byte_buffer::{byte_buffer::MByteBuffer, quantized::SmallestThree};
+///
+/// let mut buffer = MByteBuffer::new().unwrap();
+/// let rotation = [0.0, 0.7071068, 0.0, 0.7071068];
+///
+/// buffer.write(SmallestThree::<10>(rotation)).unwrap();
+/// buffer.move_cursor(0).unwrap();
+///
+/// let read: SmallestThree = buffer.read().unwrap();
+///
+/// assert_eq!(buffer.length(), 4);
+/// assert!(read.0.iter().zip(rotation).all(|(a, b)| (a - b).abs() < 0.001));
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd)]
+pub struct SmallestThree<const BITS: u32 = 10>(pub [f32; 4]);
+
+impl<const BITS: u32> SmallestThree<BITS> {
+    pub(crate) const VALID: () =
+        assert!(BITS >= 2 && BITS <= 20, "SmallestThree needs 2 to 20 BITS");
+
+    /// The number of bytes the value is written as.
+    pub const BYTES: usize = (2 + 3 * BITS).div_ceil(8) as usize;
+
+    const STEPS: u64 = (1u64 << BITS) - 1;
+    const RANGE: f32 = std::f32::consts::FRAC_1_SQRT_2;
+
+    /// The value packed into its integer, or `None` if any component is NaN.
+    pub(crate) fn pack(self) -> Option<u64> {
+        if self.0.iter().any(|c| c.is_nan()) {
+            return None;
+        }
+
+        let largest = (0..4)
+            .max_by(|&a, &b| self.0[a].abs().total_cmp(&self.0[b].abs()))
+            .unwrap_or(3);
+        let sign = if self.0[largest] < 0.0 { -1.0 } else { 1.0 };
+
+        Some(
+            (0..4)
+                .filter(|&i| i != largest)
+                .fold(largest as u64, |packed, i| {
+                    let normalized = ((self.0[i] * sign).clamp(-Self::RANGE, Self::RANGE)
+                        + Self::RANGE)
+                        / (2.0 * Self::RANGE);
+
+                    (packed << BITS) | (normalized as f64 * Self::STEPS as f64).round() as u64
+                }),
+        )
+    }
+
+    pub(crate) fn unpack(packed: u64) -> Self {
+        let largest = ((packed >> (3 * BITS)) & 0b11) as usize;
+        let mut components = [0.0f32; 4];
+        let mut sum = 0.0;
+
+        for (n, i) in (0..4).filter(|&i| i != largest).enumerate() {
+            let steps = (packed >> ((2 - n as u32) * BITS)) & Self::STEPS;
+            let component =
+                (steps as f64 / Self::STEPS as f64) as f32 * 2.0 * Self::RANGE - Self::RANGE;
+
+            components[i] = component;
+            sum += component * component;
+        }
+
+        components[largest] = (1.0 - sum).max(0.0).sqrt();
+        SmallestThree(components)
+    }
+}
+
+macro_rules! impl_float_conversions {
+    ($($float:ty),*) => {
+        $(
+            impl<const MIN: i32, const MAX: i32, const BITS: u32> From<$float>
+                for Quantized<$float, MIN, MAX, BITS>
+            {
+                #[inline]
+                fn from(value: $float) -> Self {
+                    Quantized(value)
+                }
+            }
+
+            impl<const MIN: i32, const MAX: i32, const BITS: u32> From<Quantized<$float, MIN, MAX, BITS>>
+                for $float
+            {
+                #[inline]
+                fn from(value: Quantized<$float, MIN, MAX, BITS>) -> Self {
+                    value.0
+                }
+            }
+        )*
+    };
+}
+
+impl_float_conversions!(f32, f64);
+
+impl<I: FixedInt, const FRAC_BITS: u32> From<f32> for FixedPoint<I, FRAC_BITS> {
+    #[inline]
+    fn from(value: f32) -> Self {
+        Self::from_f32(value)
+    }
+}
+
+impl<I: FixedInt, const FRAC_BITS: u32> From<FixedPoint<I, FRAC_BITS>> for f32 {
+    #[inline]
+    fn from(value: FixedPoint<I, FRAC_BITS>) -> Self {
+        value.to_f32()
+    }
+}
+
+impl<I: FixedInt, const FRAC_BITS: u32> From<f64> for FixedPoint<I, FRAC_BITS> {
+    #[inline]
+    fn from(value: f64) -> Self {
+        Self::from_f64(value)
+    }
+}
+
+impl<I: FixedInt, const FRAC_BITS: u32> From<FixedPoint<I, FRAC_BITS>> for f64 {
+    #[inline]
+    fn from(value: FixedPoint<I, FRAC_BITS>) -> Self {
+        value.to_f64()
+    }
+}
+
+impl From<f32> for F16 {
+    #[inline]
+    fn from(value: f32) -> Self {
+        F16(value)
+    }
+}
+
+impl From<F16> for f32 {
+    #[inline]
+    fn from(value: F16) -> Self {
+        value.0
+    }
+}
+
+impl From<f32> for BF16 {
+    #[inline]
+    fn from(value: f32) -> Self {
+        BF16(value)
+    }
+}
+
+impl From<BF16> for f32 {
+    #[inline]
+    fn from(value: BF16) -> Self {
+        value.0
+    }
+}
+
+impl<const BITS: u32> From<[f32; 4]> for SmallestThree<BITS> {
+    #[inline]
+    fn from(value: [f32; 4]) -> Self {
+        SmallestThree(value)
+    }
+}
+
+impl<const BITS: u32> From<SmallestThree<BITS>> for [f32; 4] {
+    #[inline]
+    fn from(value: SmallestThree<BITS>) -> Self {
+        value.0
+    }
+}
+
+#[cfg(feature = "glam")]
+impl<const BITS: u32> From<::glam::Quat> for SmallestThree<BITS> {
+    #[inline]
+    fn from(value: ::glam::Quat) -> Self {
+        SmallestThree(value.to_array())
+    }
+}
+
+#[cfg(feature = "glam")]
+impl<const BITS: u32> From<SmallestThree<BITS>> for ::glam::Quat {
+    #[inline]
+    fn from(value: SmallestThree<BITS>) -> Self {
+        ::glam::Quat::from_array(value.0)
+    }
+}
@@ -0,0 +1,149 @@
+//! String encodings other than the default `usize` length prefix followed by UTF-8.
+//!
+//! Every type here wraps the string it encodes and can be written and read like any other type.
+//! They can also be used on a field of a derived type with `#[mbytey(encode_as = "Type")]`, which
+//! converts the field with [`From`] on the way in and out.
+//!
+//! # Examples
+//! ```
+//! use mmap_bytey_byte_buffer::{
+//!     byte_buffer::MByteBuffer,
+//!     string_encoding::{FixedStr, NulTerminated, Utf16},
+//! };
+//!
+//! let mut buffer = MByteBuffer::new().unwrap();
+//!
+//! buffer.write(NulTerminated("hi")).unwrap();
+//! buffer.write(FixedStr::<4, _>("ab")).unwrap();
+//! buffer.write_le(Utf16("é")).unwrap();
+//! buffer.move_cursor(0).unwrap();
+//!
+//! assert_eq!(buffer.read_slice(3).unwrap(), b"hi\0");
+//! assert_eq!(buffer.read_slice(4).unwrap(), b"ab\0\0");
+//!
+//! let text: Utf16 = buffer.read_le().unwrap();
+//!
+//! assert_eq!(text.0, "é");
+//! ```
+
+use std::ffi::{CStr, CString};
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// String types whose bytes [`NulTerminated`] and [`FixedStr`] can write.
+pub trait StrBytes: private::Sealed {
+    /// The bytes of the string, without any nul terminator.
+    fn str_bytes(&self) -> &[u8];
+}
+
+macro_rules! impl_str_bytes {
+    ($($type:ty => |$value:ident| $bytes:expr),* $(,)?) => {
+        $(
+            impl private::Sealed for $type {}
+
+            impl StrBytes for $type {
+                #[inline]
+                fn str_bytes(&self) -> &[u8] {
+                    let $value = self;
+
+                    $bytes
+                }
+            }
+        )*
+    };
+}
+
+impl_str_bytes!(
+    String => |value| value.as_bytes(),
+    &str => |value| value.as_bytes(),
+    Vec<u8> => |value| value,
+    &[u8] => |value| value,
+    CString => |value| value.to_bytes(),
+    &CStr => |value| value.to_bytes(),
+);
+
+/// A string written as its bytes followed by a nul byte, like a C string.
+///
+/// Writing fails with [`EmbeddedNul`](crate::error::MByteBufferError::EmbeddedNul) if the
+/// string contains a nul. Reading takes everything up to the first nul and fails with
+/// [`ReadOutOfBounds`](crate::error::MByteBufferError::ReadOutOfBounds) if the buffer ends first.
+/// `NulTerminated<&str>`, `NulTerminated<&[u8]>` and `NulTerminated<&CStr>` can be read without
+/// copying with [`read_ref`](crate::byte_buffer::MByteBuffer::read_ref).
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NulTerminated<T = String>(pub T);
+
+/// A string zero padded to exactly `N` bytes, like a `char[N]` field in C.
+///
+/// The string may take up all `N` bytes, in which case there is no nul. Writing fails with
+/// [`StringTooLong`](crate::error::MByteBufferError::StringTooLong) if the string is longer
+/// than `N` bytes and with [`EmbeddedNul`](crate::error::MByteBufferError::EmbeddedNul) if it
+/// contains a nul. Reading takes the bytes up to the first nul.
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FixedStr<const N: usize, T = String>(pub T);
+
+/// A string written as a `usize` count of UTF-16 code units followed by the code units.
+///
+/// The count and every code unit are written in the requested byte order, so Windows tools
+/// expecting UTF-16LE are served by `write_le`/`read_le`. Reading fails with
+/// [`Utf16Error`](crate::error::MByteBufferError::Utf16Error) on unpaired surrogates.
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Utf16<T = String>(pub T);
+
+impl<T> From<T> for NulTerminated<T> {
+    #[inline]
+    fn from(value: T) -> Self {
+        NulTerminated(value)
+    }
+}
+
+impl<const N: usize, T> From<T> for FixedStr<N, T> {
+    #[inline]
+    fn from(value: T) -> Self {
+        FixedStr(value)
+    }
+}
+
+impl<T> From<T> for Utf16<T> {
+    #[inline]
+    fn from(value: T) -> Self {
+        Utf16(value)
+    }
+}
+
+macro_rules! impl_into_inner {
+    ($(<$($lifetime:lifetime),*> $type:ty),* $(,)?) => {
+        $(
+            impl<$($lifetime),*> From<NulTerminated<$type>> for $type {
+                #[inline]
+                fn from(value: NulTerminated<$type>) -> Self {
+                    value.0
+                }
+            }
+
+            impl<$($lifetime,)* const N: usize> From<FixedStr<N, $type>> for $type {
+                #[inline]
+                fn from(value: FixedStr<N, $type>) -> Self {
+                    value.0
+                }
+            }
+        )*
+    };
+}
+
+impl_into_inner!(
+    <> String,
+    <> Vec<u8>,
+    <> CString,
+    <'a> &'a str,
+    <'a> &'a [u8],
+    <'a> &'a CStr,
+);
+
+impl From<Utf16<String>> for String {
+    #[inline]
+    fn from(value: Utf16<String>) -> Self {
+        value.0
+    }
+}
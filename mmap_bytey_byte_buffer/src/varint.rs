@@ -0,0 +1,114 @@
+use crate::{
+    decode_mode::DecodeMode,
+    error::{MByteBufferError, Result},
+};
+
+/// An unsigned integer written in 1 to 10 bytes as an LEB128 variable length integer.
+///
+/// Each byte holds 7 bits of the value, least significant group first, with the high bit set
+/// on every byte but the last, so values below 128 take a single byte. The encoding does not
+/// depend on the byte order. In [`Strict`](DecodeMode::Strict) mode reading rejects encodings
+/// that are longer than needed, such as `[0x80, 0x00]` for 0.
+///
+/// # Examples
+/// ```
+/// use mmap_bytey_byte_buffer::{byte_buffer::MByteBuffer, varint::VarInt};
+///
+/// let mut buffer = MByteBuffer::new().unwrap();
+///
+/// buffer.write(VarInt(300)).unwrap();
+/// buffer.move_cursor(0).unwrap();
+///
+/// assert_eq!(buffer.read_slice(2).unwrap(), [0xac, 0x02]);
+///
+/// buffer.move_cursor(0).unwrap();
+///
+/// assert_eq!(buffer.read::<VarInt>().unwrap(), VarInt(300));
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct VarInt(pub u64);
+
+impl VarInt {
+    /// The largest number of bytes a value takes.
+    pub const MAX_LEN: usize = 10;
+
+    /// The number of bytes the value is written as.
+    pub const fn byte_len(self) -> usize {
+        let bits = 64 - self.0.leading_zeros() as usize;
+
+        if bits == 0 {
+            1
+        } else {
+            bits.div_ceil(7)
+        }
+    }
+
+    /// Encodes the value into `bytes`, returning the number of bytes used.
+    pub(crate) fn encode(self, bytes: &mut [u8; Self::MAX_LEN]) -> usize {
+        let mut value = self.0;
+        let mut len = 0;
+
+        loop {
+            let byte = (value & 0x7f) as u8;
+
+            value >>= 7;
+
+            if value == 0 {
+                bytes[len] = byte;
+                return len + 1;
+            }
+
+            bytes[len] = byte | 0x80;
+            len += 1;
+        }
+    }
+
+    /// Decodes a value from the bytes returned by `next_byte`.
+    pub(crate) fn decode(
+        mode: DecodeMode,
+        mut next_byte: impl FnMut() -> Result<u8>,
+    ) -> Result<Self> {
+        let mut value = 0u64;
+
+        for index in 0..Self::MAX_LEN {
+            let byte = next_byte()?;
+            let group = (byte & 0x7f) as u64;
+
+            if index == Self::MAX_LEN - 1 && byte > 1 {
+                return Err(MByteBufferError::InvalidVarInt {
+                    reason: "the value does not fit in a u64",
+                });
+            }
+
+            value |= group << (7 * index);
+
+            if byte & 0x80 == 0 {
+                if mode == DecodeMode::Strict && index > 0 && byte == 0 {
+                    return Err(MByteBufferError::InvalidVarInt {
+                        reason: "the value is not encoded in as few bytes as possible",
+                    });
+                }
+
+                return Ok(VarInt(value));
+            }
+        }
+
+        Err(MByteBufferError::InvalidVarInt {
+            reason: "the value is longer than 10 bytes",
+        })
+    }
+}
+
+impl From<u64> for VarInt {
+    #[inline]
+    fn from(value: u64) -> Self {
+        VarInt(value)
+    }
+}
+
+impl From<VarInt> for u64 {
+    #[inline]
+    fn from(value: VarInt) -> Self {
+        value.0
+    }
+}
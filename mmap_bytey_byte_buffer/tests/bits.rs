@@ -0,0 +1,182 @@
+use mmap_bytey_byte_buffer::{
+    bits::{LsbFirst, MsbFirst},
+    byte_buffer::MByteBuffer,
+    decode_mode::DecodeMode,
+    error::MByteBufferError,
+};
+
+#[test]
+fn test_msb_first_write_read() {
+    let mut buffer = MByteBuffer::new().unwrap();
+    let mut bits = buffer.bit_writer::<MsbFirst>();
+
+    bits.write_bool(true).unwrap();
+    bits.write_bits(0b010, 3).unwrap();
+    bits.write_bits(0xabc, 12).unwrap();
+    bits.write(&-3i32, 5).unwrap();
+    bits.write_bits(u64::MAX, 64).unwrap();
+
+    assert_eq!(bits.bit_offset(), 5);
+
+    bits.finish().unwrap();
+
+    assert_eq!(buffer.length(), 11);
+
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(
+        buffer.read_slice(3).unwrap(),
+        [0b1010_1010, 0b1011_1100, 0b1110_1111]
+    );
+
+    buffer.move_cursor(0).unwrap();
+
+    let mut bits = buffer.bit_reader::<MsbFirst>();
+
+    assert!(bits.read_bool().unwrap());
+    assert_eq!(bits.read_bits(3).unwrap(), 0b010);
+    assert_eq!(bits.read::<u16>(12).unwrap(), 0xabc);
+    assert_eq!(bits.read::<i32>(5).unwrap(), -3);
+    assert_eq!(bits.read_bits(64).unwrap(), u64::MAX);
+    assert_eq!(buffer.cursor(), 11);
+}
+
+#[test]
+fn test_lsb_first_write_read() {
+    let mut buffer = MByteBuffer::new().unwrap();
+    let mut bits = buffer.bit_writer::<LsbFirst>();
+
+    bits.write_bits(0b101, 3).unwrap();
+    bits.write(&false, 1).unwrap();
+    bits.write_bits(0x1ff, 9).unwrap();
+    bits.finish().unwrap();
+
+    assert_eq!(buffer.length(), 2);
+
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read_slice(2).unwrap(), [0b1111_0101, 0b0001_1111]);
+
+    buffer.move_cursor(0).unwrap();
+
+    let mut bits = buffer.bit_reader::<LsbFirst>();
+
+    assert_eq!(bits.read_bits(3).unwrap(), 0b101);
+    assert!(!bits.read::<bool>(1).unwrap());
+    assert_eq!(bits.read_bits(9).unwrap(), 0x1ff);
+}
+
+#[test]
+fn test_bits_align() {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    {
+        let mut bits = buffer.bit_writer::<MsbFirst>();
+
+        bits.write_bits(1, 1).unwrap();
+        bits.align().unwrap();
+        bits.align().unwrap();
+        bits.write_bits(0b11, 2).unwrap();
+    }
+
+    buffer.write(0xffu8).unwrap();
+
+    assert_eq!(buffer.length(), 3);
+
+    buffer.move_cursor(0).unwrap();
+
+    let mut bits = buffer.bit_reader::<MsbFirst>();
+
+    assert_eq!(bits.read_bits(1).unwrap(), 1);
+    assert_eq!(bits.align().read_bits(2).unwrap(), 0b11);
+    assert_eq!(bits.bit_offset(), 2);
+    assert_eq!(buffer.read::<u8>().unwrap(), 0xff);
+}
+
+#[test]
+fn test_bits_errors() {
+    let mut buffer = MByteBuffer::new().unwrap();
+    let mut bits = buffer.bit_writer::<MsbFirst>();
+
+    assert!(matches!(
+        bits.write_bits(8, 3),
+        Err(MByteBufferError::BitOverflow { value: 8, bits: 3 })
+    ));
+    assert!(matches!(
+        bits.write(&4i8, 3),
+        Err(MByteBufferError::BitOverflow { value: 4, bits: 3 })
+    ));
+    assert!(bits.write(&-4i8, 3).is_ok());
+    bits.write_bits(0b11, 2).unwrap();
+    bits.finish().unwrap();
+
+    buffer
+        .set_decode_mode(DecodeMode::Strict)
+        .move_cursor(0)
+        .unwrap();
+
+    let mut bits = buffer.bit_reader::<MsbFirst>();
+
+    assert_eq!(bits.read::<i8>(3).unwrap(), -4);
+    assert!(matches!(
+        bits.read::<bool>(2),
+        Err(MByteBufferError::InvalidBool(3))
+    ));
+    assert!(matches!(
+        bits.read_bits(8),
+        Err(MByteBufferError::ReadOutOfBounds { .. })
+    ));
+}
+
+#[test]
+fn test_reader_bits() {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer.write_slice(&[0b0110_0000, 7]).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    let mut reader = buffer.reader();
+
+    assert_eq!(reader.bit_reader::<MsbFirst>().read_bits(3).unwrap(), 0b011);
+    assert_eq!(reader.read::<u8>().unwrap(), 7);
+}
+
+#[test]
+fn test_bool_decode_mode() {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer.write_slice(&[0b1000_0000, 0xff, 0xff]).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    let mut bits = buffer.bit_reader::<MsbFirst>();
+
+    assert!(bits.read::<bool>(2).unwrap());
+    assert!(!bits.read::<bool>(2).unwrap());
+    assert!(bits.read::<bool>(16).unwrap());
+
+    buffer
+        .set_decode_mode(DecodeMode::Strict)
+        .move_cursor(0)
+        .unwrap();
+
+    let mut bits = buffer.bit_reader::<MsbFirst>();
+
+    assert!(matches!(
+        bits.read::<bool>(2),
+        Err(MByteBufferError::InvalidBool(2))
+    ));
+    assert!(!bits.read::<bool>(2).unwrap());
+    assert!(matches!(
+        bits.read::<bool>(16),
+        Err(MByteBufferError::InvalidBool(u8::MAX))
+    ));
+
+    buffer.move_cursor(0).unwrap();
+
+    let mut reader = buffer.reader();
+
+    assert!(matches!(
+        reader.bit_reader::<MsbFirst>().read::<bool>(2),
+        Err(MByteBufferError::InvalidBool(2))
+    ));
+}
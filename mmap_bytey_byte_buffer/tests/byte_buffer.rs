@@ -1,3 +1,4 @@
+use mmap_bytey_byte_buffer::allocator::BUFFER_SIZE;
 use mmap_bytey_byte_buffer::byte_buffer::MByteBuffer;
 use mmap_bytey_byte_buffer::error::MByteBufferError;
 
@@ -23,7 +24,7 @@ fn test_max_capacity() {
 fn test_capacity() {
     let buffer = MByteBuffer::new().unwrap();
 
-    assert_eq!(buffer.capacity(), 1024);
+    assert_eq!(buffer.capacity(), BUFFER_SIZE);
 }
 
 #[test]
@@ -32,7 +33,7 @@ fn test_write_slice_capacity() {
     let i: u64 = u64::MAX / 2;
     let i2: u16 = u16::MAX / 2;
 
-    assert_eq!(buffer.capacity(), 1024);
+    assert_eq!(buffer.capacity(), BUFFER_SIZE);
 
     let _ = buffer.write_slice(&i.to_ne_bytes());
     assert_eq!(buffer.cursor(), 8);
@@ -101,6 +102,41 @@ fn test_read_slice_bounds() {
     }
 }
 
+#[test]
+fn test_read_slice_overflow() {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    let _ = buffer.write(0u32);
+    let _ = buffer.move_cursor(2);
+
+    assert!(matches!(
+        buffer.read_slice(usize::MAX),
+        Err(MByteBufferError::ReadOutOfBounds {
+            end: usize::MAX,
+            ..
+        })
+    ));
+    assert!(matches!(
+        buffer.slice_from(2, usize::MAX),
+        Err(MByteBufferError::ReadOutOfBounds {
+            end: usize::MAX,
+            ..
+        })
+    ));
+
+    let mut reader = buffer.reader();
+
+    assert!(matches!(
+        reader.read_slice(usize::MAX),
+        Err(MByteBufferError::ReadOutOfBounds {
+            start: 2,
+            end: usize::MAX,
+            ..
+        })
+    ));
+    assert_eq!(reader.cursor(), 2);
+}
+
 #[test]
 fn test_read_slice_cursor() {
     let mut buffer = MByteBuffer::new().unwrap();
@@ -113,6 +149,90 @@ fn test_read_slice_cursor() {
     assert_eq!(buffer.cursor(), 2);
 }
 
+#[test]
+fn test_skip() {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer.write_slice(&[1, 2, 3]).unwrap();
+    buffer.move_cursor(0).unwrap();
+    buffer.skip(2).unwrap();
+
+    assert_eq!(buffer.cursor(), 2);
+    assert!(matches!(
+        buffer.skip(2),
+        Err(MByteBufferError::ReadOutOfBounds {
+            length: 3,
+            start: 2,
+            end: 4
+        })
+    ));
+    assert_eq!(buffer.cursor(), 2);
+
+    buffer.move_cursor(0).unwrap();
+
+    let mut reader = buffer.reader();
+
+    reader.skip(1).unwrap();
+
+    assert_eq!(reader.read::<u8>().unwrap(), 2);
+    assert!(reader.skip(2).is_err());
+}
+
+#[test]
+fn test_align_to() {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer.write_zeros(9).unwrap();
+    buffer.move_cursor(1).unwrap();
+    buffer.align_to(4).unwrap();
+
+    assert_eq!(buffer.cursor(), 4);
+
+    buffer
+        .align_to(4)
+        .unwrap()
+        .align_to(1)
+        .unwrap()
+        .align_to(0)
+        .unwrap();
+
+    assert_eq!(buffer.cursor(), 4);
+
+    buffer.move_cursor(5).unwrap();
+
+    let mut reader = buffer.reader();
+
+    reader.align_to(8).unwrap();
+
+    assert_eq!(reader.cursor(), 8);
+    assert!(reader.align_to(16).is_err());
+}
+
+#[test]
+fn test_write_zeros() {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer.write(0xffu8).unwrap();
+    buffer.write_zeros(100).unwrap();
+    buffer.write(0xffu8).unwrap();
+    buffer.pad_to(8).unwrap();
+
+    assert_eq!(buffer.length(), 104);
+
+    buffer.pad_to(8).unwrap();
+
+    assert_eq!(buffer.length(), 104);
+
+    buffer.move_cursor(1).unwrap();
+
+    assert!(buffer
+        .read_slice(100)
+        .unwrap()
+        .iter()
+        .all(|&byte| byte == 0));
+    assert_eq!(buffer.read_slice(3).unwrap(), [0xff, 0, 0]);
+}
+
 #[test]
 fn test_read_to_buffer() {
     let mut buffer = MByteBuffer::new().unwrap();
@@ -128,3 +248,18 @@ fn test_read_to_buffer() {
     assert_eq!(buffer.cursor(), 8);
     assert_eq!(new_buffer.capacity(), buffer.capacity());
 }
+
+#[test]
+fn test_counter() {
+    let mut counter = MByteBuffer::counter();
+
+    assert!(counter.is_counter());
+
+    counter.write(0xffu32).unwrap();
+    counter.write_slice(&[0; BUFFER_SIZE * 4]).unwrap();
+    counter.write_zeros(3).unwrap();
+
+    assert_eq!(counter.length(), 4 + BUFFER_SIZE * 4 + 3);
+    assert_eq!(counter.as_slice(), &[] as &[u8]);
+    assert!(counter.read_slice(1).is_err());
+}
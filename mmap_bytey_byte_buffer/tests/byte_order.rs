@@ -0,0 +1,110 @@
+use mmap_bytey_byte_buffer::{
+    byte_buffer::MByteBuffer,
+    byte_buffer_read::MByteBufferRead,
+    byte_buffer_write::MByteBufferWrite,
+    byte_order::{Big, ByteOrder, Little, Native},
+    error::Result,
+};
+
+// Implemented the way it had to be done before `ByteOrder` existed.
+#[derive(Debug, PartialEq)]
+struct Legacy(u16);
+
+impl MByteBufferWrite for Legacy {
+    fn write_to_mbuffer(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        self.0.write_to_mbuffer(buffer)
+    }
+
+    fn write_to_mbuffer_le(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        self.0.write_to_mbuffer_le(buffer)
+    }
+
+    fn write_to_mbuffer_be(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        self.0.write_to_mbuffer_be(buffer)
+    }
+}
+
+impl MByteBufferRead for Legacy {
+    fn read_from_mbuffer(buffer: &mut MByteBuffer) -> Result<Self> {
+        Ok(Legacy(buffer.read()?))
+    }
+
+    fn read_from_mbuffer_le(buffer: &mut MByteBuffer) -> Result<Self> {
+        Ok(Legacy(buffer.read_le()?))
+    }
+
+    fn read_from_mbuffer_be(buffer: &mut MByteBuffer) -> Result<Self> {
+        Ok(Legacy(buffer.read_be()?))
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct Generic(u16);
+
+impl MByteBufferWrite for Generic {
+    fn write_to_mbuffer_with<O: ByteOrder>(&self, buffer: &mut MByteBuffer) -> Result<()> {
+        self.0.write_to_mbuffer_with::<O>(buffer)
+    }
+}
+
+impl MByteBufferRead for Generic {
+    fn read_from_mbuffer_with<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<Self> {
+        Ok(Generic(buffer.read_with::<O, u16>()?))
+    }
+}
+
+#[test]
+fn test_byte_order_bytes() {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer.write_with::<Big, _>(0x0102u16).unwrap();
+    buffer.write_with::<Little, _>(0x0102u16).unwrap();
+    buffer.write_with::<Native, _>(0x0102u16).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read_slice(4).unwrap(), [1, 2, 2, 1]);
+    assert_eq!(buffer.read_slice(2).unwrap(), 0x0102u16.to_ne_bytes());
+}
+
+#[test]
+fn test_legacy_impl() {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer.write_with::<Big, _>(Legacy(0x0102)).unwrap();
+    buffer.write_le(Legacy(0x0102)).unwrap();
+    buffer.write(vec![Legacy(1), Legacy(2)]).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read_slice(4).unwrap(), [1, 2, 2, 1]);
+    assert_eq!(
+        buffer.read::<Vec<Legacy>>().unwrap(),
+        [Legacy(1), Legacy(2)]
+    );
+
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read_be::<Legacy>().unwrap(), Legacy(0x0102));
+    assert_eq!(
+        buffer.read_with::<Little, Legacy>().unwrap(),
+        Legacy(0x0102)
+    );
+}
+
+#[test]
+fn test_generic_impl() {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer.write_be(Generic(0x0102)).unwrap();
+    buffer.write_le(Some(Generic(0x0102))).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read_slice(5).unwrap(), [1, 2, 1, 2, 1]);
+
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read_with::<Big, Generic>().unwrap(), Generic(0x0102));
+    assert_eq!(
+        buffer.read_le::<Option<Generic>>().unwrap(),
+        Some(Generic(0x0102))
+    );
+}
@@ -0,0 +1,157 @@
+use mmap_bytey_byte_buffer::{
+    byte_buffer::MByteBuffer,
+    byte_buffer_write::{MByteBufferWrite, Sorted},
+    error::MByteBufferError,
+};
+use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque};
+
+#[test]
+fn test_map_write_read() {
+    let mut buffer = MByteBuffer::new().unwrap();
+    let hash_map: HashMap<u32, String> = (0..8).map(|i| (i, i.to_string())).collect();
+    let btree_map: BTreeMap<u16, Vec<u8>> =
+        (0..8).map(|i| (i, vec![i as u8; i as usize])).collect();
+
+    buffer.write(&hash_map).unwrap();
+    buffer.write(&btree_map).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read::<HashMap<u32, String>>().unwrap(), hash_map);
+    assert_eq!(buffer.read::<BTreeMap<u16, Vec<u8>>>().unwrap(), btree_map);
+}
+
+#[test]
+fn test_set_write_read() {
+    let mut buffer = MByteBuffer::new().unwrap();
+    let hash_set: HashSet<i64> = (-4..4).collect();
+    let btree_set: BTreeSet<char> = "mbytey".chars().collect();
+
+    buffer.write_le(&hash_set).unwrap();
+    buffer.write_le(&btree_set).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read_le::<HashSet<i64>>().unwrap(), hash_set);
+    assert_eq!(buffer.read_le::<BTreeSet<char>>().unwrap(), btree_set);
+}
+
+#[test]
+fn test_sequence_write_read() {
+    let mut buffer = MByteBuffer::new().unwrap();
+    let mut deque: VecDeque<u32> = (0..4).collect();
+    let heap: BinaryHeap<u8> = [3, 1, 4, 1, 5].into_iter().collect();
+    let list: LinkedList<String> = ["a", "bc", "def"].map(str::to_owned).into_iter().collect();
+
+    // Wrap around so the deque is split over two slices.
+    deque.push_front(9);
+
+    buffer.write_be(&deque).unwrap();
+    buffer.write_be(&heap).unwrap();
+    buffer.write_be(&list).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read_be::<VecDeque<u32>>().unwrap(), deque);
+    assert_eq!(
+        buffer
+            .read_be::<BinaryHeap<u8>>()
+            .unwrap()
+            .into_sorted_vec(),
+        heap.into_sorted_vec()
+    );
+    assert_eq!(buffer.read_be::<LinkedList<String>>().unwrap(), list);
+}
+
+#[test]
+fn test_collection_matches_slice_encoding() {
+    let mut buffer = MByteBuffer::new().unwrap();
+    let deque: VecDeque<u16> = (0..5).collect();
+
+    buffer.write(&deque).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read::<Vec<u16>>().unwrap(), Vec::from(deque.clone()));
+    assert_eq!(deque.encoded_len().unwrap(), buffer.length());
+}
+
+#[test]
+fn test_huge_length_prefix() {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer.write(u64::MAX / 2).unwrap();
+    buffer.write(1u32).unwrap();
+
+    buffer.move_cursor(0).unwrap();
+    let error = buffer.read::<Vec<u32>>().unwrap_err();
+    assert_eq!(error.path(), Some("[1]"));
+
+    buffer.move_cursor(0).unwrap();
+    assert!(buffer.read::<VecDeque<u32>>().is_err());
+
+    buffer.move_cursor(0).unwrap();
+    assert!(buffer.read::<Box<[u32]>>().is_err());
+
+    buffer.move_cursor(0).unwrap();
+    assert!(buffer.read::<BinaryHeap<u32>>().is_err());
+}
+
+#[test]
+fn test_duplicate_key() {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer.write(&[(1u8, 10u8), (2, 20), (1, 30)][..]).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert!(matches!(
+        buffer.read::<HashMap<u8, u8>>(),
+        Err(MByteBufferError::DuplicateKey { index: 2, .. })
+    ));
+
+    buffer.move_cursor(0).unwrap();
+
+    assert!(matches!(
+        buffer.read::<BTreeMap<u8, u8>>(),
+        Err(MByteBufferError::DuplicateKey { index: 2, .. })
+    ));
+
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer.write(&[7u32, 7][..]).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert!(matches!(
+        buffer.read::<HashSet<u32>>(),
+        Err(MByteBufferError::DuplicateKey { index: 1, .. })
+    ));
+
+    buffer.move_cursor(0).unwrap();
+
+    assert!(matches!(
+        buffer.read::<BTreeSet<u32>>(),
+        Err(MByteBufferError::DuplicateKey { index: 1, .. })
+    ));
+}
+
+#[test]
+fn test_sorted_write_read() {
+    let hash_map: HashMap<u32, u64> = (0..32).map(|i| (i * 7 % 32, i as u64)).collect();
+    let btree_map: BTreeMap<u32, u64> = hash_map.iter().map(|(k, v)| (*k, *v)).collect();
+    let hash_set: HashSet<u32> = hash_map.keys().copied().collect();
+    let btree_set: BTreeSet<u32> = hash_set.iter().copied().collect();
+
+    let mut sorted = MByteBuffer::new().unwrap();
+    let mut expected = MByteBuffer::new().unwrap();
+
+    sorted.write(Sorted(&hash_map)).unwrap();
+    sorted.write(Sorted(hash_set.clone())).unwrap();
+    expected.write(&btree_map).unwrap();
+    expected.write(&btree_set).unwrap();
+
+    assert_eq!(sorted.as_slice(), expected.as_slice());
+
+    sorted.move_cursor(0).unwrap();
+
+    assert_eq!(
+        sorted.read::<Sorted<HashMap<u32, u64>>>().unwrap().0,
+        hash_map
+    );
+    assert_eq!(sorted.read::<HashSet<u32>>().unwrap(), hash_set);
+}
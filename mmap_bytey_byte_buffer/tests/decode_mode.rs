@@ -0,0 +1,96 @@
+use mmap_bytey_byte_buffer::{
+    byte_buffer::MByteBuffer, decode_mode::DecodeMode, error::MByteBufferError,
+};
+
+fn invalid_buffer() -> MByteBuffer {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer.write(2u8).unwrap();
+    buffer.write(0xd800u32).unwrap();
+    buffer.move_cursor(0).unwrap();
+    buffer
+}
+
+#[test]
+fn test_strict_mode() {
+    let mut buffer = invalid_buffer();
+
+    buffer.set_decode_mode(DecodeMode::Strict);
+
+    assert_eq!(buffer.decode_mode(), DecodeMode::Strict);
+    assert!(matches!(
+        buffer.read::<bool>(),
+        Err(MByteBufferError::InvalidBool(2))
+    ));
+
+    buffer.move_cursor(1).unwrap();
+
+    assert!(matches!(
+        buffer.read::<char>(),
+        Err(MByteBufferError::NotAChar)
+    ));
+
+    buffer.move_cursor(0).unwrap();
+
+    let mut reader = buffer.reader();
+
+    assert!(matches!(
+        reader.read::<bool>(),
+        Err(MByteBufferError::InvalidBool(2))
+    ));
+    assert!(matches!(
+        reader.read::<char>(),
+        Err(MByteBufferError::NotAChar)
+    ));
+}
+
+#[test]
+fn test_lenient_mode() {
+    let mut buffer = invalid_buffer();
+
+    assert_eq!(buffer.decode_mode(), DecodeMode::Lenient);
+    assert!(buffer.read::<bool>().unwrap());
+    assert!(matches!(
+        buffer.read::<char>(),
+        Err(MByteBufferError::NotAChar)
+    ));
+
+    buffer.move_cursor(0).unwrap();
+
+    let mut reader = buffer.reader();
+
+    assert_eq!(reader.decode_mode(), DecodeMode::Lenient);
+    assert!(reader.read::<bool>().unwrap());
+    assert!(matches!(
+        reader.read::<char>(),
+        Err(MByteBufferError::NotAChar)
+    ));
+
+    buffer.move_cursor(0).unwrap();
+
+    let mut copy = buffer.read_to_buffer(1).unwrap();
+
+    assert_eq!(copy.decode_mode(), DecodeMode::Lenient);
+    assert_eq!(
+        buffer.try_clone().unwrap().decode_mode(),
+        DecodeMode::Lenient
+    );
+    assert!(copy.read::<bool>().unwrap());
+}
+
+#[test]
+fn test_valid_values_in_both_modes() {
+    for mode in [DecodeMode::Strict, DecodeMode::Lenient] {
+        let mut buffer = MByteBuffer::new().unwrap();
+
+        buffer.set_decode_mode(mode);
+        buffer.write(true).unwrap();
+        buffer.write(false).unwrap();
+        buffer.write('λ').unwrap();
+        buffer.move_cursor(0).unwrap();
+
+        assert!(buffer.read::<bool>().unwrap());
+        assert!(!buffer.read::<bool>().unwrap());
+        assert_eq!(buffer.read::<char>().unwrap(), 'λ');
+    }
+}
@@ -0,0 +1,133 @@
+#![cfg(any(feature = "glam", feature = "mint", feature = "nalgebra"))]
+
+use mmap_bytey_byte_buffer::byte_buffer::MByteBuffer;
+
+#[cfg(feature = "glam")]
+#[test]
+fn test_glam_write_read() {
+    use glam::{DVec2, IVec3, Mat3A, Mat4, Quat, Vec3, Vec3A};
+
+    let mut buffer = MByteBuffer::new().unwrap();
+    let position = Vec3::new(1.5, -2.0, 3.25);
+    let aligned = Vec3A::new(0.5, 0.25, 0.125);
+    let rotation = Quat::from_rotation_y(1.0);
+    let transform = Mat4::from_scale_rotation_translation(Vec3::ONE * 2.0, rotation, position);
+    let basis = Mat3A::from_quat(rotation);
+
+    buffer.write(position).unwrap();
+    buffer.write_le(aligned).unwrap();
+    buffer.write_be(rotation).unwrap();
+    buffer.write(transform).unwrap();
+    buffer.write(basis).unwrap();
+    buffer.write(DVec2::new(1e300, -1e-300)).unwrap();
+    buffer.write_be(IVec3::new(-1, 0, 1)).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read::<Vec3>().unwrap(), position);
+    assert_eq!(buffer.read_le::<Vec3A>().unwrap(), aligned);
+    assert_eq!(buffer.read_be::<Quat>().unwrap(), rotation);
+    assert_eq!(buffer.read::<Mat4>().unwrap(), transform);
+    assert_eq!(buffer.read::<Mat3A>().unwrap(), basis);
+    assert_eq!(buffer.read::<DVec2>().unwrap(), DVec2::new(1e300, -1e-300));
+    assert_eq!(buffer.read_be::<IVec3>().unwrap(), IVec3::new(-1, 0, 1));
+}
+
+#[cfg(feature = "glam")]
+#[test]
+fn test_glam_component_layout() {
+    use glam::Vec3A;
+    use mmap_bytey_byte_buffer::byte_buffer_write::MByteBufferWrite;
+
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    // Vec3A is 16 bytes in memory but only its three components are written.
+    buffer.write_be(Vec3A::new(1.0, 2.0, 3.0)).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.length(), 12);
+    assert_eq!(Vec3A::FIXED_SIZE, Some(12));
+    assert_eq!(buffer.read_slice(4).unwrap(), 1.0f32.to_be_bytes());
+    assert_eq!(buffer.read_be::<f32>().unwrap(), 2.0);
+    assert_eq!(buffer.read_be::<f32>().unwrap(), 3.0);
+}
+
+#[cfg(feature = "mint")]
+#[test]
+fn test_mint_write_read() {
+    use mint::{ColumnMatrix2, Point3, Quaternion, Vector2, Vector3};
+
+    let mut buffer = MByteBuffer::new().unwrap();
+    let point = Point3 {
+        x: 1.0f32,
+        y: 2.0,
+        z: 3.0,
+    };
+    let rotation = Quaternion {
+        v: Vector3 {
+            x: 0.0f32,
+            y: 0.0,
+            z: 0.0,
+        },
+        s: 1.0,
+    };
+    let matrix = ColumnMatrix2 {
+        x: Vector2 { x: 1i32, y: 2 },
+        y: Vector2 { x: 3, y: 4 },
+    };
+
+    buffer.write_le(point).unwrap();
+    buffer.write_le(rotation).unwrap();
+    buffer.write_le(matrix).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read_le::<Point3<f32>>().unwrap(), point);
+    assert_eq!(buffer.read_le::<Quaternion<f32>>().unwrap(), rotation);
+    assert_eq!(buffer.read_le::<ColumnMatrix2<i32>>().unwrap(), matrix);
+}
+
+#[cfg(all(feature = "glam", feature = "mint"))]
+#[test]
+fn test_glam_mint_same_encoding() {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer.write(glam::Vec3::new(4.0, 5.0, 6.0)).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(
+        buffer.read::<mint::Vector3<f32>>().unwrap(),
+        mint::Vector3 {
+            x: 4.0,
+            y: 5.0,
+            z: 6.0
+        }
+    );
+}
+
+#[cfg(feature = "nalgebra")]
+#[test]
+fn test_nalgebra_write_read() {
+    use nalgebra::{Matrix2x3, Point2, UnitQuaternion, Vector3};
+
+    let mut buffer = MByteBuffer::new().unwrap();
+    let velocity = Vector3::new(0.5f32, 1.5, -2.5);
+    let point = Point2::new(7.0f64, 8.0);
+    let rotation = UnitQuaternion::from_euler_angles(0.1f32, 0.2, 0.3);
+    let matrix = Matrix2x3::new(1u16, 2, 3, 4, 5, 6);
+
+    buffer.write_be(velocity).unwrap();
+    buffer.write_be(point).unwrap();
+    buffer.write_be(rotation).unwrap();
+    buffer.write_be(matrix).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read_be::<Vector3<f32>>().unwrap(), velocity);
+    assert_eq!(buffer.read_be::<Point2<f64>>().unwrap(), point);
+    assert_eq!(buffer.read_be::<UnitQuaternion<f32>>().unwrap(), rotation);
+    assert_eq!(buffer.read_be::<Matrix2x3<u16>>().unwrap(), matrix);
+
+    // Column-major: the first column is written first.
+    buffer.move_cursor(buffer.length() - 12).unwrap();
+
+    assert_eq!(buffer.read_be::<u16>().unwrap(), 1);
+    assert_eq!(buffer.read_be::<u16>().unwrap(), 4);
+}
@@ -0,0 +1,70 @@
+use mmap_bytey_byte_buffer::{
+    byte_buffer::MByteBuffer, byte_buffer_write::MByteBufferWrite, error::MByteBufferError,
+};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+
+#[test]
+fn test_ip_addr_write_read() {
+    let mut buffer = MByteBuffer::new().unwrap();
+    let v4 = IpAddr::V4(Ipv4Addr::new(192, 168, 1, 20));
+    let v6 = IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1));
+
+    buffer.write(v4).unwrap();
+    buffer.write_le(v6).unwrap();
+    buffer.write_be(Ipv4Addr::LOCALHOST).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read::<IpAddr>().unwrap(), v4);
+    assert_eq!(buffer.read_le::<IpAddr>().unwrap(), v6);
+    assert_eq!(buffer.read_be::<Ipv4Addr>().unwrap(), Ipv4Addr::LOCALHOST);
+}
+
+#[test]
+fn test_socket_addr_write_read() {
+    let mut buffer = MByteBuffer::new().unwrap();
+    let v4 = SocketAddr::V4(SocketAddrV4::new(Ipv4Addr::new(10, 0, 0, 1), 7777));
+    let v6 = SocketAddr::V6(SocketAddrV6::new(Ipv6Addr::LOCALHOST, 443, 0x12345, 3));
+
+    buffer.write_le(v4).unwrap();
+    buffer.write_le(v6).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read_le::<SocketAddr>().unwrap(), v4);
+    assert_eq!(buffer.read_le::<SocketAddr>().unwrap(), v6);
+    assert_eq!(v4.encoded_len().unwrap(), 7);
+    assert_eq!(v6.encoded_len().unwrap(), 27);
+}
+
+#[test]
+fn test_socket_addr_network_order() {
+    let mut buffer = MByteBuffer::new().unwrap();
+    let addr = SocketAddrV4::new(Ipv4Addr::new(1, 2, 3, 4), 0x1f90);
+
+    buffer.write_le(addr).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read_slice(6).unwrap(), [1, 2, 3, 4, 0x1f, 0x90]);
+    assert_eq!(SocketAddrV4::FIXED_SIZE, Some(6));
+    assert_eq!(SocketAddrV6::FIXED_SIZE, Some(26));
+}
+
+#[test]
+fn test_ip_addr_invalid_tag() {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer.write(5u8).unwrap();
+    buffer.write_slice(&[0; 16]).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert!(matches!(
+        buffer.read::<IpAddr>(),
+        Err(MByteBufferError::InvalidTag { tag: 5, .. })
+    ));
+
+    buffer.move_cursor(0).unwrap();
+
+    let err = buffer.read::<SocketAddr>().unwrap_err();
+
+    assert!(matches!(err, MByteBufferError::InvalidTag { tag: 5, .. }));
+    assert!(err.to_string().starts_with("Invalid tag 5 while reading"));
+}
@@ -0,0 +1,127 @@
+use mmap_bytey_byte_buffer::{byte_buffer::MByteBuffer, error::MByteBufferError};
+use std::{
+    borrow::Cow,
+    ffi::{CStr, CString, OsString},
+    path::{Path, PathBuf},
+    rc::Rc,
+    sync::Arc,
+};
+
+#[test]
+fn test_shared_pointer_write_read() {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer.write(Rc::new(7u32)).unwrap();
+    buffer.write(Arc::new(String::from("arc"))).unwrap();
+    buffer.write(Box::<str>::from("box")).unwrap();
+    buffer.write(Box::<[u16]>::from([1, 2, 3])).unwrap();
+    buffer.write(Arc::<str>::from("shared")).unwrap();
+    buffer.write(Rc::<[u8]>::from([4, 5])).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(*buffer.read::<Rc<u32>>().unwrap(), 7);
+    assert_eq!(*buffer.read::<Arc<String>>().unwrap(), "arc");
+    assert_eq!(&*buffer.read::<Box<str>>().unwrap(), "box");
+    assert_eq!(&*buffer.read::<Box<[u16]>>().unwrap(), [1, 2, 3]);
+    assert_eq!(&*buffer.read::<Arc<str>>().unwrap(), "shared");
+    assert_eq!(&*buffer.read::<Rc<[u8]>>().unwrap(), [4, 5]);
+}
+
+#[test]
+fn test_unsized_pointer_matches_owned_encoding() {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer.write(Arc::<str>::from("text")).unwrap();
+    buffer.write(Rc::<[u32]>::from([9, 8])).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read::<String>().unwrap(), "text");
+    assert_eq!(buffer.read::<Vec<u32>>().unwrap(), [9, 8]);
+}
+
+#[test]
+fn test_cow_write_read() {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer.write(Cow::Borrowed("cow")).unwrap();
+    buffer.write(Cow::<[u8]>::Borrowed(&[1, 2, 3])).unwrap();
+    buffer.write(Cow::<u64>::Owned(5)).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read::<Cow<str>>().unwrap(), "cow");
+    assert_eq!(&*buffer.read::<Cow<[u8]>>().unwrap(), [1, 2, 3]);
+    assert_eq!(*buffer.read::<Cow<u64>>().unwrap(), 5);
+}
+
+#[test]
+fn test_c_string_write_read() {
+    let mut buffer = MByteBuffer::new().unwrap();
+    let value = CString::new("c string").unwrap();
+
+    buffer.write(&value).unwrap();
+    buffer.write(c"borrowed").unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read::<CString>().unwrap(), value);
+    assert_eq!(buffer.read::<CString>().unwrap().as_c_str(), c"borrowed");
+
+    // The trailing nul is not written, so a CStr reads back as a plain string.
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read::<String>().unwrap(), "c string");
+}
+
+#[test]
+fn test_c_string_interior_nul() {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer.write("in\0side").unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert!(matches!(
+        buffer.read::<CString>(),
+        Err(MByteBufferError::NulError(_))
+    ));
+}
+
+#[test]
+fn test_os_string_and_path_write_read() {
+    let mut buffer = MByteBuffer::new().unwrap();
+    let os_string = OsString::from("os string");
+    let path = PathBuf::from("assets/maps/level_1.map");
+
+    buffer.write(&os_string).unwrap();
+    buffer.write(&path).unwrap();
+    buffer.write(Path::new("relative")).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read::<OsString>().unwrap(), os_string);
+    assert_eq!(buffer.read::<PathBuf>().unwrap(), path);
+    assert_eq!(buffer.read::<String>().unwrap(), "relative");
+}
+
+#[cfg(unix)]
+#[test]
+fn test_non_unicode_os_string() {
+    use std::os::unix::ffi::OsStringExt;
+
+    let mut buffer = MByteBuffer::new().unwrap();
+    let value = OsString::from_vec(vec![0x66, 0x6f, 0x80, 0x6f]);
+
+    buffer.write(&value).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read::<OsString>().unwrap(), value);
+}
+
+#[test]
+fn test_c_str_encoded_len() {
+    use mmap_bytey_byte_buffer::byte_buffer_write::MByteBufferWrite;
+
+    let value: &CStr = c"len";
+
+    assert_eq!(
+        value.encoded_len().unwrap(),
+        std::mem::size_of::<usize>() + 3
+    );
+}
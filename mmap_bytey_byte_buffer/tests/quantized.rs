@@ -0,0 +1,137 @@
+use mmap_bytey_byte_buffer::{
+    byte_buffer::MByteBuffer,
+    byte_buffer_write::MByteBufferWrite,
+    error::MByteBufferError,
+    quantized::{FixedPoint, Quantized, SmallestThree, BF16, F16},
+};
+
+type Position = Quantized<f32, -1024, 1024, 16>;
+type Percent = Quantized<f64, 0, 100, 7>;
+
+#[test]
+fn test_quantized_write_read() {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    for value in [-1024.0, -512.5, 0.0, 0.01, 333.333, 1024.0] {
+        buffer.write_le(Position::from(value)).unwrap();
+    }
+    buffer.write_be(Percent::from(42.0)).unwrap();
+
+    assert_eq!(buffer.length(), 6 * 2 + 1);
+    assert_eq!(Position::FIXED_SIZE, Some(2));
+    assert_eq!(Percent::from(1.0).encoded_len().unwrap(), 1);
+
+    buffer.move_cursor(0).unwrap();
+
+    for value in [-1024.0f32, -512.5, 0.0, 0.01, 333.333, 1024.0] {
+        let read = f32::from(buffer.read_le::<Position>().unwrap());
+
+        assert!((read - value).abs() as f64 <= Position::max_error() + 1e-4);
+    }
+
+    let read = f64::from(buffer.read_be::<Percent>().unwrap());
+
+    assert!((read - 42.0).abs() <= Percent::max_error());
+}
+
+#[test]
+fn test_quantized_bounds() {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer.write(Position::from(-5000.0)).unwrap();
+    buffer.write(Position::from(f32::INFINITY)).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read::<Position>().unwrap().0, -1024.0);
+    assert_eq!(buffer.read::<Position>().unwrap().0, 1024.0);
+
+    assert!(buffer.write(Position::from(f32::NAN)).is_err());
+
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer.write(0xffu8).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert!(matches!(
+        buffer.read::<Percent>(),
+        Err(MByteBufferError::OtherError { .. })
+    ));
+}
+
+#[test]
+fn test_fixed_point_write_read() {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer.write(FixedPoint::<i16, 8>::from_f32(-3.25)).unwrap();
+    buffer
+        .write_be(FixedPoint::<u32, 16>::from_f64(1.5))
+        .unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read::<i16>().unwrap(), -3 * 256 - 64);
+    assert_eq!(buffer.read_be::<u32>().unwrap(), 0x18000);
+
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read::<FixedPoint<i16, 8>>().unwrap().to_f32(), -3.25);
+    assert_eq!(
+        buffer.read_be::<FixedPoint<u32, 16>>().unwrap().to_f64(),
+        1.5
+    );
+}
+
+#[test]
+fn test_half_floats_write_read() {
+    let mut buffer = MByteBuffer::new().unwrap();
+    let values = [1.0, -2.5, 65504.0, f32::INFINITY, 5.9604645e-8];
+
+    for value in values {
+        buffer.write(F16(value)).unwrap();
+        buffer.write(BF16(value)).unwrap();
+    }
+    buffer.write(F16(f32::NAN)).unwrap();
+    buffer.write(BF16(f32::NAN)).unwrap();
+
+    assert_eq!(buffer.length(), (values.len() + 1) * 4);
+
+    buffer.move_cursor(0).unwrap();
+
+    for value in values {
+        assert_eq!(buffer.read::<F16>().unwrap().0, value);
+        let brain = buffer.read::<BF16>().unwrap().0;
+
+        assert!(brain == value || (brain - value).abs() <= value.abs() / 256.0);
+    }
+
+    assert!(buffer.read::<F16>().unwrap().0.is_nan());
+    assert!(buffer.read::<BF16>().unwrap().0.is_nan());
+    assert_eq!(F16(1.0).to_bits(), 0x3c00);
+    assert_eq!(F16(100000.0).to_bits(), 0x7c00);
+    assert_eq!(BF16(1.0).to_bits(), 0x3f80);
+}
+
+#[test]
+fn test_smallest_three_write_read() {
+    let mut buffer = MByteBuffer::new().unwrap();
+    let rotation = [0.5f32, -0.5, 0.5, -0.5];
+
+    buffer.write(SmallestThree::<10>(rotation)).unwrap();
+    buffer.write_be(SmallestThree::<16>(rotation)).unwrap();
+
+    assert_eq!(buffer.length(), 4 + 7);
+
+    buffer.move_cursor(0).unwrap();
+
+    let coarse = buffer.read::<SmallestThree<10>>().unwrap().0;
+    let fine = buffer.read_be::<SmallestThree<16>>().unwrap().0;
+
+    // The largest component may come back negated, which is the same rotation.
+    let sign = coarse[3].signum() * rotation[3].signum();
+
+    for ((coarse, fine), expected) in coarse.iter().zip(fine).zip(rotation) {
+        assert!((coarse - expected * sign).abs() < 0.002);
+        assert!((fine - expected * sign).abs() < 0.0001);
+    }
+
+    assert!(buffer.write(SmallestThree::<10>([f32::NAN; 4])).is_err());
+}
@@ -0,0 +1,139 @@
+use mmap_bytey_byte_buffer::{
+    byte_buffer::MByteBuffer, byte_buffer_write::MByteBufferWrite, error::MByteBufferError,
+};
+use std::{
+    cmp::{Ordering, Reverse},
+    num::{NonZeroI64, NonZeroU16, NonZeroU32},
+    ops::{Bound, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive},
+};
+
+#[test]
+fn test_range_write_read() {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer.write(2u32..7).unwrap();
+    buffer.write_le(-3i16..=3).unwrap();
+    buffer.write_be(10u64..).unwrap();
+    buffer.write(..5u8).unwrap();
+    buffer.write(..=9i32).unwrap();
+    buffer.write(..).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read::<Range<u32>>().unwrap(), 2..7);
+    assert_eq!(buffer.read_le::<RangeInclusive<i16>>().unwrap(), -3..=3);
+    assert_eq!(buffer.read_be::<RangeFrom<u64>>().unwrap(), 10..);
+    assert_eq!(buffer.read::<RangeTo<u8>>().unwrap(), ..5);
+    assert_eq!(buffer.read::<RangeToInclusive<i32>>().unwrap(), ..=9);
+    assert_eq!(buffer.read::<RangeFull>().unwrap(), ..);
+    assert_eq!(buffer.cursor(), buffer.length());
+}
+
+#[test]
+fn test_range_fixed_size() {
+    assert_eq!(Range::<u32>::FIXED_SIZE, Some(8));
+    assert_eq!(RangeFrom::<u32>::FIXED_SIZE, Some(4));
+    assert_eq!(RangeToInclusive::<u16>::FIXED_SIZE, Some(2));
+    assert_eq!(RangeFull::FIXED_SIZE, Some(0));
+    assert_eq!((..String::from("end")).encoded_len().unwrap(), 8 + 3);
+}
+
+#[test]
+fn test_bound_write_read() {
+    let mut buffer = MByteBuffer::new().unwrap();
+    let bounds = [Bound::Included(4u32), Bound::Excluded(9), Bound::Unbounded];
+
+    for bound in bounds {
+        buffer.write_be(bound).unwrap();
+    }
+
+    buffer.write(3u8).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    for bound in bounds {
+        assert_eq!(buffer.read_be::<Bound<u32>>().unwrap(), bound);
+    }
+
+    assert!(matches!(
+        buffer.read::<Bound<u32>>(),
+        Err(MByteBufferError::InvalidTag { tag: 3, .. })
+    ));
+}
+
+#[test]
+fn test_ordering_and_reverse_write_read() {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer.write(Ordering::Less).unwrap();
+    buffer.write(Ordering::Equal).unwrap();
+    buffer.write(Ordering::Greater).unwrap();
+    buffer.write_le(Reverse(0x1234u16)).unwrap();
+    buffer.write(2i8).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read::<Ordering>().unwrap(), Ordering::Less);
+    assert_eq!(buffer.read::<Ordering>().unwrap(), Ordering::Equal);
+    assert_eq!(buffer.read::<Ordering>().unwrap(), Ordering::Greater);
+    assert_eq!(buffer.read_le::<Reverse<u16>>().unwrap(), Reverse(0x1234));
+    assert!(matches!(
+        buffer.read::<Ordering>(),
+        Err(MByteBufferError::InvalidTag { tag: 2, .. })
+    ));
+}
+
+#[test]
+fn test_unit_write_read() {
+    let mut buffer = MByteBuffer::new().unwrap();
+    let ok: Result<(), u16> = Ok(());
+    let err: Result<(), u16> = Err(404);
+
+    buffer.write(()).unwrap();
+    buffer.write(ok).unwrap();
+    buffer.write(err).unwrap();
+    buffer.write(((), 1u8)).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.length(), 1 + 3 + 1);
+    buffer.read::<()>().unwrap();
+    assert_eq!(buffer.read::<Result<(), u16>>().unwrap(), ok);
+    assert_eq!(buffer.read::<Result<(), u16>>().unwrap(), err);
+    assert_eq!(buffer.read::<((), u8)>().unwrap(), ((), 1));
+    assert_eq!(<()>::FIXED_SIZE, Some(0));
+}
+
+#[test]
+fn test_option_non_zero_write_read() {
+    let mut buffer = MByteBuffer::new().unwrap();
+    let values = [NonZeroU32::new(77), None];
+
+    for value in values {
+        buffer.write_be(value).unwrap();
+    }
+
+    buffer.write(NonZeroU16::new(5)).unwrap();
+    buffer.write(NonZeroI64::new(-1)).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    for value in values {
+        assert_eq!(buffer.read_be::<Option<NonZeroU32>>().unwrap(), value);
+    }
+
+    assert_eq!(
+        buffer.read::<Option<NonZeroU16>>().unwrap(),
+        NonZeroU16::new(5)
+    );
+    assert_eq!(
+        buffer.read::<Option<NonZeroI64>>().unwrap(),
+        NonZeroI64::new(-1)
+    );
+
+    // A zero inside Some is still rejected.
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer.write(Some(0u32)).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert!(matches!(
+        buffer.read::<Option<NonZeroU32>>(),
+        Err(MByteBufferError::NonZeroIsZero)
+    ));
+}
@@ -0,0 +1,150 @@
+use mmap_bytey_byte_buffer::{
+    byte_buffer::MByteBuffer,
+    byte_buffer_write::MByteBufferWrite,
+    error::MByteBufferError,
+    string_encoding::{FixedStr, NulTerminated, Utf16},
+};
+use std::ffi::{CStr, CString};
+
+#[test]
+fn test_nul_terminated_write_read() {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer.write(NulTerminated("player")).unwrap();
+    let empty = NulTerminated(String::new());
+
+    buffer.write(&empty).unwrap();
+    buffer.write(NulTerminated(c"legacy")).unwrap();
+    buffer.write(NulTerminated(vec![0xffu8, 1])).unwrap();
+
+    assert_eq!(buffer.length(), 7 + 1 + 7 + 3);
+    assert_eq!(NulTerminated("player").encoded_len().unwrap(), 7);
+
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read::<NulTerminated>().unwrap().0, "player");
+    assert_eq!(buffer.read::<NulTerminated>().unwrap().0, "");
+    assert_eq!(
+        buffer.read::<NulTerminated<CString>>().unwrap().0,
+        CString::from(c"legacy")
+    );
+    assert_eq!(
+        buffer.read::<NulTerminated<Vec<u8>>>().unwrap().0,
+        [0xff, 1]
+    );
+
+    buffer.move_cursor(0).unwrap();
+
+    let mut reader = buffer.reader();
+
+    assert_eq!(reader.read::<NulTerminated<&str>>().unwrap().0, "player");
+    assert_eq!(reader.read::<NulTerminated<&[u8]>>().unwrap().0, b"");
+    assert_eq!(reader.read::<NulTerminated<&CStr>>().unwrap().0, c"legacy");
+}
+
+#[test]
+fn test_nul_terminated_errors() {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    assert!(matches!(
+        buffer.write(NulTerminated("a\0b")),
+        Err(MByteBufferError::EmbeddedNul { position: 1, .. })
+    ));
+    assert!(buffer.is_empty());
+
+    buffer.write_slice(b"no end").unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert!(matches!(
+        buffer.read::<NulTerminated>(),
+        Err(MByteBufferError::ReadOutOfBounds { .. })
+    ));
+    assert_eq!(buffer.cursor(), 0);
+}
+
+#[test]
+fn test_fixed_str_write_read() {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer.write(FixedStr::<8, _>("name")).unwrap();
+    buffer
+        .write(FixedStr::<4, _>(String::from("full")))
+        .unwrap();
+    buffer.write(FixedStr::<3, _>("")).unwrap();
+
+    assert_eq!(buffer.length(), 8 + 4 + 3);
+    assert_eq!(FixedStr::<8, &str>::FIXED_SIZE, Some(8));
+
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read_slice(8).unwrap(), b"name\0\0\0\0");
+
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read::<FixedStr<8>>().unwrap().0, "name");
+    assert_eq!(buffer.read::<FixedStr<4, Vec<u8>>>().unwrap().0, b"full");
+    assert_eq!(
+        buffer.read::<FixedStr<3, CString>>().unwrap().0,
+        CString::default()
+    );
+
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read_ref::<FixedStr<8, &str>>().unwrap().0, "name");
+}
+
+#[test]
+fn test_fixed_str_errors() {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    assert!(matches!(
+        buffer.write(FixedStr::<4, _>("too long")),
+        Err(MByteBufferError::StringTooLong { len: 8, max: 4, .. })
+    ));
+    assert!(matches!(
+        buffer.write(FixedStr::<4, _>("a\0")),
+        Err(MByteBufferError::EmbeddedNul { position: 1, .. })
+    ));
+    assert!(buffer.is_empty());
+
+    buffer.write_slice(&[0xc3, 0x28, 0, 0]).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert!(matches!(
+        buffer.read::<FixedStr<4>>(),
+        Err(MByteBufferError::UnicodeError(_))
+    ));
+}
+
+#[test]
+fn test_utf16_write_read() {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer.write_le(Utf16("hé😀")).unwrap();
+    let upper = Utf16(String::from("A"));
+
+    buffer.write_be(&upper).unwrap();
+
+    assert_eq!(Utf16("hé😀").encoded_len().unwrap(), 8 + 2 * 4);
+    assert_eq!(buffer.length(), (8 + 2 * 4) + (8 + 2));
+
+    buffer.move_cursor(8).unwrap();
+
+    assert_eq!(buffer.read_slice(4).unwrap(), [b'h', 0, 0xe9, 0]);
+
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read_le::<Utf16>().unwrap().0, "hé😀");
+    assert_eq!(buffer.read_be::<Utf16>().unwrap().0, "A");
+
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer.write_le(1usize).unwrap();
+    buffer.write_le(0xd800u16).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert!(matches!(
+        buffer.read_le::<Utf16>(),
+        Err(MByteBufferError::Utf16Error(_))
+    ));
+}
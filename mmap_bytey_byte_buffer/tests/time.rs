@@ -0,0 +1,132 @@
+use mmap_bytey_byte_buffer::{
+    byte_buffer::MByteBuffer,
+    byte_order::{Big, Little},
+};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+#[test]
+fn test_system_time_write_read() {
+    let mut buffer = MByteBuffer::new().unwrap();
+    let now = SystemTime::now();
+    let before_epoch = UNIX_EPOCH - Duration::new(86_400, 250_000_000);
+
+    buffer.write(now).unwrap();
+    buffer.write_be(before_epoch).unwrap();
+    buffer.write_le(UNIX_EPOCH).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read::<SystemTime>().unwrap(), now);
+    assert_eq!(buffer.read_be::<SystemTime>().unwrap(), before_epoch);
+    assert_eq!(buffer.read_le::<SystemTime>().unwrap(), UNIX_EPOCH);
+}
+
+#[test]
+fn test_system_time_signed_layout() {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer
+        .write(UNIX_EPOCH - Duration::from_millis(1500))
+        .unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    // 1.5 seconds before the epoch is -2 seconds plus half a second.
+    assert_eq!(buffer.read::<i64>().unwrap(), -2);
+    assert_eq!(buffer.read::<u32>().unwrap(), 500_000_000);
+}
+
+#[test]
+fn test_system_time_invalid_nanos() {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer.write(0i64).unwrap();
+    buffer.write(1_000_000_000u32).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert!(buffer.read::<SystemTime>().is_err());
+}
+
+#[test]
+fn test_instant_write_read() {
+    let mut buffer = MByteBuffer::new().unwrap();
+    let base = Instant::now() + Duration::from_secs(10);
+    let later = base + Duration::new(3, 7);
+    let earlier = base - Duration::new(2, 900_000_000);
+
+    buffer.write_instant_with::<Little>(later, base).unwrap();
+    buffer.write_instant_with::<Big>(earlier, base).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read_instant_with::<Little>(base).unwrap(), later);
+    assert_eq!(buffer.read_instant_with::<Big>(base).unwrap(), earlier);
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn test_chrono_write_read() {
+    use chrono::{DateTime, NaiveDate, Utc};
+
+    let mut buffer = MByteBuffer::new().unwrap();
+    let time = DateTime::from_timestamp(-1_000, 123_456_789).unwrap();
+    let date = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+
+    buffer.write(time).unwrap();
+    buffer.write_be(date).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read::<DateTime<Utc>>().unwrap(), time);
+    assert_eq!(buffer.read_be::<NaiveDate>().unwrap(), date);
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn test_chrono_matches_system_time() {
+    use chrono::{DateTime, Utc};
+
+    let mut buffer = MByteBuffer::new().unwrap();
+    let time = UNIX_EPOCH - Duration::new(5, 1);
+
+    buffer.write(time).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(
+        buffer.read::<DateTime<Utc>>().unwrap(),
+        DateTime::from_timestamp(-6, 999_999_999).unwrap()
+    );
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn test_offset_date_time_write_read() {
+    use time::{OffsetDateTime, UtcOffset};
+
+    let mut buffer = MByteBuffer::new().unwrap();
+    let value = OffsetDateTime::from_unix_timestamp_nanos(1_700_000_000_123_456_789)
+        .unwrap()
+        .to_offset(UtcOffset::from_hms(-5, -30, 0).unwrap());
+
+    buffer.write_le(value).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    let read = buffer.read_le::<OffsetDateTime>().unwrap();
+
+    assert_eq!(read, value);
+    assert_eq!(read.offset(), value.offset());
+}
+
+#[cfg(feature = "uuid")]
+#[test]
+fn test_uuid_write_read() {
+    use uuid::Uuid;
+
+    let mut buffer = MByteBuffer::new().unwrap();
+    let value = Uuid::from_u128(0x0123_4567_89ab_cdef_0123_4567_89ab_cdef);
+
+    buffer.write_le(value).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read_slice(2).unwrap(), [0x01, 0x23]);
+
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read_be::<Uuid>().unwrap(), value);
+}
@@ -0,0 +1,118 @@
+use mmap_bytey_byte_buffer::{
+    byte_buffer::MByteBuffer, byte_buffer_write::MByteBufferWrite, decode_mode::DecodeMode,
+    error::MByteBufferError, varint::VarInt,
+};
+
+#[test]
+fn test_varint_write_read() {
+    let mut buffer = MByteBuffer::new().unwrap();
+    let values = [
+        0,
+        1,
+        127,
+        128,
+        300,
+        16_383,
+        16_384,
+        u32::MAX as u64,
+        u64::MAX,
+    ];
+
+    for value in values {
+        buffer.write(VarInt(value)).unwrap();
+    }
+
+    assert_eq!(buffer.length(), 1 + 1 + 1 + 2 + 2 + 2 + 3 + 5 + 10);
+
+    buffer.move_cursor(0).unwrap();
+
+    for value in values {
+        let len = VarInt(value).byte_len();
+
+        assert_eq!(VarInt(value).encoded_len().unwrap(), len);
+        assert_eq!(buffer.read::<VarInt>().unwrap(), VarInt(value));
+    }
+
+    assert_eq!(buffer.cursor(), buffer.length());
+}
+
+#[test]
+fn test_varint_bytes() {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer.write_be(VarInt(300)).unwrap();
+    buffer.write_le(VarInt(u64::MAX)).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read_slice(2).unwrap(), [0xac, 0x02]);
+    assert_eq!(
+        buffer.read_slice(10).unwrap(),
+        [0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01]
+    );
+}
+
+#[test]
+fn test_varint_read_ref() {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer.write(VarInt(1_000_000)).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read_ref::<VarInt>().unwrap(), VarInt(1_000_000));
+}
+
+#[test]
+fn test_varint_errors() {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer.write_slice(&[0x80; 10]).unwrap();
+    buffer.write_slice(&[0x01]).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert!(matches!(
+        buffer.read::<VarInt>(),
+        Err(MByteBufferError::InvalidVarInt { .. })
+    ));
+
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer
+        .write_slice(&[0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x02])
+        .unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert!(matches!(
+        buffer.read::<VarInt>(),
+        Err(MByteBufferError::InvalidVarInt { .. })
+    ));
+
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer.write_slice(&[0x80]).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert!(matches!(
+        buffer.read::<VarInt>(),
+        Err(MByteBufferError::ReadOutOfBounds { .. })
+    ));
+}
+
+#[test]
+fn test_varint_non_minimal() {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer.write_slice(&[0x85, 0x00]).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read::<VarInt>().unwrap(), VarInt(5));
+
+    buffer
+        .set_decode_mode(DecodeMode::Strict)
+        .move_cursor(0)
+        .unwrap();
+
+    assert!(matches!(
+        buffer.read::<VarInt>(),
+        Err(MByteBufferError::InvalidVarInt { .. })
+    ));
+}
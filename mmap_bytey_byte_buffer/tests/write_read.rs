@@ -462,3 +462,118 @@ fn test_bool_write_read() {
     assert_eq!(read_isfalse_ref, isfalse);
     assert_eq!(read_isfalse, isfalse);
 }
+
+#[test]
+fn test_borrowed_write_read() {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    let _ = buffer.write("borrowed");
+    let _ = buffer.write_le([1u8, 2, 3].as_slice());
+    let _ = buffer.write_be("cow");
+    let _ = buffer.move_cursor(0);
+
+    let mut reader = buffer.reader();
+
+    assert_eq!(reader.read::<&str>().unwrap(), "borrowed");
+    assert_eq!(reader.read_le::<&[u8]>().unwrap(), &[1, 2, 3]);
+    assert!(matches!(
+        reader.read_be::<std::borrow::Cow<str>>().unwrap(),
+        std::borrow::Cow::Borrowed("cow")
+    ));
+    assert!(reader.read::<&str>().is_err());
+    assert_eq!(buffer.cursor(), buffer.length());
+}
+
+#[test]
+fn test_bulk_vec_write_read() {
+    let mut buffer = MByteBuffer::new().unwrap();
+    let value: Vec<f32> = (0..32).map(|i| i as f32 * 1.5).collect();
+
+    let _ = buffer.write(&value);
+    let _ = buffer.write_le(&value);
+    let _ = buffer.write_be(&value);
+    let _ = buffer.move_cursor(0);
+
+    assert_eq!(buffer.read::<Vec<f32>>().unwrap(), value);
+    assert_eq!(buffer.read_le::<Vec<f32>>().unwrap(), value);
+    assert_eq!(buffer.read_be::<Vec<f32>>().unwrap(), value);
+
+    let mut buffer = MByteBuffer::new().unwrap();
+    let value: [u16; 3] = [0x0102, 0x0304, 0x0506];
+
+    let _ = buffer.write_be(value);
+    let _ = buffer.write_le(value.as_slice());
+    let _ = buffer.move_cursor(8);
+
+    assert_eq!(buffer.read_slice(6).unwrap(), [1, 2, 3, 4, 5, 6]);
+
+    let _ = buffer.move_cursor(22);
+
+    assert_eq!(buffer.read_slice(6).unwrap(), [2, 1, 4, 3, 6, 5]);
+
+    let _ = buffer.move_cursor(0);
+
+    assert_eq!(buffer.read_be::<[u16; 3]>().unwrap(), value);
+    assert_eq!(buffer.read_le::<Vec<u16>>().unwrap(), value);
+}
+
+#[test]
+fn test_bulk_vec_read_out_of_bounds() {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    let _ = buffer.write(vec![String::from("a"), String::from("b")]);
+    let _ = buffer.write(vec![1u64, 2, 3]);
+    let length = buffer.length();
+    let _ = buffer.truncate(length - 1);
+    let _ = buffer.move_cursor(0);
+
+    assert_eq!(buffer.read::<Vec<String>>().unwrap(), ["a", "b"]);
+    assert!(buffer.read::<Vec<u64>>().is_err());
+}
+
+#[test]
+fn test_encoded_len() {
+    use mmap_bytey_byte_buffer::byte_buffer_write::MByteBufferWrite;
+    use std::ops::Bound;
+    use std::time::Duration;
+
+    fn check<T: MByteBufferWrite>(value: T) {
+        let mut buffer = MByteBuffer::new().unwrap();
+
+        value.write_to_mbuffer(&mut buffer).unwrap();
+        assert_eq!(value.encoded_len().unwrap(), buffer.length());
+
+        if let Some(size) = T::FIXED_SIZE {
+            assert_eq!(size, buffer.length());
+        }
+    }
+
+    check(5u8);
+    check(5u64);
+    check(true);
+    check('a');
+    check(Duration::from_secs(3));
+    check(std::num::NonZeroU32::new(3).unwrap());
+    check(Some(5u32));
+    check(None::<u32>);
+    check(Ok::<u8, String>(1));
+    check(Err::<(), String>("error".to_owned()));
+    check(Bound::Included(3u16));
+    check(1u8..4);
+    check((1u8, "two", 3.0f64));
+    check([1u16, 2, 3]);
+    check(vec!["a".to_owned(), "bc".to_owned()]);
+    check(vec![1u32, 2, 3]);
+    check(String::from("hello"));
+    check(Box::new(7i16));
+    check(std::cell::RefCell::new(9u32));
+
+    assert_eq!(<[u16; 3]>::FIXED_SIZE, Some(14));
+    assert_eq!(<(u8, u32)>::FIXED_SIZE, Some(5));
+    assert_eq!(<Option<u32>>::FIXED_SIZE, None);
+    assert_eq!(<Vec<u8>>::FIXED_SIZE, None);
+
+    let mut buffer = MByteBuffer::new().unwrap();
+    buffer.write(1u32).unwrap();
+    assert_eq!(buffer.remaining_capacity(), buffer.capacity() - 4);
+}
@@ -0,0 +1,158 @@
+use crate::field_wrapper::{Bound, FieldAttrs};
+use quote::{quote, ToTokens};
+use syn::visit::{self, Visit};
+
+/// Whether the bounds are for the write derive or for the read derives.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum Direction {
+    Write,
+    Read,
+}
+
+impl Bound {
+    fn get(&self, direction: Direction) -> Option<&Vec<syn::WherePredicate>> {
+        match direction {
+            Direction::Write => self.write.as_ref(),
+            Direction::Read => self.read.as_ref(),
+        }
+    }
+}
+
+/// Adds the bounds an impl of the derive needs for `fields` to the where clause of `generics`.
+///
+/// Each type parameter a field is encoded as gets `trait_bound`, or `BitField` for bit fields,
+/// and each one of a field the reader can leave out gets `Default`. Associated types such as
+/// `T::Id` are bounded themselves, and `PhantomData<T>` needs nothing of `T`. Fields with
+/// `encode_as` or their own functions add no bounds. A `bound` on the container replaces all of
+/// them and one on a field replaces those of the field.
+pub fn with_bounds<'a>(
+    generics: &syn::Generics,
+    fields: impl IntoIterator<Item = &'a syn::Field>,
+    container: &Bound,
+    direction: Direction,
+    trait_bound: proc_macro2::TokenStream,
+) -> syn::Result<syn::Generics> {
+    let mut generics = generics.clone();
+    let predicates = match container.get(direction) {
+        Some(predicates) => predicates.clone(),
+        None => inferred_bounds(&generics, fields, direction, trait_bound)?,
+    };
+
+    if !predicates.is_empty() {
+        generics.make_where_clause().predicates.extend(predicates);
+    }
+
+    Ok(generics)
+}
+
+fn inferred_bounds<'a>(
+    generics: &syn::Generics,
+    fields: impl IntoIterator<Item = &'a syn::Field>,
+    direction: Direction,
+    trait_bound: proc_macro2::TokenStream,
+) -> syn::Result<Vec<syn::WherePredicate>> {
+    let params: Vec<&syn::Ident> = generics.type_params().map(|param| &param.ident).collect();
+    let mut predicates: Vec<syn::WherePredicate> = Vec::new();
+    let mut encoded = TypeParams::new(&params);
+    let mut bit_fields = TypeParams::new(&params);
+    let mut defaults = TypeParams::new(&params);
+
+    if params.is_empty() {
+        return Ok(predicates);
+    }
+
+    for field in fields {
+        let attrs = FieldAttrs::from_field(field)?;
+
+        if let Some(bound) = attrs.bound.get(direction) {
+            predicates.extend(bound.iter().cloned());
+            continue;
+        }
+
+        let (skipped, has_fn, optional) = match direction {
+            Direction::Write => (attrs.skips_write(), attrs.write_fn().is_some(), false),
+            Direction::Read => (
+                attrs.skips_read(),
+                attrs.read_fn(false).is_some(),
+                attrs.skips_read() || attrs.is_versioned() || attrs.condition.is_some(),
+            ),
+        };
+
+        if optional && attrs.default.is_none() {
+            defaults.visit_type(&field.ty);
+        }
+
+        if skipped || has_fn || attrs.encode_as.is_some() {
+            continue;
+        }
+
+        match attrs.bits {
+            Some(_) => bit_fields.visit_type(&field.ty),
+            None => encoded.visit_type(&field.ty),
+        }
+    }
+
+    let bounds = [
+        (encoded, trait_bound),
+        (bit_fields, quote! { ::mmap_bytey::bits::BitField }),
+        (defaults, quote! { ::core::default::Default }),
+    ];
+
+    for (types, bound) in bounds {
+        for ty in types.found {
+            predicates.push(syn::parse_quote! { #ty: #bound });
+        }
+    }
+
+    Ok(predicates)
+}
+
+/// Collects the type parameters, and associated types of them, that a type is made of.
+struct TypeParams<'a> {
+    params: &'a [&'a syn::Ident],
+    found: Vec<syn::TypePath>,
+}
+
+impl<'a> TypeParams<'a> {
+    fn new(params: &'a [&'a syn::Ident]) -> Self {
+        TypeParams {
+            params,
+            found: Vec::new(),
+        }
+    }
+
+    fn insert(&mut self, ty: &syn::TypePath) {
+        let tokens = ty.to_token_stream().to_string();
+
+        if !self
+            .found
+            .iter()
+            .any(|found| found.to_token_stream().to_string() == tokens)
+        {
+            self.found.push(ty.clone());
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for TypeParams<'_> {
+    fn visit_type_path(&mut self, ty: &'ast syn::TypePath) {
+        let segments = &ty.path.segments;
+
+        if segments
+            .last()
+            .is_some_and(|segment| segment.ident == "PhantomData")
+        {
+            return;
+        }
+
+        // Either `T` itself or an associated type such as `T::Id`, which is bounded instead.
+        if ty.qself.is_none()
+            && ty.path.leading_colon.is_none()
+            && self.params.contains(&&segments[0].ident)
+        {
+            self.insert(ty);
+        }
+
+        visit::visit_type_path(self, ty);
+    }
+}
@@ -1,286 +1,513 @@
 use crate::{
-    field_wrapper::is_skipped,
+    bound::{with_bounds, Direction},
+    field_refs::FieldRefs,
+    field_wrapper::{byte_order, FieldAttrs},
     source::{EnumSource, StructSource},
 };
 use quote::quote;
 
-pub fn expand_derive_byte_buffer_read(input: &syn::DeriveInput) -> proc_macro2::TokenStream {
+pub fn expand_derive_byte_buffer_read(
+    input: &syn::DeriveInput,
+) -> syn::Result<proc_macro2::TokenStream> {
     match &input.data {
         syn::Data::Struct(_) => {
-            let source = StructSource::from_input(input);
+            let source = StructSource::from_input(input)?;
 
             handle_struct(source)
         }
         syn::Data::Enum(_) => {
-            let source = EnumSource::from_input(input);
+            let source = EnumSource::from_input(input)?;
 
             handle_enum(source)
         }
-        syn::Data::Union(_) => {
-            input
-                .ident
-                .span()
-                .unwrap()
-                .error("Unions are currently not supported")
-                .emit();
-
-            proc_macro2::TokenStream::new()
-        }
+        syn::Data::Union(_) => Err(syn::Error::new(
+            input.ident.span(),
+            "Unions are currently not supported",
+        )),
     }
 }
 
-fn handle_struct(input: StructSource) -> proc_macro2::TokenStream {
-    let struct_ident = input.ident;
-    let init_struct_native: proc_macro2::TokenStream;
-    let init_struct_le: proc_macro2::TokenStream;
-    let init_struct_be: proc_macro2::TokenStream;
-
-    match input.fields {
-        syn::Fields::Named(syn::FieldsNamed { named, .. }) => {
-            let mut field_reads_native: Vec<proc_macro2::TokenStream> = Vec::new();
-            let mut field_reads_le: Vec<proc_macro2::TokenStream> = Vec::new();
-            let mut field_reads_be: Vec<proc_macro2::TokenStream> = Vec::new();
-
-            for field in named {
-                let field_ident = field.ident.as_ref().unwrap();
-                let field_ty = &field.ty;
-
-                field_reads_native.push(if is_skipped(field) {
-                    quote! {#field_ident: Default::default()}
-                } else {
-                    quote! {#field_ident: buffer.read::<#field_ty>()?}
-                });
-
-                field_reads_le.push(if is_skipped(field) {
-                    quote! {#field_ident: Default::default()}
-                } else {
-                    quote! {#field_ident: buffer.read_le::<#field_ty>()?}
-                });
+fn handle_struct(input: StructSource) -> syn::Result<proc_macro2::TokenStream> {
+    let init_struct = with_post_read(struct_reads(&input, false)?, input.post_read.as_ref());
 
-                field_reads_be.push(if is_skipped(field) {
-                    quote! {#field_ident: Default::default()}
-                } else {
-                    quote! {#field_ident: buffer.read_be::<#field_ty>()?}
-                });
+    let struct_ident = input.ident;
+    let generics = with_bounds(
+        input.generics,
+        input.fields,
+        &input.bound,
+        Direction::Read,
+        quote! { ::mmap_bytey::MByteBufferRead },
+    )?;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::mmap_bytey::MByteBufferRead for #struct_ident #ty_generics #where_clause {
+            #[inline]
+            fn read_from_mbuffer_with<__O: ::mmap_bytey::ByteOrder>(buffer: &mut ::mmap_bytey::MByteBuffer) -> ::mmap_bytey::Result<#struct_ident #ty_generics> {
+                #init_struct
             }
-
-            init_struct_native = quote! {
-                #struct_ident {
-                    #(#field_reads_native),*
-                }
-            };
-
-            init_struct_le = quote! {
-                #struct_ident {
-                    #(#field_reads_le),*
-                }
-            };
-
-            init_struct_be = quote! {
-                #struct_ident {
-                    #(#field_reads_be),*
-                }
-            };
         }
-        syn::Fields::Unnamed(syn::FieldsUnnamed { unnamed, .. }) => {
-            let mut field_reads_native: Vec<proc_macro2::TokenStream> = Vec::new();
-            let mut field_reads_le: Vec<proc_macro2::TokenStream> = Vec::new();
-            let mut field_reads_be: Vec<proc_macro2::TokenStream> = Vec::new();
-
-            for field in unnamed {
-                let field_ty = &field.ty;
-
-                field_reads_native.push(if is_skipped(field) {
-                    quote! { Default::default() }
-                } else {
-                    quote! { buffer.read::<#field_ty>()?}
-                });
+    })
+}
 
-                field_reads_le.push(if is_skipped(field) {
-                    quote! { Default::default() }
-                } else {
-                    quote! { buffer.read_le::<#field_ty>()?}
-                });
+/// Wraps the initializer of a value into a block returning it, after calling `post_read` on it.
+pub fn with_post_read(
+    init: proc_macro2::TokenStream,
+    post_read: Option<&syn::Path>,
+) -> proc_macro2::TokenStream {
+    match post_read {
+        Some(post_read) => quote! {
+            {
+                let mut __value = #init;
+
+                #post_read(&mut __value)?;
+                Ok(__value)
+            }
+        },
+        None => quote! { Ok(#init) },
+    }
+}
 
-                field_reads_be.push(if is_skipped(field) {
-                    quote! { Default::default() }
-                } else {
-                    quote! { buffer.read_be::<#field_ty>()?}
+/// The initializer of a struct that reads each of its fields from `buffer` in the byte order `__O`,
+/// unless the struct or field sets its own, after checking its `magic` bytes.
+/// `by_ref` is set when `buffer` is a `MByteBufferReader`, which `with` modules read from with
+/// their `read_ref` function.
+pub fn struct_reads(input: &StructSource, by_ref: bool) -> syn::Result<proc_macro2::TokenStream> {
+    let struct_ident = input.ident;
+    let type_name = struct_ident.to_string();
+    let order = byte_order(input.endian, &quote! { __O });
+    let magic = input.magic.as_ref().map(|magic| {
+        let magic_len = magic.value().len();
+
+        quote! {
+            let __magic = buffer.read_slice(#magic_len)?;
+
+            if __magic != #magic {
+                return Err(::mmap_bytey::MByteBufferError::MagicMismatch {
+                    expected: #magic,
+                    found: ::std::vec::Vec::from(__magic),
+                    type_name: #type_name,
                 });
             }
+        }
+    });
 
-            init_struct_native = quote! {
-                #struct_ident (
-                    #(#field_reads_native),*
-                )
-            };
-
-            init_struct_le = quote! {
-                #struct_ident (
-                    #(#field_reads_le),*
-                )
-            };
-
-            init_struct_be = quote! {
-                #struct_ident (
-                    #(#field_reads_be),*
-                )
-            };
+    match input.fields {
+        syn::Fields::Named(syn::FieldsNamed { named, .. }) => {
+            let field_idents = named.iter().map(|field| field.ident.as_ref().unwrap());
+            let (reads, locals) = field_reads(
+                named
+                    .iter()
+                    .map(|field| (field, field.ident.as_ref().unwrap().to_string())),
+                &type_name,
+                by_ref,
+                &order,
+                input.version,
+            )?;
+
+            Ok(quote! {
+                {
+                    #magic
+                    #(#reads)*
+
+                    #struct_ident {
+                        #(#field_idents: #locals),*
+                    }
+                }
+            })
         }
-        syn::Fields::Unit => {
-            input
-                .ident
-                .span()
-                .unwrap()
-                .error("Unit structs are currently not supported")
-                .emit();
-
-            return proc_macro2::TokenStream::new();
+        syn::Fields::Unnamed(syn::FieldsUnnamed { unnamed, .. }) => {
+            let (reads, locals) = field_reads(
+                unnamed
+                    .iter()
+                    .enumerate()
+                    .map(|(index, field)| (field, index.to_string())),
+                &type_name,
+                by_ref,
+                &order,
+                input.version,
+            )?;
+
+            Ok(quote! {
+                {
+                    #magic
+                    #(#reads)*
+
+                    #struct_ident (
+                        #(#locals),*
+                    )
+                }
+            })
         }
+        syn::Fields::Unit => Err(syn::Error::new(
+            input.ident.span(),
+            "Unit structs are currently not supported",
+        )),
     }
+}
 
-    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
-
-    quote! {
-        impl #impl_generics ::mmap_bytey::MByteBufferRead for #struct_ident #ty_generics #where_clause {
-            #[inline]
-            fn read_from_mbuffer(buffer: &mut ::mmap_bytey::MByteBuffer) -> ::mmap_bytey::Result<#struct_ident #ty_generics> {
-                Ok(#init_struct_native)
-            }
-
-            #[inline]
-            fn read_from_mbuffer_le(buffer: &mut ::mmap_bytey::MByteBuffer) -> ::mmap_bytey::Result<#struct_ident #ty_generics> {
-                Ok(#init_struct_le)
-            }
+fn handle_enum(input: EnumSource) -> syn::Result<proc_macro2::TokenStream> {
+    let read_enum = enum_reads(&input, false)?;
 
+    let enum_ident = input.ident;
+    let generics = with_bounds(
+        input.generics,
+        input.variants.iter().flat_map(|variant| variant.fields),
+        &input.bound,
+        Direction::Read,
+        quote! { ::mmap_bytey::MByteBufferRead },
+    )?;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::mmap_bytey::MByteBufferRead for #enum_ident #ty_generics #where_clause {
             #[inline]
-            fn read_from_mbuffer_be(buffer: &mut ::mmap_bytey::MByteBuffer) -> ::mmap_bytey::Result<#struct_ident #ty_generics> {
-                Ok(#init_struct_be)
+            fn read_from_mbuffer_with<__O: ::mmap_bytey::ByteOrder>(buffer: &mut ::mmap_bytey::MByteBuffer) -> ::mmap_bytey::Result<#enum_ident #ty_generics> {
+                #read_enum
             }
         }
-    }
+    })
 }
 
-fn handle_enum(input: EnumSource) -> proc_macro2::TokenStream {
+/// The statements reading an enum from `buffer` in the byte order `__O`, unless the enum or
+/// variant sets its own, returning the result: its tag, the length of its payload if it is
+/// `length_delimited`, and then the fields of the variant the tag is for, after which
+/// `post_read` is called. `by_ref` is set when `buffer` is a `MByteBufferReader`, from which
+/// the payload of the `other` variant is borrowed instead of copied.
+pub fn enum_reads(input: &EnumSource, by_ref: bool) -> syn::Result<proc_macro2::TokenStream> {
     let enum_ident = input.ident;
-    let mut match_arms_native: Vec<proc_macro2::TokenStream> = Vec::new();
-    let mut match_arms_le: Vec<proc_macro2::TokenStream> = Vec::new();
-    let mut match_arms_be: Vec<proc_macro2::TokenStream> = Vec::new();
-    let mut id: u16 = 1;
+    let enum_name = enum_ident.to_string();
+    let mut match_arms: Vec<proc_macro2::TokenStream> = Vec::new();
+    let order = byte_order(input.endian, &quote! { __O });
 
     for variant in &input.variants {
         let variant_ident = variant.ident;
+        let variant_order = byte_order(variant.endian, &order);
+        let id = input.id_pattern(variant);
+        let type_name = format!("{}::{}", enum_ident, variant_ident);
 
-        match variant.fields {
+        let (reads, init_variant) = match variant.fields {
             syn::Fields::Named(syn::FieldsNamed { named, .. }) => {
-                let mut field_idents: Vec<&syn::Ident> = Vec::new();
-                let mut field_tys: Vec<&syn::Type> = Vec::new();
-
-                for field in named {
-                    field_idents.push(field.ident.as_ref().unwrap());
-                    field_tys.push(&field.ty);
+                let field_idents = named.iter().map(|field| field.ident.as_ref().unwrap());
+                let (reads, locals) = field_reads(
+                    named
+                        .iter()
+                        .map(|field| (field, field.ident.as_ref().unwrap().to_string())),
+                    &type_name,
+                    by_ref,
+                    &variant_order,
+                    None,
+                )?;
+
+                (
+                    reads,
+                    quote! {
+                        #enum_ident::#variant_ident {
+                            #( #field_idents: #locals ),*
+                        }
+                    },
+                )
+            }
+            syn::Fields::Unnamed(syn::FieldsUnnamed { unnamed, .. }) => {
+                let (reads, locals) = field_reads(
+                    unnamed
+                        .iter()
+                        .enumerate()
+                        .map(|(index, field)| (field, index.to_string())),
+                    &type_name,
+                    by_ref,
+                    &variant_order,
+                    None,
+                )?;
+
+                (
+                    reads,
+                    quote! {
+                        #enum_ident::#variant_ident (
+                            #( #locals ),*
+                        )
+                    },
+                )
+            }
+            syn::Fields::Unit => (Vec::new(), quote! { #enum_ident::#variant_ident }),
+        };
+
+        // Fields a newer version appended to the variant are skipped over.
+        let finish = input.length_delimited.then(|| {
+            quote! {
+                if buffer.cursor() > __end {
+                    return Err(::mmap_bytey::MByteBufferError::PayloadOverrun {
+                        expected: __len,
+                        read: buffer.cursor() - __start,
+                        type_name: #type_name,
+                    });
                 }
 
-                match_arms_native.push(quote! {
-                    #id => {
-                        Ok(#enum_ident::#variant_ident {
-                            #( #field_idents: buffer.read::<#field_tys>()? ),*
-                        })
-                    }
-                });
+                buffer.skip(__end - buffer.cursor())?;
+            }
+        });
+
+        match_arms.push(quote! {
+            #id => {
+                #(#reads)*
+
+                let __value = #init_variant;
+
+                #finish
+
+                Ok(__value)
+            }
+        });
+    }
+
+    let fallback = match &input.other {
+        Some(other) => {
+            let variant_ident = other.ident;
+            let tag_member = &other.tag;
+            let payload = match by_ref {
+                true => quote! { buffer.read_slice(__len)? },
+                false => quote! { ::std::vec::Vec::from(buffer.read_slice(__len)?) },
+            };
+
+            match (&other.payload, input.length_delimited) {
+                (Some(payload_member), _) => quote! {
+                    tag => {
+                        let __payload = #payload;
 
-                match_arms_le.push(quote! {
-                    #id => {
                         Ok(#enum_ident::#variant_ident {
-                            #( #field_idents: buffer.read_le::<#field_tys>()? ),*
+                            #tag_member: tag,
+                            #payload_member: ::core::convert::From::from(__payload),
                         })
                     }
-                });
+                },
+                (None, true) => quote! {
+                    tag => {
+                        buffer.skip(__len)?;
 
-                match_arms_be.push(quote! {
-                    #id => {
-                        Ok(#enum_ident::#variant_ident {
-                            #( #field_idents: buffer.read_be::<#field_tys>()? ),*
-                        })
+                        Ok(#enum_ident::#variant_ident { #tag_member: tag })
                     }
-                });
+                },
+                (None, false) => quote! {
+                    tag => Ok(#enum_ident::#variant_ident { #tag_member: tag })
+                },
             }
-            syn::Fields::Unnamed(syn::FieldsUnnamed { unnamed, .. }) => {
-                let mut field_tys: Vec<&syn::Type> = Vec::new();
-
-                for field in unnamed {
-                    field_tys.push(&field.ty);
+        }
+        None => quote! {
+            tag => Err(::mmap_bytey::MByteBufferError::InvalidTag {
+                tag: ::core::convert::From::from(tag),
+                type_name: #enum_name,
+            })
+        },
+    };
+
+    // The length of the payload is checked against the buffer before any of it is read.
+    let read_len = input.length_delimited.then(|| {
+        quote! {
+            let __len = buffer.read_with::<#order, usize>()?;
+            let __start = buffer.cursor();
+            let __end = match __start.checked_add(__len) {
+                Some(end) if end <= buffer.length() => end,
+                _ => {
+                    return Err(::mmap_bytey::MByteBufferError::ReadOutOfBounds {
+                        length: buffer.length(),
+                        start: __start,
+                        end: __start.saturating_add(__len),
+                    })
                 }
+            };
+        }
+    });
 
-                match_arms_native.push(quote! {
-                    #id => {
-                        Ok(#enum_ident::#variant_ident (
-                            #( buffer.read::<#field_tys>()? ),*
-                        ))
-                    }
-                });
+    let read_tag = input.tag.read(&order);
+    let read_value = quote! {
+        match __tag {
+            #(#match_arms,)*
+            #fallback,
+        }
+    };
+    let read_value = match &input.post_read {
+        Some(post_read) => quote! {
+            let mut __value = #read_value?;
 
-                match_arms_le.push(quote! {
-                    #id => {
-                        Ok(#enum_ident::#variant_ident (
-                            #( buffer.read_le::<#field_tys>()? ),*
-                        ))
-                    }
-                });
+            #post_read(&mut __value)?;
+            Ok(__value)
+        },
+        None => read_value,
+    };
 
-                match_arms_be.push(quote! {
-                    #id => {
-                        Ok(#enum_ident::#variant_ident (
-                            #( buffer.read_be::<#field_tys>()? ),*
-                        ))
-                    }
+    let id_check = input.id_check();
+
+    Ok(quote! {
+        #id_check
+
+        let __tag = #read_tag;
+
+        #read_len
+
+        #read_value
+    })
+}
+
+/// The statements reading `fields` of `type_name` into locals in the byte order `order`, along
+/// with those locals.
+///
+/// Skipped fields, and fields not in the version that was read or whose `if` is false, are set to
+/// their default. `if` and `count` expressions see the fields read before them. Runs of fields
+/// with `bits = N` are unpacked most significant bit first from whole bytes. With a `version`,
+/// the version is read first and newer ones are rejected.
+fn field_reads<'a>(
+    fields: impl IntoIterator<Item = (&'a syn::Field, String)>,
+    type_name: &str,
+    by_ref: bool,
+    order: &proc_macro2::TokenStream,
+    version: Option<u32>,
+) -> syn::Result<(Vec<proc_macro2::TokenStream>, Vec<syn::Ident>)> {
+    let mut reads: Vec<proc_macro2::TokenStream> = Vec::new();
+
+    if let Some(version) = version {
+        reads.push(quote! {
+            let __version = buffer.read_with::<#order, u32>()?;
+
+            if __version > #version {
+                return Err(::mmap_bytey::MByteBufferError::UnsupportedVersion {
+                    version: __version,
+                    latest: #version,
+                    type_name: #type_name,
                 });
             }
-            syn::Fields::Unit => {
-                match_arms_native.push(quote! {
-                    #id => Ok(#enum_ident::#variant_ident)
-                });
+        });
+    }
 
-                match_arms_le.push(quote! {
-                    #id => Ok(#enum_ident::#variant_ident)
-                });
+    let mut locals: Vec<syn::Ident> = Vec::new();
+    let mut bit_fields: Vec<(syn::Ident, proc_macro2::TokenStream)> = Vec::new();
+    let mut refs = FieldRefs::default();
+
+    for (index, (field, field_name)) in fields.into_iter().enumerate() {
+        let mut attrs = FieldAttrs::from_field(field)?;
+        let local = quote::format_ident!("__field{}", index);
+
+        locals.push(local.clone());
+        attrs.in_current_version(field, version)?;
+        attrs.condition = attrs
+            .condition
+            .map(|expr| refs.rewrite(&expr))
+            .transpose()?;
+        attrs.count = attrs.count.map(|expr| refs.rewrite(&expr)).transpose()?;
+        refs.push(member(field, index), syn::parse_quote! { #local }, &attrs);
+
+        if attrs.ends_bit_run() {
+            reads.extend(bit_reads(std::mem::take(&mut bit_fields)));
+        }
 
-                match_arms_be.push(quote! {
-                    #id => Ok(#enum_ident::#variant_ident)
-                });
+        if attrs.skips_read() {
+            let default = attrs.default_value();
+
+            reads.push(quote! { let #local = #default; });
+            continue;
+        }
+
+        let read = read_field(
+            field,
+            &attrs,
+            type_name,
+            &field_name,
+            by_ref,
+            &byte_order(attrs.endian, order),
+        );
+
+        if attrs.bits.is_some() {
+            bit_fields.push((local, read));
+            continue;
+        }
+
+        match attrs.read_condition() {
+            Some(condition) => {
+                let default = attrs.default_value();
+
+                reads.push(quote! { let #local = if #condition { #read } else { #default }; });
             }
+            None => reads.push(quote! { let #local = #read; }),
         }
+    }
 
-        id += 1;
+    reads.extend(bit_reads(bit_fields));
+
+    Ok((reads, locals))
+}
+
+/// The member a field is accessed by, its name or its index.
+pub fn member(field: &syn::Field, index: usize) -> syn::Member {
+    match &field.ident {
+        Some(ident) => syn::Member::Named(ident.clone()),
+        None => syn::Member::Unnamed(syn::Index::from(index)),
     }
+}
 
-    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+/// Reads a run of bit fields into their locals.
+fn bit_reads(
+    fields: Vec<(syn::Ident, proc_macro2::TokenStream)>,
+) -> Option<proc_macro2::TokenStream> {
+    if fields.is_empty() {
+        return None;
+    }
 
-    quote! {
-        impl #impl_generics ::mmap_bytey::MByteBufferRead for #enum_ident #ty_generics #where_clause {
-            #[inline]
-            fn read_from_mbuffer(buffer: &mut ::mmap_bytey::MByteBuffer) -> ::mmap_bytey::Result<#enum_ident #ty_generics> {
-                match buffer.read::<u16>()? {
-                    #(#match_arms_native,)*
-                    id => Err(::mmap_bytey::MByteBufferError::OtherError { error: ::std::format!("Invalid id: {}", id) })
-                }
-            }
+    let (locals, reads): (Vec<_>, Vec<_>) = fields.into_iter().unzip();
 
-            #[inline]
-            fn read_from_mbuffer_le(buffer: &mut ::mmap_bytey::MByteBuffer) -> ::mmap_bytey::Result<#enum_ident #ty_generics> {
-                match buffer.read::<u16>()? {
-                    #(#match_arms_le,)*
-                    id => Err(::mmap_bytey::MByteBufferError::OtherError { error: ::std::format!("Invalid id: {}", id) })
-                }
-            }
+    Some(quote! {
+        let (#(#locals,)*) = {
+            let mut __bits = buffer.bit_reader::<::mmap_bytey::bits::MsbFirst>();
 
-            #[inline]
-            fn read_from_mbuffer_be(buffer: &mut ::mmap_bytey::MByteBuffer) -> ::mmap_bytey::Result<#enum_ident #ty_generics> {
-                match buffer.read::<u16>()? {
-                    #(#match_arms_be,)*
-                    id => Err(::mmap_bytey::MByteBufferError::OtherError { error: ::std::format!("Invalid id: {}", id) })
-                }
+            (#(#reads,)*)
+        };
+    })
+}
+
+/// Reads a field in the byte order `order`, or from `__bits` if it is a bit field, wrapping any
+/// error with the field's path. Fields with `encode_as = "Type"` are read as that type and
+/// converted back with `From`, and fields with `count = "expr"` are collected from that many
+/// elements. The bytes of `pad` and `align` are skipped first.
+fn read_field(
+    field: &syn::Field,
+    attrs: &FieldAttrs,
+    type_name: &str,
+    field_name: &str,
+    by_ref: bool,
+    order: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let field_ty = &field.ty;
+    let encoded_ty = attrs.encoded_type(field);
+    let read = match (attrs.bits, attrs.read_fn(by_ref), &attrs.count) {
+        (Some(bits), _, _) => quote! { __bits.read::<#encoded_ty>(#bits) },
+        (None, Some(read_fn), _) => quote! { #read_fn::<#order>(buffer) },
+        (None, None, Some(count)) => quote! {
+            (0..(#count) as usize)
+                .map(|__index| buffer.read_with::<#order, _>().map_err(|e| e.at_index(__index)))
+                .collect::<::mmap_bytey::Result<#field_ty>>()
+        },
+        (None, None, None) => quote! { buffer.read_with::<#order, #encoded_ty>() },
+    };
+    let read = quote! { #read.map_err(|e| e.in_field(#type_name, #field_name))? };
+    let read = match &attrs.encode_as {
+        Some(_) => quote! {
+            <#field_ty as ::core::convert::From<#encoded_ty>>::from(#read)
+        },
+        None => read,
+    };
+    let pad = attrs.pad.map(|pad| {
+        quote! { buffer.skip(#pad).map_err(|e| e.in_field(#type_name, #field_name))?; }
+    });
+    let align = attrs.align.map(|align| {
+        quote! { buffer.align_to(#align).map_err(|e| e.in_field(#type_name, #field_name))?; }
+    });
+
+    match (&pad, &align) {
+        (None, None) => read,
+        _ => quote! {
+            {
+                #pad
+                #align
+                #read
             }
-        }
+        },
     }
 }
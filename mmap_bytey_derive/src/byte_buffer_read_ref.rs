@@ -0,0 +1,94 @@
+use crate::{
+    bound::{with_bounds, Direction},
+    byte_buffer_read::{enum_reads, struct_reads, with_post_read},
+    field_wrapper::Bound,
+    source::{EnumSource, StructSource},
+};
+use quote::quote;
+
+pub fn expand_derive_byte_buffer_read_ref(
+    input: &syn::DeriveInput,
+) -> syn::Result<proc_macro2::TokenStream> {
+    match &input.data {
+        syn::Data::Struct(_) => {
+            let source = StructSource::from_input(input)?;
+
+            handle_struct(source)
+        }
+        syn::Data::Enum(_) => {
+            let source = EnumSource::from_input(input)?;
+
+            handle_enum(source)
+        }
+        syn::Data::Union(_) => Err(syn::Error::new(
+            input.ident.span(),
+            "Unions are currently not supported",
+        )),
+    }
+}
+
+fn handle_struct(input: StructSource) -> syn::Result<proc_macro2::TokenStream> {
+    let init_struct = with_post_read(struct_reads(&input, true)?, input.post_read.as_ref());
+
+    read_ref_impl(
+        input.ident,
+        input.generics,
+        input.fields,
+        &input.bound,
+        init_struct,
+    )
+}
+
+fn handle_enum(input: EnumSource) -> syn::Result<proc_macro2::TokenStream> {
+    let read_enum = enum_reads(&input, true)?;
+
+    read_ref_impl(
+        input.ident,
+        input.generics,
+        input.variants.iter().flat_map(|variant| variant.fields),
+        &input.bound,
+        read_enum,
+    )
+}
+
+/// The impl of `MByteBufferReadRef` for `ident`, whose function runs `body`.
+fn read_ref_impl<'a>(
+    ident: &syn::Ident,
+    input_generics: &syn::Generics,
+    fields: impl IntoIterator<Item = &'a syn::Field>,
+    bound: &Bound,
+    body: proc_macro2::TokenStream,
+) -> syn::Result<proc_macro2::TokenStream> {
+    // The buffer's borrow has to outlive every lifetime of the type for borrowed fields to be read.
+    let buffer_lifetime = syn::Lifetime::new("'__mbytey", proc_macro2::Span::call_site());
+    let bounded = with_bounds(
+        input_generics,
+        fields,
+        bound,
+        Direction::Read,
+        quote! { ::mmap_bytey::MByteBufferReadRef<#buffer_lifetime> },
+    )?;
+    let mut generics = bounded.clone();
+    let mut buffer_param = syn::LifetimeParam::new(buffer_lifetime.clone());
+
+    buffer_param.bounds.extend(
+        input_generics
+            .lifetimes()
+            .map(|param| param.lifetime.clone()),
+    );
+    generics
+        .params
+        .insert(0, syn::GenericParam::Lifetime(buffer_param));
+
+    let (impl_generics, _, _) = generics.split_for_impl();
+    let (_, ty_generics, where_clause) = bounded.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::mmap_bytey::MByteBufferReadRef<#buffer_lifetime> for #ident #ty_generics #where_clause {
+            #[inline]
+            fn read_ref_from_mbuffer_with<__O: ::mmap_bytey::ByteOrder>(buffer: &mut ::mmap_bytey::MByteBufferReader<#buffer_lifetime>) -> ::mmap_bytey::Result<#ident #ty_generics> {
+                #body
+            }
+        }
+    })
+}
@@ -1,131 +1,131 @@
-use crate::field_wrapper::{is_skipped, FieldWrapper};
+use crate::bound::{with_bounds, Direction};
+use crate::byte_buffer_read::member;
+use crate::field_refs::FieldRefs;
+use crate::field_wrapper::{byte_order, FieldAttrs, FieldWrapper};
 use crate::source::{EnumSource, StructSource};
 use quote::quote;
 use syn::spanned::Spanned;
 
-pub fn expand_derive_byte_buffer_write(input: &syn::DeriveInput) -> proc_macro2::TokenStream {
+pub fn expand_derive_byte_buffer_write(
+    input: &syn::DeriveInput,
+) -> syn::Result<proc_macro2::TokenStream> {
     match &input.data {
         syn::Data::Struct(_) => {
-            let source = StructSource::from_input(input);
+            let source = StructSource::from_input(input)?;
 
             handle_struct(source)
         }
         syn::Data::Enum(_) => {
-            let source = EnumSource::from_input(input);
+            let source = EnumSource::from_input(input)?;
 
             handle_enum(source)
         }
-        syn::Data::Union(_) => {
-            input
-                .ident
-                .span()
-                .unwrap()
-                .error("Unions are currently not supported")
-                .emit();
-
-            proc_macro2::TokenStream::new()
-        }
+        syn::Data::Union(_) => Err(syn::Error::new(
+            input.ident.span(),
+            "Unions are currently not supported",
+        )),
     }
 }
 
-fn handle_struct(input: StructSource) -> proc_macro2::TokenStream {
-    let mut fields: Vec<FieldWrapper> = Vec::new();
-
-    match input.fields {
-        syn::Fields::Named(syn::FieldsNamed { named, .. }) => {
-            for field in named {
-                if is_skipped(field) {
-                    continue;
-                }
-
-                fields.push(FieldWrapper {
-                    field: Some(field.ident.as_ref().unwrap()),
-                    index: None,
-                });
-            }
-        }
-        syn::Fields::Unnamed(syn::FieldsUnnamed { unnamed, .. }) => {
-            for (count, field) in unnamed.into_iter().enumerate() {
-                if is_skipped(field) {
-                    continue;
-                }
+fn handle_struct(input: StructSource) -> syn::Result<proc_macro2::TokenStream> {
+    if let syn::Fields::Unit = input.fields {
+        return Err(syn::Error::new(
+            input.ident.span(),
+            "Unit structs are currently not supported",
+        ));
+    }
 
-                fields.push(FieldWrapper {
-                    field: None,
-                    index: Some(syn::Index::from(count)),
-                });
-            }
-        }
-        syn::Fields::Unit => {
-            input
-                .ident
-                .span()
-                .unwrap()
-                .error("Unit structs are currently not supported")
-                .emit();
-
-            return proc_macro2::TokenStream::new();
-        }
+    let FieldWrites {
+        mut writes,
+        mut lens,
+        mut sizes,
+    } = FieldWrites::new(
+        input.fields.iter().enumerate().map(|(count, field)| {
+            let field_wrapper = FieldWrapper {
+                field: field.ident.as_ref(),
+                index: field.ident.is_none().then(|| syn::Index::from(count)),
+            };
+
+            (field, quote! { self.#field_wrapper })
+        }),
+        &input.ident.to_string(),
+        &byte_order(input.endian, &quote! { __O }),
+        input.version,
+    )?;
+
+    if let Some(magic) = &input.magic {
+        let magic_len = magic.value().len();
+
+        writes.insert(0, quote! { buffer.write_slice(#magic)?; });
+        lens.insert(0, quote! { #magic_len });
+        sizes.insert(0, quote! { Some(#magic_len) });
     }
 
-    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let generics = with_bounds(
+        input.generics,
+        input.fields,
+        &input.bound,
+        Direction::Write,
+        quote! { ::mmap_bytey::MByteBufferWrite },
+    )?;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
     let ident = input.ident;
+    let fixed_size = quote! {
+        const FIXED_SIZE: Option<usize> = ::mmap_bytey::byte_buffer_write::fixed_size_sum(&[
+            #(#sizes),*
+        ]);
+    };
+    let encoded_len = quote! {
+        #[inline]
+        fn encoded_len(&self) -> ::mmap_bytey::Result<usize> {
+            self.encoded_len_at(0)
+        }
 
-    quote! {
-        impl #impl_generics ::mmap_bytey::MByteBufferWrite for #ident #ty_generics #where_clause {
-            #[inline]
-            fn write_to_mbuffer(&self, buffer: &mut ::mmap_bytey::MByteBuffer) -> ::mmap_bytey::Result<()> {
-                #(self.#fields.write_to_mbuffer(buffer)?;)*
+        #[inline]
+        fn encoded_len_at(&self, __position: usize) -> ::mmap_bytey::Result<usize> {
+            let __len = 0usize;
+            #(let __len = __len + #lens;)*
 
-                Ok(())
-            }
+            Ok(__len)
+        }
+    };
 
-            #[inline]
-            fn write_to_mbuffer_le(&self, buffer: &mut ::mmap_bytey::MByteBuffer) -> ::mmap_bytey::Result<()> {
-                #(self.#fields.write_to_mbuffer_le(buffer)?;)*
+    Ok(quote! {
+        impl #impl_generics ::mmap_bytey::MByteBufferWrite for #ident #ty_generics #where_clause {
+            #fixed_size
 
-                Ok(())
-            }
+            #encoded_len
 
             #[inline]
-            fn write_to_mbuffer_be(&self, buffer: &mut ::mmap_bytey::MByteBuffer) -> ::mmap_bytey::Result<()> {
-                #(self.#fields.write_to_mbuffer_be(buffer)?;)*
+            fn write_to_mbuffer_with<__O: ::mmap_bytey::ByteOrder>(&self, buffer: &mut ::mmap_bytey::MByteBuffer) -> ::mmap_bytey::Result<()> {
+                #(#writes)*
 
                 Ok(())
             }
         }
 
         impl #impl_generics ::mmap_bytey::MByteBufferWrite for &#ident #ty_generics #where_clause {
-            #[inline]
-            fn write_to_mbuffer(&self, buffer: &mut ::mmap_bytey::MByteBuffer) -> ::mmap_bytey::Result<()> {
-                #(self.#fields.write_to_mbuffer(buffer)?;)*
+            #fixed_size
 
-                Ok(())
-            }
+            #encoded_len
 
             #[inline]
-            fn write_to_mbuffer_le(&self, buffer: &mut ::mmap_bytey::MByteBuffer) -> ::mmap_bytey::Result<()> {
-                #(self.#fields.write_to_mbuffer_le(buffer)?;)*
-
-                Ok(())
-            }
-
-            #[inline]
-            fn write_to_mbuffer_be(&self, buffer: &mut ::mmap_bytey::MByteBuffer) -> ::mmap_bytey::Result<()> {
-                #(self.#fields.write_to_mbuffer_be(buffer)?;)*
+            fn write_to_mbuffer_with<__O: ::mmap_bytey::ByteOrder>(&self, buffer: &mut ::mmap_bytey::MByteBuffer) -> ::mmap_bytey::Result<()> {
+                #(#writes)*
 
                 Ok(())
             }
         }
-    }
+    })
 }
 
-fn handle_enum(input: EnumSource) -> proc_macro2::TokenStream {
+fn handle_enum(input: EnumSource) -> syn::Result<proc_macro2::TokenStream> {
     let enum_ident = input.ident;
-    let mut variants_native: Vec<proc_macro2::TokenStream> = Vec::new();
-    let mut variants_le: Vec<proc_macro2::TokenStream> = Vec::new();
-    let mut variants_be: Vec<proc_macro2::TokenStream> = Vec::new();
-    let mut id: u16 = 1;
+    let mut variants: Vec<proc_macro2::TokenStream> = Vec::new();
+    let mut variants_len: Vec<proc_macro2::TokenStream> = Vec::new();
+    let mut variant_sizes: Vec<proc_macro2::TokenStream> = Vec::new();
+    let tag = input.tag;
+    let order = byte_order(input.endian, &quote! { __O });
 
     for variant in &input.variants {
         let mut field_idents: Vec<syn::Ident> = Vec::new();
@@ -152,89 +152,387 @@ fn handle_enum(input: EnumSource) -> proc_macro2::TokenStream {
             syn::Fields::Unit => quote! {#enum_ident::#variant_ident},
         };
 
-        variants_native.push(quote! {
+        let FieldWrites {
+            writes,
+            lens,
+            sizes,
+        } = FieldWrites::new(
+            variant
+                .fields
+                .iter()
+                .zip(&field_idents)
+                .map(|(field, ident)| (field, quote! { (*#ident) })),
+            &format!("{}::{}", enum_ident, variant_ident),
+            &byte_order(variant.endian, &order),
+            None,
+        )?;
+
+        let id = tag.wrap(input.id(variant));
+        let id_len = tag.len(&id);
+        let writes = match input.length_delimited {
+            true => length_delimited(quote! { #(#writes)* }, &order),
+            false => quote! { #(#writes)* },
+        };
+        let (mut lens, mut sizes) = (lens, sizes);
+
+        if input.length_delimited {
+            lens.insert(0, quote! { ::core::mem::size_of::<usize>() });
+            sizes.insert(0, quote! { Some(::core::mem::size_of::<usize>()) });
+        }
+
+        // Skipped fields are never used and bit fields do not need their values for the length.
+        variants.push(quote! {
+            #[allow(unused_variables)]
             #variant_match_case => {
-                #id.write_to_mbuffer(buffer)?;
-                #(#field_idents.write_to_mbuffer(buffer)?;)*
+                #id.write_to_mbuffer_with::<#order>(buffer)?;
+                #writes
             }
         });
 
-        variants_le.push(quote! {
+        variants_len.push(quote! {
+            #[allow(unused_variables)]
             #variant_match_case => {
-                #id.write_to_mbuffer_le(buffer)?;
-                #(#field_idents.write_to_mbuffer_le(buffer)?;)*
+                let __len = #id_len;
+                #(let __len = __len + #lens;)*
+
+                Ok(__len)
             }
         });
 
-        variants_be.push(quote! {
-            #variant_match_case => {
-                #id.write_to_mbuffer_be(buffer)?;
-                #(#field_idents.write_to_mbuffer_be(buffer)?;)*
+        variant_sizes.push(quote! {
+            ::mmap_bytey::byte_buffer_write::fixed_size_sum(&[
+                Some(#id_len),
+                #(#sizes),*
+            ])
+        });
+    }
+
+    if let Some(other) = &input.other {
+        let variant_ident = other.ident;
+        let tag_member = &other.tag;
+        let id = tag.wrap(quote! { *__tag });
+        let id_len = tag.len(&id);
+        let (pattern, payload_write, payload_len) = match (&other.payload, input.length_delimited) {
+            (Some(payload_member), _) => (
+                quote! { #enum_ident::#variant_ident { #tag_member: __tag, #payload_member: __payload } },
+                quote! {
+                    let __payload: &[u8] = ::core::convert::AsRef::<[u8]>::as_ref(__payload);
+
+                    buffer.write_with::<#order, usize>(__payload.len())?;
+                    buffer.write_slice(__payload)?;
+                },
+                quote! { + ::core::mem::size_of::<usize>() + ::core::convert::AsRef::<[u8]>::as_ref(__payload).len() },
+            ),
+            (None, true) => (
+                quote! { #enum_ident::#variant_ident { #tag_member: __tag } },
+                quote! { buffer.write_with::<#order, usize>(0)?; },
+                quote! { + ::core::mem::size_of::<usize>() },
+            ),
+            (None, false) => (
+                quote! { #enum_ident::#variant_ident { #tag_member: __tag } },
+                quote! {},
+                quote! {},
+            ),
+        };
+
+        variants.push(quote! {
+            #pattern => {
+                #id.write_to_mbuffer_with::<#order>(buffer)?;
+                #payload_write
             }
         });
 
-        id += 1;
+        variants_len.push(quote! {
+            #pattern => Ok(#id_len #payload_len)
+        });
+
+        // The tag of an unknown variant is only known at runtime, as is its payload.
+        let size = match (tag.fixed_len(), input.length_delimited) {
+            (Some(len), false) => quote! { Some(#len) },
+            _ => quote! { None },
+        };
+
+        variant_sizes.push(size);
     }
 
-    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let generics = with_bounds(
+        input.generics,
+        input.variants.iter().flat_map(|variant| variant.fields),
+        &input.bound,
+        Direction::Write,
+        quote! { ::mmap_bytey::MByteBufferWrite },
+    )?;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let id_check = input.id_check();
+    let fixed_size = quote! {
+        const FIXED_SIZE: Option<usize> = ::mmap_bytey::byte_buffer_write::fixed_size_same(&[
+            #(#variant_sizes),*
+        ]);
+    };
+    let encoded_len = quote! {
+        #[inline]
+        fn encoded_len(&self) -> ::mmap_bytey::Result<usize> {
+            self.encoded_len_at(0)
+        }
 
-    quote! {
+        #[inline]
+        fn encoded_len_at(&self, __position: usize) -> ::mmap_bytey::Result<usize> {
+            match self {
+                #(#variants_len),*
+            }
+        }
+    };
+
+    Ok(quote! {
         impl #impl_generics ::mmap_bytey::MByteBufferWrite for #enum_ident #ty_generics #where_clause {
-            #[inline]
-            fn write_to_mbuffer(&self, buffer: &mut ::mmap_bytey::MByteBuffer) -> ::mmap_bytey::Result<()> {
-                match self {
-                    #(#variants_native),*
-                }
+            #fixed_size
 
-                Ok(())
-            }
+            #encoded_len
 
             #[inline]
-            fn write_to_mbuffer_le(&self, buffer: &mut ::mmap_bytey::MByteBuffer) -> ::mmap_bytey::Result<()> {
+            fn write_to_mbuffer_with<__O: ::mmap_bytey::ByteOrder>(&self, buffer: &mut ::mmap_bytey::MByteBuffer) -> ::mmap_bytey::Result<()> {
+                #id_check
+
                 match self {
-                    #(#variants_le),*
+                    #(#variants),*
                 }
 
                 Ok(())
             }
+        }
+
+        impl #impl_generics ::mmap_bytey::MByteBufferWrite for &#enum_ident #ty_generics #where_clause {
+            #fixed_size
+
+            #encoded_len
 
             #[inline]
-            fn write_to_mbuffer_be(&self, buffer: &mut ::mmap_bytey::MByteBuffer) -> ::mmap_bytey::Result<()> {
+            fn write_to_mbuffer_with<__O: ::mmap_bytey::ByteOrder>(&self, buffer: &mut ::mmap_bytey::MByteBuffer) -> ::mmap_bytey::Result<()> {
+                #id_check
+
                 match self {
-                    #(#variants_be),*
+                    #(#variants),*
                 }
 
                 Ok(())
             }
         }
+    })
+}
 
-        impl #impl_generics ::mmap_bytey::MByteBufferWrite for &#enum_ident #ty_generics #where_clause {
-            #[inline]
-            fn write_to_mbuffer(&self, buffer: &mut ::mmap_bytey::MByteBuffer) -> ::mmap_bytey::Result<()> {
-                match self {
-                    #(#variants_native),*
-                }
+/// Wraps the writes of a variant's fields so they are prefixed with the number of bytes they take
+/// up, which is filled in once they are written.
+fn length_delimited(
+    writes: proc_macro2::TokenStream,
+    order: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    quote! {
+        let __start = buffer.cursor();
 
-                Ok(())
+        buffer.write_with::<#order, usize>(0)?;
+        #writes
+
+        let __end = buffer.cursor();
+
+        buffer.move_cursor(__start)?;
+        buffer.write_with::<#order, usize>(__end - __start - ::core::mem::size_of::<usize>())?;
+        buffer.move_cursor(__end)?;
+    }
+}
+
+/// The code writing a list of fields, along with what each write adds to the encoded length and
+/// its fixed size. What a write adds can depend on `__len`, the encoded length before it, and
+/// `__position`, the buffer position the value starts at.
+#[derive(Default)]
+struct FieldWrites {
+    writes: Vec<proc_macro2::TokenStream>,
+    lens: Vec<proc_macro2::TokenStream>,
+    sizes: Vec<proc_macro2::TokenStream>,
+}
+
+impl FieldWrites {
+    /// Builds the writes of `fields` of `type_name`, each given with a place expression of its
+    /// value, in the byte order `order` unless a field sets its own. Runs of fields with
+    /// `bits = N` are packed together and padded to a whole byte. `pad` and `align` write zeros
+    /// before their field, and `align` counts towards the encoded length as if the value started
+    /// at an aligned position. With a `version` it is written
+    /// first, followed by the fields in that version. `if` and `count` expressions see the fields
+    /// before them.
+    fn new<'a>(
+        fields: impl IntoIterator<Item = (&'a syn::Field, proc_macro2::TokenStream)>,
+        type_name: &str,
+        order: &proc_macro2::TokenStream,
+        version: Option<u32>,
+    ) -> syn::Result<Self> {
+        let mut field_writes = FieldWrites::default();
+        let mut bit_fields = Vec::new();
+        let mut refs = FieldRefs::default();
+
+        if let Some(version) = version {
+            field_writes
+                .writes
+                .push(quote! { buffer.write_with::<#order, u32>(#version)?; });
+            field_writes.lens.push(quote! { 4usize });
+            field_writes.sizes.push(quote! { Some(4usize) });
+        }
+
+        for (index, (field, place)) in fields.into_iter().enumerate() {
+            let mut attrs = FieldAttrs::from_field(field)?;
+            let member = member(field, index);
+            let value = quote! { &#place };
+
+            attrs.condition = attrs
+                .condition
+                .map(|expr| refs.rewrite(&expr))
+                .transpose()?;
+            attrs.count = attrs.count.map(|expr| refs.rewrite(&expr)).transpose()?;
+            refs.push(member.clone(), syn::parse_quote! { #place }, &attrs);
+
+            if attrs.ends_bit_run() {
+                field_writes.push_bits(std::mem::take(&mut bit_fields));
             }
 
-            #[inline]
-            fn write_to_mbuffer_le(&self, buffer: &mut ::mmap_bytey::MByteBuffer) -> ::mmap_bytey::Result<()> {
-                match self {
-                    #(#variants_le),*
-                }
+            if !attrs.in_current_version(field, version)? || attrs.skips_write() {
+                continue;
+            }
 
-                Ok(())
+            if let Some(bits) = attrs.bits {
+                bit_fields.push((attrs.encoded_ref(field, value), bits));
+                continue;
             }
 
-            #[inline]
-            fn write_to_mbuffer_be(&self, buffer: &mut ::mmap_bytey::MByteBuffer) -> ::mmap_bytey::Result<()> {
-                match self {
-                    #(#variants_be),*
-                }
+            let order = byte_order(attrs.endian, order);
+            let (write, len, size) = if let Some(write_fn) = attrs.write_fn() {
+                (
+                    quote! { #write_fn::<#order>((#value), buffer)?; },
+                    // There is no way to know the length but to write the field out.
+                    quote! {
+                        {
+                            let mut __counter = ::mmap_bytey::MByteBuffer::counter();
+
+                            #write_fn::<::mmap_bytey::Native>((#value), &mut __counter)?;
+                            __counter.length()
+                        }
+                    },
+                    quote! { None },
+                )
+            } else if let Some(count) = &attrs.count {
+                let field_path = format!("{}.{}", type_name, quote! { #member });
+
+                (
+                    quote! {
+                        {
+                        let __count = (#count) as usize;
+                        let __len = (#value).len();
+
+                        if __len != __count {
+                            return Err(::mmap_bytey::MByteBufferError::CountMismatch {
+                                field: #field_path,
+                                count: __count,
+                                len: __len,
+                            });
+                        }
+
+                        for __item in #value {
+                            __item.write_to_mbuffer_with::<#order>(buffer)?;
+                        }
+                        }
+                    },
+                    quote! {
+                        {
+                            let mut __items_len = 0usize;
+
+                            for __item in #value {
+                                __items_len += __item.encoded_len_at(__position + __len + __items_len)?;
+                            }
+
+                            __items_len
+                        }
+                    },
+                    quote! { None },
+                )
+            } else {
+                let field_ty = attrs.encoded_type(field);
+                let value = attrs.encoded_value(field, value);
+
+                (
+                    quote! { #value.write_to_mbuffer_with::<#order>(buffer)?; },
+                    quote! { #value.encoded_len_at(__position + __len)? },
+                    quote! { <#field_ty as ::mmap_bytey::MByteBufferWrite>::FIXED_SIZE },
+                )
+            };
+
+            let mut parts = Vec::new();
+
+            if let Some(pad) = attrs.pad {
+                parts.push((
+                    quote! { buffer.write_zeros(#pad)?; },
+                    quote! { #pad },
+                    quote! { Some(#pad) },
+                ));
+            }
 
-                Ok(())
+            if let Some(align) = attrs.align {
+                parts.push((
+                    quote! { buffer.pad_to(#align)?; },
+                    quote! { ::mmap_bytey::byte_buffer_write::padding_to(__position + __len, #align) },
+                    quote! { None },
+                ));
             }
+
+            parts.push((write, len, size));
+
+            match &attrs.condition {
+                Some(condition) => {
+                    let writes = parts.iter().map(|(write, _, _)| write);
+                    let lens = parts.iter().map(|(_, len, _)| len);
+
+                    field_writes
+                        .writes
+                        .push(quote! { if #condition { #(#writes)* } });
+                    field_writes.lens.push(quote! {
+                        if #condition {
+                            let __start = __len;
+                            #(let __len = __len + #lens;)*
+
+                            __len - __start
+                        } else {
+                            0
+                        }
+                    });
+                    field_writes.sizes.push(quote! { None });
+                }
+                None => {
+                    for (write, len, size) in parts {
+                        field_writes.writes.push(write);
+                        field_writes.lens.push(len);
+                        field_writes.sizes.push(size);
+                    }
+                }
+            }
+        }
+
+        field_writes.push_bits(bit_fields);
+        Ok(field_writes)
+    }
+
+    /// Packs a run of bit fields most significant bit first, taking up whole bytes.
+    fn push_bits(&mut self, fields: Vec<(proc_macro2::TokenStream, u32)>) {
+        if fields.is_empty() {
+            return;
         }
+
+        let bytes = fields.iter().map(|(_, bits)| bits).sum::<u32>().div_ceil(8) as usize;
+        let (values, bits): (Vec<_>, Vec<_>) = fields.into_iter().unzip();
+
+        self.writes.push(quote! {
+            {
+                let mut __bits = buffer.bit_writer::<::mmap_bytey::bits::MsbFirst>();
+                #(__bits.write(#values, #bits)?;)*
+                __bits.finish()?;
+            }
+        });
+        self.lens.push(quote! { #bytes });
+        self.sizes.push(quote! { Some(#bytes) });
     }
 }
@@ -0,0 +1,121 @@
+use crate::field_wrapper::FieldAttrs;
+use syn::visit_mut::{self, VisitMut};
+
+/// The fields an `if` or `count` expression can refer to, either as `self.field` or by name,
+/// along with the expression each one is replaced with in the generated code, or why it can not
+/// be used.
+#[derive(Default)]
+pub struct FieldRefs {
+    fields: Vec<(syn::Member, Result<syn::Expr, &'static str>)>,
+}
+
+impl FieldRefs {
+    /// Makes `member` available to the expressions of the fields after it. A field the reader
+    /// can decode as its default while the writer has its real value is not, as the expression
+    /// would be evaluated differently on both sides.
+    pub fn push(&mut self, member: syn::Member, replacement: syn::Expr, attrs: &FieldAttrs) {
+        let replacement = if attrs.skip {
+            Err("`skip`")
+        } else if attrs.skip_read {
+            Err("`skip_read`")
+        } else if attrs.skip_write {
+            Err("`skip_write`")
+        } else if attrs.is_versioned() {
+            Err("`since` or `until`")
+        } else {
+            Ok(replacement)
+        };
+
+        self.fields.push((member, replacement));
+    }
+
+    /// Replaces the fields `expr` refers to. Referring through `self` to a field that is not
+    /// available is an error, while other names are left alone so constants can be used.
+    pub fn rewrite(&self, expr: &syn::Expr) -> syn::Result<syn::Expr> {
+        let mut rewriter = Rewriter {
+            refs: self,
+            error: None,
+        };
+        let mut expr = expr.clone();
+
+        rewriter.visit_expr_mut(&mut expr);
+
+        match rewriter.error {
+            Some(error) => Err(error),
+            None => Ok(expr),
+        }
+    }
+
+    fn get(&self, member: &syn::Member) -> Option<&Result<syn::Expr, &'static str>> {
+        self.fields
+            .iter()
+            .find(|(field, _)| field == member)
+            .map(|(_, replacement)| replacement)
+    }
+}
+
+struct Rewriter<'a> {
+    refs: &'a FieldRefs,
+    error: Option<syn::Error>,
+}
+
+impl Rewriter<'_> {
+    fn replace(
+        &mut self,
+        expr: &mut syn::Expr,
+        replacement: &Result<syn::Expr, &'static str>,
+        member: &syn::Member,
+    ) {
+        match replacement {
+            Ok(replacement) => *expr = replacement.clone(),
+            Err(reason) => self.error(syn::Error::new_spanned(
+                &*expr,
+                format!(
+                    "`{}` has {}, so it is not always read as it was written and can not be used",
+                    quote::quote! { #member },
+                    reason
+                ),
+            )),
+        }
+    }
+
+    fn error(&mut self, error: syn::Error) {
+        match &mut self.error {
+            Some(errors) => errors.combine(error),
+            None => self.error = Some(error),
+        }
+    }
+}
+
+impl VisitMut for Rewriter<'_> {
+    fn visit_expr_mut(&mut self, expr: &mut syn::Expr) {
+        match expr {
+            syn::Expr::Field(field) if is_self(&field.base) => {
+                let member = field.member.clone();
+
+                match self.refs.get(&member) {
+                    Some(replacement) => self.replace(expr, replacement, &member),
+                    None => self.error(syn::Error::new_spanned(
+                        &member,
+                        "only fields before this one can be used",
+                    )),
+                }
+            }
+            syn::Expr::Path(path) if path.qself.is_none() => {
+                let Some(ident) = path.path.get_ident() else {
+                    return;
+                };
+                let member = syn::Member::Named(ident.clone());
+
+                if let Some(replacement) = self.refs.get(&member) {
+                    self.replace(expr, replacement, &member);
+                }
+            }
+            _ => visit_mut::visit_expr_mut(self, expr),
+        }
+    }
+}
+
+fn is_self(expr: &syn::Expr) -> bool {
+    matches!(expr, syn::Expr::Path(path) if path.qself.is_none() && path.path.is_ident("self"))
+}
@@ -17,31 +17,751 @@ impl quote::ToTokens for FieldWrapper<'_> {
     }
 }
 
-pub fn is_skipped(field: &syn::Field) -> bool {
-    let mut ret = false;
+/// The `#[mbytey(...)]` attributes of a field.
+#[derive(Default)]
+pub struct FieldAttrs {
+    pub skip: bool,
+    /// Whether only the `MByteBufferRead` derives leave the field out, from `skip_read`.
+    pub skip_read: bool,
+    /// Whether only the `MByteBufferWrite` derive leaves the field out, from `skip_write`.
+    pub skip_write: bool,
+    /// The value a field that is not read is set to, from `default = "expr"`.
+    pub default: Option<syn::Expr>,
+    /// The type the field is converted to with `From` and written as, from `encode_as = "Type"`.
+    pub encode_as: Option<syn::Type>,
+    /// The number of bits the field is packed into with its neighbours, from `bits = N`.
+    pub bits: Option<u32>,
+    /// The module whose `write` and `read` functions encode the field, from `with = "path"`.
+    pub with: Option<syn::Path>,
+    /// The function the field is written with, from `write_with = "path"`.
+    pub write_with: Option<syn::Path>,
+    /// The function the field is read with, from `read_with = "path"`.
+    pub read_with: Option<syn::Path>,
+    /// The byte order the field is always written in, from `endian = "big"`.
+    pub endian: Option<Endian>,
+    /// The first version the field is in, from `since = N`.
+    pub since: Option<u32>,
+    /// The version the field was removed in, from `until = N`.
+    pub until: Option<u32>,
+    /// The condition for the field to be written and read, from `if = "expr"`.
+    pub condition: Option<syn::Expr>,
+    /// The number of elements the field holds, written without a length, from `count = "expr"`.
+    pub count: Option<syn::Expr>,
+    /// The number of zero bytes before the field, from `pad = N`.
+    pub pad: Option<usize>,
+    /// The multiple of the buffer position the field starts at, from `align = N`.
+    pub align: Option<usize>,
+    /// The bounds replacing those inferred from the field, from `bound = "..."`.
+    pub bound: Bound,
+}
+
+impl FieldAttrs {
+    pub fn from_field(field: &syn::Field) -> syn::Result<FieldAttrs> {
+        let mut attrs = FieldAttrs::default();
+
+        for attr in &field.attrs {
+            for meta_item in get_bytey_meta_items(attr)? {
+                match &meta_item {
+                    Meta::Path(word) if word == SKIP => attrs.skip = true,
+                    Meta::Path(word) if word == SKIP_READ => attrs.skip_read = true,
+                    Meta::Path(word) if word == SKIP_WRITE => attrs.skip_write = true,
+                    Meta::NameValue(pair) if pair.path == DEFAULT => {
+                        attrs.default = Some(expr_lit(&pair.value, "default = \"Vec::new()\"")?);
+                    }
+                    Meta::NameValue(pair) if pair.path == IF => {
+                        attrs.condition = Some(expr_lit(&pair.value, "if = \"self.flags & 1 != 0\"")?);
+                    }
+                    Meta::NameValue(pair) if pair.path == COUNT => {
+                        attrs.count = Some(expr_lit(&pair.value, "count = \"self.len\"")?);
+                    }
+                    Meta::NameValue(pair) if pair.path == PAD => {
+                        let lit = int_lit(&pair.value, "expected an integer, as in `pad = 4`")?;
+
+                        attrs.pad = Some(lit.base10_parse::<usize>()?);
+                    }
+                    Meta::NameValue(pair) if pair.path == ALIGN => {
+                        let lit = int_lit(&pair.value, "expected an integer, as in `align = 8`")?;
+
+                        match lit.base10_parse::<usize>()? {
+                            0 => return Err(syn::Error::new_spanned(lit, "expected an alignment of at least 1")),
+                            align => attrs.align = Some(align),
+                        }
+                    }
+                    meta if meta.path() == BOUND => attrs.bound = Bound::from_meta(meta)?,
+                    Meta::NameValue(pair) if pair.path == ENCODE_AS => {
+                        let syn::Expr::Lit(syn::ExprLit {
+                            lit: syn::Lit::Str(lit),
+                            ..
+                        }) = &pair.value
+                        else {
+                            return Err(syn::Error::new_spanned(
+                                &pair.value,
+                                "expected a string of a type, as in `encode_as = \"Type\"`",
+                            ));
+                        };
+
+                        attrs.encode_as = Some(lit.parse::<syn::Type>()?);
+                    }
+                    Meta::NameValue(pair) if pair.path == BITS => {
+                        let lit = int_lit(&pair.value, "expected an integer, as in `bits = 3`")?;
+
+                        match lit.base10_parse::<u32>()? {
+                            bits @ 1..=64 => attrs.bits = Some(bits),
+                            _ => return Err(syn::Error::new_spanned(lit, "expected 1 to 64 bits")),
+                        }
+                    }
+                    Meta::NameValue(pair) if pair.path == SINCE => {
+                        let lit = int_lit(&pair.value, "expected an integer, as in `since = 2`")?;
+
+                        attrs.since = Some(lit.base10_parse::<u32>()?);
+                    }
+                    Meta::NameValue(pair) if pair.path == UNTIL => {
+                        let lit = int_lit(&pair.value, "expected an integer, as in `until = 4`")?;
+
+                        attrs.until = Some(lit.base10_parse::<u32>()?);
+                    }
+                    Meta::NameValue(pair) if pair.path == ENDIAN => {
+                        attrs.endian = Some(Endian::from_expr(&pair.value)?);
+                    }
+                    Meta::NameValue(pair) if pair.path == WITH => {
+                        attrs.with = Some(path_lit(&pair.value, "with = \"module\"")?);
+                    }
+                    Meta::NameValue(pair) if pair.path == WRITE_WITH => {
+                        attrs.write_with = Some(path_lit(&pair.value, "write_with = \"function\"")?);
+                    }
+                    Meta::NameValue(pair) if pair.path == READ_WITH => {
+                        attrs.read_with = Some(path_lit(&pair.value, "read_with = \"function\"")?);
+                    }
+                    _ => return Err(syn::Error::new_spanned(
+                        meta_item,
+                        "unknown mbytey field attribute, expected `skip`, `skip_read`, `skip_write`, `default`, `encode_as`, `bits`, `endian`, `since`, `until`, `if`, `count`, `pad`, `align`, `bound`, `with`, `write_with` or `read_with`",
+                    )),
+                }
+            }
+        }
+
+        let has_codec =
+            attrs.with.is_some() || attrs.write_with.is_some() || attrs.read_with.is_some();
+
+        if let Some(default) = &attrs.default {
+            if !attrs.skips_read() && !attrs.is_versioned() && attrs.condition.is_none() {
+                return Err(syn::Error::new_spanned(
+                    default,
+                    "`default` is only used for fields that are not always read, add `skip`, `skip_read`, `since`, `until` or `if`",
+                ));
+            }
+        }
+
+        if (attrs.is_versioned() || attrs.condition.is_some()) && attrs.bits.is_some() {
+            return Err(syn::Error::new_spanned(
+                field,
+                "bit fields can not be made conditional with `since`, `until` or `if`",
+            ));
+        }
+
+        if (attrs.pad.is_some() || attrs.align.is_some()) && attrs.bits.is_some() {
+            return Err(syn::Error::new_spanned(
+                field,
+                "bit fields are packed with their neighbours and can not be padded with `pad` or `align`",
+            ));
+        }
+
+        if attrs.count.is_some() && (attrs.bits.is_some() || attrs.encode_as.is_some() || has_codec)
+        {
+            return Err(syn::Error::new_spanned(
+                field,
+                "`count` can not be combined with `bits`, `encode_as`, `with`, `write_with` or `read_with`",
+            ));
+        }
+
+        if let (Some(since), Some(until)) = (attrs.since, attrs.until) {
+            if until <= since {
+                return Err(syn::Error::new_spanned(
+                    field,
+                    "`until` is the version the field was removed in and has to be after `since`",
+                ));
+            }
+        }
+
+        if attrs.endian.is_some() && attrs.bits.is_some() {
+            return Err(syn::Error::new_spanned(
+                field,
+                "`endian` has no effect on bit fields, which are packed most significant bit first",
+            ));
+        }
+
+        if attrs.with.is_some() && (attrs.write_with.is_some() || attrs.read_with.is_some()) {
+            return Err(syn::Error::new_spanned(
+                field,
+                "`with` already sets how the field is written and read, use it or `write_with` and `read_with`",
+            ));
+        }
+
+        if has_codec && (attrs.encode_as.is_some() || attrs.bits.is_some()) {
+            return Err(syn::Error::new_spanned(
+                field,
+                "`with`, `write_with` and `read_with` can not be combined with `encode_as` or `bits`",
+            ));
+        }
+
+        Ok(attrs)
+    }
+
+    /// Whether the `MByteBufferWrite` derive leaves the field out.
+    pub fn skips_write(&self) -> bool {
+        self.skip || self.skip_write
+    }
+
+    /// Whether the `MByteBufferRead` derives leave the field out.
+    pub fn skips_read(&self) -> bool {
+        self.skip || self.skip_read
+    }
+
+    /// Whether the field ends a run of bit fields before it. Every field but bit fields and
+    /// `skip` fields does, on the write and the read side alike, even when the version or
+    /// `skip_write` leave it out, so both sides pack the same runs.
+    pub fn ends_bit_run(&self) -> bool {
+        self.bits.is_none() && !self.skip
+    }
+
+    /// Whether the field is only in some versions of the struct.
+    pub fn is_versioned(&self) -> bool {
+        self.since.is_some() || self.until.is_some()
+    }
+
+    /// Checks the field is only versioned within a struct with a `version`, and whether it is in
+    /// the version that gets written.
+    pub fn in_current_version(
+        &self,
+        field: &syn::Field,
+        version: Option<u32>,
+    ) -> syn::Result<bool> {
+        let Some(version) = version else {
+            return match self.is_versioned() {
+                true => Err(syn::Error::new_spanned(
+                    field,
+                    "`since` and `until` need a `#[mbytey(version = N)]` on the struct",
+                )),
+                false => Ok(true),
+            };
+        };
 
-    for meta_item in field.attrs.iter().flat_map(get_bytey_meta_items) {
-        match meta_item {
-            Meta::Path(word) if word == SKIP => ret = true,
-            Meta::Path(_) => {
-                panic!("Unexpected field attribute found in bytey. Only skip is supported")
+        if self.since.is_some_and(|since| since > version) {
+            return Err(syn::Error::new_spanned(
+                field,
+                format!("`since` is after the current version {}", version),
+            ));
+        }
+
+        Ok(self.until.is_none_or(|until| version < until))
+    }
+
+    /// The condition for the field to be read, on `__version` if it is versioned and on the
+    /// fields before it if it has an `if`.
+    pub fn read_condition(&self) -> Option<proc_macro2::TokenStream> {
+        let since = self
+            .since
+            .map(|since| quote::quote! { __version >= #since });
+        let until = self.until.map(|until| quote::quote! { __version < #until });
+        let condition = self
+            .condition
+            .as_ref()
+            .map(|condition| quote::quote! { (#condition) });
+        let conditions: Vec<_> = [since, until, condition].into_iter().flatten().collect();
+
+        (!conditions.is_empty()).then(|| quote::quote! { #(#conditions)&&* })
+    }
+
+    /// The value a field that is not read is set to.
+    pub fn default_value(&self) -> proc_macro2::TokenStream {
+        match &self.default {
+            Some(expr) => quote::quote! { #expr },
+            None => quote::quote! { ::core::default::Default::default() },
+        }
+    }
+
+    /// The function writing the field, if it is not written with `MByteBufferWrite`.
+    pub fn write_fn(&self) -> Option<proc_macro2::TokenStream> {
+        match (&self.write_with, &self.with) {
+            (Some(path), _) => Some(quote::quote! { #path }),
+            (None, Some(module)) => Some(quote::quote! { #module::write }),
+            (None, None) => None,
+        }
+    }
+
+    /// The function reading the field, if it is not read with `MByteBufferRead`, or with
+    /// `MByteBufferReadRef` when `by_ref` is set.
+    pub fn read_fn(&self, by_ref: bool) -> Option<proc_macro2::TokenStream> {
+        match (&self.read_with, &self.with) {
+            (Some(path), _) => Some(quote::quote! { #path }),
+            (None, Some(module)) if by_ref => Some(quote::quote! { #module::read_ref }),
+            (None, Some(module)) => Some(quote::quote! { #module::read }),
+            (None, None) => None,
+        }
+    }
+
+    /// The type the field is written and read as.
+    pub fn encoded_type<'a>(&'a self, field: &'a syn::Field) -> &'a syn::Type {
+        self.encode_as.as_ref().unwrap_or(&field.ty)
+    }
+
+    /// The value that gets written for a field, given an expression of a reference to it.
+    pub fn encoded_value(
+        &self,
+        field: &syn::Field,
+        value: proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        let field_ty = &field.ty;
+
+        match &self.encode_as {
+            Some(ty) => quote::quote! {
+                <#ty as ::core::convert::From<#field_ty>>::from(::core::clone::Clone::clone(#value))
+            },
+            None => quote::quote! { (#value) },
+        }
+    }
+
+    /// Like [`encoded_value`](Self::encoded_value), but always a reference.
+    pub fn encoded_ref(
+        &self,
+        field: &syn::Field,
+        value: proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        match &self.encode_as {
+            Some(_) => {
+                let value = self.encoded_value(field, value);
+
+                quote::quote! { &#value }
             }
-            _ => {}
+            None => quote::quote! { (#value) },
         }
     }
+}
 
-    ret
+/// The `#[mbytey(...)]` attributes of a struct or enum.
+#[derive(Default)]
+pub struct ContainerAttrs {
+    /// The type enum variant ids are written as, from `tag = "u8"`.
+    pub tag: Option<TagType>,
+    /// Whether enum variants prefix their fields with their length, from `length_delimited`.
+    pub length_delimited: bool,
+    /// The function called with the value after it is read, from `post_read = "path"`.
+    pub post_read: Option<syn::Path>,
+    /// The byte order of every field and of enum tags, from `endian = "big"`.
+    pub endian: Option<Endian>,
+    /// The version written before the fields of a struct, from `version = N`.
+    pub version: Option<u32>,
+    /// The bytes a struct starts with, from `magic = b"..."`.
+    pub magic: Option<syn::LitByteStr>,
+    /// The bounds replacing those inferred from the fields, from `bound = "..."`.
+    pub bound: Bound,
 }
 
-fn get_bytey_meta_items(attr: &syn::Attribute) -> Vec<syn::Meta> {
-    if attr.path() != BYTEY {
-        return Vec::new();
+impl ContainerAttrs {
+    pub fn from_input(input: &syn::DeriveInput) -> syn::Result<ContainerAttrs> {
+        let mut attrs = ContainerAttrs::default();
+
+        for attr in &input.attrs {
+            for meta_item in get_bytey_meta_items(attr)? {
+                match &meta_item {
+                    Meta::NameValue(pair) if pair.path == TAG => {
+                        attrs.tag = Some(TagType::from_expr(&pair.value)?);
+                    }
+                    Meta::Path(word) if word == LENGTH_DELIMITED => attrs.length_delimited = true,
+                    Meta::NameValue(pair) if pair.path == ENDIAN => {
+                        attrs.endian = Some(Endian::from_expr(&pair.value)?);
+                    }
+                    Meta::NameValue(pair) if pair.path == VERSION => {
+                        let lit = int_lit(&pair.value, "expected an integer, as in `version = 2`")?;
+
+                        attrs.version = Some(lit.base10_parse::<u32>()?);
+                    }
+                    Meta::NameValue(pair) if pair.path == MAGIC => {
+                        let syn::Expr::Lit(syn::ExprLit {
+                            lit: syn::Lit::ByteStr(lit),
+                            ..
+                        }) = &pair.value
+                        else {
+                            return Err(syn::Error::new_spanned(
+                                &pair.value,
+                                "expected a byte string, as in `magic = b\"MBTY\"`",
+                            ));
+                        };
+
+                        attrs.magic = Some(lit.clone());
+                    }
+                    meta if meta.path() == BOUND => attrs.bound = Bound::from_meta(meta)?,
+                    Meta::NameValue(pair) if pair.path == POST_READ => {
+                        attrs.post_read = Some(path_lit(&pair.value, "post_read = \"function\"")?);
+                    }
+                    _ => return Err(syn::Error::new_spanned(
+                        meta_item,
+                        "unknown mbytey container attribute, expected `tag`, `length_delimited`, `post_read`, `endian`, `version`, `magic` or `bound`",
+                    )),
+                }
+            }
+        }
+
+        Ok(attrs)
+    }
+}
+
+/// The `#[mbytey(...)]` attributes of an enum variant.
+#[derive(Default)]
+pub struct VariantAttrs {
+    /// The id the variant is written with, from `id = N`.
+    pub id: Option<syn::LitInt>,
+    /// Whether the variant holds every unknown id, from `other`.
+    pub other: bool,
+    /// The byte order of the variant's fields, from `endian = "big"`.
+    pub endian: Option<Endian>,
+}
+
+impl VariantAttrs {
+    pub fn from_variant(variant: &syn::Variant) -> syn::Result<VariantAttrs> {
+        let mut attrs = VariantAttrs::default();
+
+        for attr in &variant.attrs {
+            for meta_item in get_bytey_meta_items(attr)? {
+                match &meta_item {
+                    Meta::NameValue(pair) if pair.path == ID => {
+                        attrs.id = Some(
+                            int_lit(&pair.value, "expected an integer, as in `id = 5`")?.clone(),
+                        );
+                    }
+                    Meta::Path(word) if word == OTHER => attrs.other = true,
+                    Meta::NameValue(pair) if pair.path == ENDIAN => {
+                        attrs.endian = Some(Endian::from_expr(&pair.value)?);
+                    }
+                    _ => {
+                        return Err(syn::Error::new_spanned(
+                            meta_item,
+                            "unknown mbytey variant attribute, expected `id`, `other` or `endian`",
+                        ))
+                    }
+                }
+            }
+        }
+
+        Ok(attrs)
+    }
+}
+
+/// The type the id of an enum variant is written as.
+#[derive(Copy, Clone, Default, PartialEq, Eq)]
+pub enum TagType {
+    U8,
+    #[default]
+    U16,
+    U32,
+    VarInt,
+}
+
+impl TagType {
+    fn from_expr(expr: &syn::Expr) -> syn::Result<TagType> {
+        const EXPECTED: &str = "expected one of \"u8\", \"u16\", \"u32\" or \"varint\"";
+
+        let syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Str(lit),
+            ..
+        }) = expr
+        else {
+            return Err(syn::Error::new_spanned(expr, EXPECTED));
+        };
+
+        match lit.value().as_str() {
+            "u8" => Ok(TagType::U8),
+            "u16" => Ok(TagType::U16),
+            "u32" => Ok(TagType::U32),
+            "varint" => Ok(TagType::VarInt),
+            _ => Err(syn::Error::new_spanned(lit, EXPECTED)),
+        }
+    }
+
+    /// The integer type of the enum's `#[repr(...)]`, along with the tag of the same size if
+    /// there is one.
+    pub fn from_repr(
+        attrs: &[syn::Attribute],
+    ) -> syn::Result<Option<(syn::Ident, Option<TagType>)>> {
+        let mut repr = None;
+
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("repr")) {
+            attr.parse_nested_meta(|meta| {
+                // `align(N)` and `packed(N)` take an argument that is of no interest here.
+                if meta.input.peek(syn::token::Paren) {
+                    let content;
+
+                    syn::parenthesized!(content in meta.input);
+                    content.parse::<proc_macro2::TokenStream>()?;
+                }
+
+                let Some(ident) = meta.path.get_ident() else {
+                    return Ok(());
+                };
+                let tag = match ident.to_string().as_str() {
+                    "u8" => Some(TagType::U8),
+                    "u16" => Some(TagType::U16),
+                    "u32" => Some(TagType::U32),
+                    "u64" | "u128" | "usize" | "i8" | "i16" | "i32" | "i64" | "i128" | "isize" => {
+                        None
+                    }
+                    _ => return Ok(()),
+                };
+
+                repr = Some((ident.clone(), tag));
+                Ok(())
+            })?;
+        }
+
+        Ok(repr)
+    }
+
+    /// The type the tag is read as.
+    pub fn ty(self) -> proc_macro2::TokenStream {
+        match self {
+            TagType::U8 => quote::quote! { u8 },
+            TagType::U16 => quote::quote! { u16 },
+            TagType::U32 => quote::quote! { u32 },
+            TagType::VarInt => quote::quote! { u64 },
+        }
+    }
+
+    /// The largest id the tag can hold.
+    pub fn max(self) -> u64 {
+        match self {
+            TagType::U8 => u8::MAX as u64,
+            TagType::U16 => u16::MAX as u64,
+            TagType::U32 => u32::MAX as u64,
+            TagType::VarInt => u64::MAX,
+        }
+    }
+
+    /// The name of the tag in error messages.
+    pub fn name(self) -> &'static str {
+        match self {
+            TagType::U8 => "u8",
+            TagType::U16 => "u16",
+            TagType::U32 => "u32",
+            TagType::VarInt => "varint",
+        }
+    }
+
+    /// A literal of `id` typed like the value the tag is read as, for match patterns.
+    pub fn literal(self, id: u64) -> proc_macro2::Literal {
+        match self {
+            TagType::U8 => proc_macro2::Literal::u8_suffixed(id as u8),
+            TagType::U16 => proc_macro2::Literal::u16_suffixed(id as u16),
+            TagType::U32 => proc_macro2::Literal::u32_suffixed(id as u32),
+            TagType::VarInt => proc_macro2::Literal::u64_suffixed(id),
+        }
+    }
+
+    /// The value written for an id given as an expression of the type the tag is read as.
+    pub fn wrap(self, id: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        match self {
+            TagType::VarInt => quote::quote! { ::mmap_bytey::VarInt(#id) },
+            _ => quote::quote! { (#id) },
+        }
+    }
+
+    /// The number of bytes a written `value` takes up, usable in a constant if `value` is.
+    pub fn len(self, value: &proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        match self {
+            TagType::U8 => quote::quote! { 1usize },
+            TagType::U16 => quote::quote! { 2usize },
+            TagType::U32 => quote::quote! { 4usize },
+            TagType::VarInt => quote::quote! { #value.byte_len() },
+        }
+    }
+
+    /// The number of bytes any id takes up, if it is the same for every id.
+    pub fn fixed_len(self) -> Option<usize> {
+        match self {
+            TagType::U8 => Some(1),
+            TagType::U16 => Some(2),
+            TagType::U32 => Some(4),
+            TagType::VarInt => None,
+        }
+    }
+
+    /// Reads the tag from `buffer` in the byte order `order`.
+    pub fn read(self, order: &proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        match self {
+            TagType::U8 => quote::quote! { buffer.read_with::<#order, u8>()? },
+            TagType::U16 => quote::quote! { buffer.read_with::<#order, u16>()? },
+            TagType::U32 => quote::quote! { buffer.read_with::<#order, u32>()? },
+            TagType::VarInt => {
+                quote::quote! { buffer.read_with::<#order, ::mmap_bytey::VarInt>()?.0 }
+            }
+        }
+    }
+}
+
+/// A byte order set with `endian = "..."`, overriding the one the value is written or read in.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum Endian {
+    Big,
+    Little,
+    Native,
+}
+
+impl Endian {
+    fn from_expr(expr: &syn::Expr) -> syn::Result<Endian> {
+        const EXPECTED: &str = "expected one of \"big\", \"little\" or \"native\"";
+
+        let syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Str(lit),
+            ..
+        }) = expr
+        else {
+            return Err(syn::Error::new_spanned(expr, EXPECTED));
+        };
+
+        match lit.value().as_str() {
+            "big" => Ok(Endian::Big),
+            "little" => Ok(Endian::Little),
+            "native" => Ok(Endian::Native),
+            _ => Err(syn::Error::new_spanned(lit, EXPECTED)),
+        }
     }
+}
+
+/// Where predicates replacing the bounds the derives infer, from `bound = "T: Trait"` for every
+/// derive or `bound(write = "...", read = "...")` for the write or the read derives only.
+#[derive(Default)]
+pub struct Bound {
+    pub write: Option<Vec<syn::WherePredicate>>,
+    pub read: Option<Vec<syn::WherePredicate>>,
+}
+
+impl Bound {
+    fn from_meta(meta: &Meta) -> syn::Result<Bound> {
+        match meta {
+            Meta::NameValue(pair) => {
+                let predicates = where_predicates(&pair.value)?;
 
-    match attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated) {
-        Ok(nested) => nested.into_iter().collect(),
-        Err(err) => {
-            panic!("error #[mbytey(...)]: {} ", err);
+                Ok(Bound {
+                    write: Some(predicates.clone()),
+                    read: Some(predicates),
+                })
+            }
+            Meta::List(list) => {
+                let mut bound = Bound::default();
+                let pairs = list.parse_args_with(
+                    Punctuated::<syn::MetaNameValue, Token![,]>::parse_terminated,
+                )?;
+
+                for pair in pairs {
+                    if pair.path == WRITE {
+                        bound.write = Some(where_predicates(&pair.value)?);
+                    } else if pair.path == READ {
+                        bound.read = Some(where_predicates(&pair.value)?);
+                    } else {
+                        return Err(syn::Error::new_spanned(
+                            pair.path,
+                            "expected `write` or `read`",
+                        ));
+                    }
+                }
+
+                Ok(bound)
+            }
+            Meta::Path(_) => Err(syn::Error::new_spanned(
+                meta,
+                "expected where predicates, as in `bound = \"T: MByteBufferWrite\"`",
+            )),
         }
     }
 }
+
+fn where_predicates(expr: &syn::Expr) -> syn::Result<Vec<syn::WherePredicate>> {
+    match expr {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Str(lit),
+            ..
+        }) => Ok(lit
+            .parse_with(Punctuated::<syn::WherePredicate, Token![,]>::parse_terminated)?
+            .into_iter()
+            .collect()),
+        _ => Err(syn::Error::new_spanned(
+            expr,
+            "expected a string of where predicates, as in `bound = \"T: MByteBufferWrite\"`",
+        )),
+    }
+}
+
+/// The byte order type to use, `endian` if it is set and `outer` otherwise.
+pub fn byte_order(
+    endian: Option<Endian>,
+    outer: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    match endian {
+        Some(Endian::Big) => quote::quote! { ::mmap_bytey::Big },
+        Some(Endian::Little) => quote::quote! { ::mmap_bytey::Little },
+        Some(Endian::Native) => quote::quote! { ::mmap_bytey::Native },
+        None => outer.clone(),
+    }
+}
+
+fn expr_lit(expr: &syn::Expr, example: &str) -> syn::Result<syn::Expr> {
+    match expr {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Str(lit),
+            ..
+        }) => lit.parse::<syn::Expr>(),
+        _ => Err(syn::Error::new_spanned(
+            expr,
+            format!("expected a string of an expression, as in `{}`", example),
+        )),
+    }
+}
+
+fn path_lit(expr: &syn::Expr, example: &str) -> syn::Result<syn::Path> {
+    match expr {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Str(lit),
+            ..
+        }) => lit.parse::<syn::Path>(),
+        _ => Err(syn::Error::new_spanned(
+            expr,
+            format!("expected a string of a path, as in `{}`", example),
+        )),
+    }
+}
+
+fn int_lit<'a>(expr: &'a syn::Expr, message: &str) -> syn::Result<&'a syn::LitInt> {
+    match expr {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Int(lit),
+            ..
+        }) => Ok(lit),
+        _ => Err(syn::Error::new_spanned(expr, message)),
+    }
+}
+
+fn get_bytey_meta_items(attr: &syn::Attribute) -> syn::Result<Vec<syn::Meta>> {
+    if attr.path() != BYTEY {
+        return Ok(Vec::new());
+    }
+
+    let nested = attr.parse_args_with(|input: syn::parse::ParseStream| {
+        Punctuated::<Meta, Token![,]>::parse_terminated_with(input, parse_meta)
+    })?;
+
+    Ok(nested.into_iter().collect())
+}
+
+/// `if` is a keyword, which `Meta` refuses as a path, so it is parsed by hand.
+fn parse_meta(input: syn::parse::ParseStream) -> syn::Result<Meta> {
+    if !input.peek(Token![if]) {
+        return input.parse();
+    }
+
+    let keyword: Token![if] = input.parse()?;
+
+    Ok(Meta::NameValue(syn::MetaNameValue {
+        path: syn::Ident::new("if", keyword.span).into(),
+        eq_token: input.parse()?,
+        value: input.parse()?,
+    }))
+}
@@ -1,7 +1,8 @@
-#![feature(proc_macro_diagnostic)]
-
+mod bound;
 mod byte_buffer_read;
+mod byte_buffer_read_ref;
 mod byte_buffer_write;
+mod field_refs;
 mod field_wrapper;
 mod source;
 mod symbols;
@@ -9,16 +10,253 @@ mod symbols;
 use proc_macro::TokenStream;
 use syn::parse_macro_input;
 
+/// Derives `MByteBufferWrite` for a struct or enum.
+///
+/// Fields are written in order and enum variants after their id. A variant's id is set with
+/// `#[mbytey(id = N)]` or its explicit discriminant, and otherwise is one more than the id of the
+/// variant before it, starting at `1`, or at `0` if any variant has a discriminant. Ids are
+/// written as a `u16` unless the enum has `#[mbytey(tag = "u8" | "u16" | "u32" | "varint")]`, or
+/// has explicit discriminants and a `#[repr(u8 | u16 | u32)]`, whose type they are written as.
+/// Discriminants that are not literals, such as `-1` or `1 << 3`, are evaluated by the compiler,
+/// which fails if one does not fit in the tag. Negative ones are written as their two's
+/// complement.
+///
+/// An `endian` attribute on the struct or enum sets the byte order of every field and of the
+/// variant ids, and one on a variant sets it for the fields of that variant. Fields still
+/// override both.
+///
+/// A struct with `#[mbytey(magic = b"...")]` starts with those bytes, and reading it fails with
+/// `MagicMismatch` if the buffer holds different ones.
+///
+/// A variant marked `#[mbytey(other)]` is read for any unknown id, which it keeps in its one
+/// field, typed like the tag (`u64` for `varint`), and writes back. With
+/// `#[mbytey(length_delimited)]` on the enum every variant's fields are prefixed with their
+/// length as a `usize`, so fields added to a variant later are skipped by older readers, and the
+/// `other` variant can keep the payload of an unknown variant in a second `Vec<u8>` field.
+///
+/// Fields take these `#[mbytey(...)]` attributes:
+/// - `skip` leaves the field out. `skip_write` leaves it out of this derive only, for types whose
+///   reader expects a different layout.
+/// - `encode_as = "Type"` converts the field with `From` and writes it as `Type`.
+/// - `bits = N` packs the field into `N` bits along with the bit fields next to it.
+/// - `with = "module"` writes the field with `module::write` and reads it with `module::read`, or
+///   `module::read_ref` when deriving `MByteBufferReadRef`. They have the signatures
+///   `fn write<O: ByteOrder>(&T, &mut MByteBuffer) -> Result<()>` and
+///   `fn read<O: ByteOrder>(&mut MByteBuffer) -> Result<T>`, with `&mut MByteBufferReader` for
+///   `read_ref`.
+/// - `write_with = "function"` and `read_with = "function"` set one of those functions on its own.
+/// - `endian = "big" | "little" | "native"` writes the field in that byte order, whatever the
+///   one passed to the buffer is.
+/// - `since = N` and `until = N` limit the field to the versions from `since` up to, but not
+///   including, `until` of a struct with `#[mbytey(version = N)]`. Such a struct is written
+///   with its version as a `u32` first and only the fields of that version. Reading accepts any
+///   older version and sets the fields it lacks to their default, or the `default = "expr"` of
+///   the field.
+/// - `if = "expr"` only writes the field when the expression is true.
+/// - `count = "expr"` writes a collection without its length prefix, and fails with
+///   `CountMismatch` if its length is not the value of the expression.
+/// - `pad = N` writes `N` zero bytes before the field, which reading skips.
+/// - `align = N` writes zero bytes before the field until the buffer's cursor is a multiple of
+///   `N`, which reading skips the same way. As their number depends on where the value is
+///   written, `encoded_len` counts them for a value at position 0 and `encoded_len_at` for one
+///   at any other position.
+///
+/// The expressions of `if` and `count` can use the fields before theirs, as `self.field` or
+/// `field`, and as `self.0` in tuple structs and variants, since the reader evaluates them with
+/// the values it has decoded so far. A field that is not read keeps its default, so fields with
+/// `skip`, `skip_read`, `skip_write`, `since` or `until` can not be used, as the reader could see
+/// their default where the writer sees their value.
+///
+/// The encoded length of a field written by a function is measured by writing it to
+/// `MByteBuffer::counter()`, which counts the bytes without storing them.
+///
+/// Type parameters used by the fields get the bounds the impl needs, such as `T: MByteBufferWrite`
+/// for a field of type `Vec<T>`, while `PhantomData<T>` and skipped fields need nothing. A
+/// `#[mbytey(bound = "T: Trait")]` on the struct or enum replaces all of them and one on a field
+/// those of the field, for fields with `encode_as` or their own functions, which get no bounds.
+/// `bound(write = "...", read = "...")` sets them for the write or the read derives only.
+///
+/// Invalid attributes are reported as compile errors pointing at the attribute.
+///
+/// ```compile_fail
+/// use mmap_bytey::MByteBufferWrite;
+///
+/// #[derive(MByteBufferWrite)]
+/// struct Packet {
+///     #[mbytey(skipp)]
+///     id: u32,
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use mmap_bytey::MByteBufferWrite;
+///
+/// #[derive(MByteBufferWrite)]
+/// struct Packet {
+///     #[mbytey(bits = 65)]
+///     id: u64,
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use mmap_bytey::MByteBufferWrite;
+///
+/// #[derive(MByteBufferWrite)]
+/// struct Packet {
+///     #[mbytey(with = "codec", bits = 4)]
+///     id: u8,
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use mmap_bytey::MByteBufferWrite;
+///
+/// #[derive(MByteBufferWrite)]
+/// enum Packet {
+///     Ping = 3,
+///     #[mbytey(id = 3)]
+///     Pong,
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use mmap_bytey::MByteBufferWrite;
+///
+/// #[derive(MByteBufferWrite)]
+/// enum Packet {
+///     Ping,
+///     #[mbytey(other)]
+///     Unknown(u16, Vec<u8>),
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use mmap_bytey::MByteBufferWrite;
+///
+/// #[derive(MByteBufferWrite)]
+/// struct Packet {
+///     #[mbytey(align = 4, bits = 3)]
+///     kind: u8,
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use mmap_bytey::MByteBufferWrite;
+///
+/// #[derive(MByteBufferWrite)]
+/// #[mbytey(tag = "u8")]
+/// enum Packet {
+///     #[mbytey(id = 256)]
+///     Ping,
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use mmap_bytey::MByteBufferWrite;
+///
+/// #[derive(MByteBufferWrite)]
+/// #[mbytey(tag = "u8")]
+/// #[repr(i32)]
+/// enum Packet {
+///     Ping = 1 << 8,
+/// }
+/// ```
 #[proc_macro_derive(MByteBufferWrite, attributes(mbytey))]
 pub fn derive_byte_buffer_write(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as syn::DeriveInput);
 
-    byte_buffer_write::expand_derive_byte_buffer_write(&input).into()
+    byte_buffer_write::expand_derive_byte_buffer_write(&input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
 }
 
+/// Derives `MByteBufferRead` for a struct or enum, reading what
+/// [`MByteBufferWrite`](derive@MByteBufferWrite) writes.
+///
+/// Fields marked `skip` or `skip_read` are not read and set to their `Default`, or to the
+/// expression of `#[mbytey(default = "expr")]`, and their type parameters are bound by `Default`
+/// instead. A `#[mbytey(post_read = "function")]` on the
+/// struct or enum calls `fn(&mut Self) -> Result<()>` with the value once it is read, to rebuild
+/// state that is not written or to reject the value with an error.
+///
+/// ```compile_fail
+/// use mmap_bytey::MByteBufferRead;
+///
+/// #[derive(MByteBufferRead)]
+/// struct Packet {
+///     #[mbytey(encode_as = F16)]
+///     speed: f32,
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use mmap_bytey::MByteBufferRead;
+///
+/// #[derive(MByteBufferRead)]
+/// struct Packet {
+///     #[mbytey(since = 2)]
+///     id: u32,
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use mmap_bytey::MByteBufferRead;
+///
+/// #[derive(MByteBufferRead)]
+/// struct Packet {
+///     #[mbytey(count = "self.len")]
+///     items: Vec<u8>,
+///     len: u8,
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use mmap_bytey::MByteBufferRead;
+///
+/// #[derive(MByteBufferRead)]
+/// struct Packet {
+///     #[mbytey(skip_read)]
+///     has_id: bool,
+///     #[mbytey(if = "has_id")]
+///     id: u32,
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use mmap_bytey::MByteBufferRead;
+///
+/// #[derive(MByteBufferRead)]
+/// struct Packet {
+///     #[mbytey(default = "5")]
+///     id: u32,
+/// }
+/// ```
 #[proc_macro_derive(MByteBufferRead, attributes(mbytey))]
 pub fn derive_byte_buffer_read(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as syn::DeriveInput);
 
-    byte_buffer_read::expand_derive_byte_buffer_read(&input).into()
+    byte_buffer_read::expand_derive_byte_buffer_read(&input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+/// Derives `MByteBufferReadRef` for a struct or enum whose fields may borrow from the buffer. It
+/// takes the same attributes as [`MByteBufferRead`](derive@MByteBufferRead), and the payload of an
+/// `other` variant can be a `&[u8]` borrowed from the buffer as well.
+///
+/// ```compile_fail
+/// use mmap_bytey::MByteBufferReadRef;
+///
+/// #[derive(MByteBufferReadRef)]
+/// union Packet<'a> {
+///     name: &'a str,
+/// }
+/// ```
+#[proc_macro_derive(MByteBufferReadRef, attributes(mbytey))]
+pub fn derive_byte_buffer_read_ref(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as syn::DeriveInput);
+
+    byte_buffer_read_ref::expand_derive_byte_buffer_read_ref(&input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
 }
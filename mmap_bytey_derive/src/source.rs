@@ -1,22 +1,81 @@
+use crate::field_wrapper::{Bound, ContainerAttrs, Endian, TagType, VariantAttrs};
+use quote::quote;
+use std::collections::HashMap;
+
 pub struct StructSource<'a> {
     pub ident: &'a syn::Ident,
     pub fields: &'a syn::Fields,
     pub generics: &'a syn::Generics,
+    pub post_read: Option<syn::Path>,
+    pub endian: Option<Endian>,
+    pub version: Option<u32>,
+    pub magic: Option<syn::LitByteStr>,
+    pub bound: Bound,
 }
 
 pub struct EnumSource<'a> {
     pub ident: &'a syn::Ident,
     pub variants: Vec<EnumVariant<'a>>,
     pub generics: &'a syn::Generics,
+    pub tag: TagType,
+    /// The type the discriminants are evaluated as, the integer repr of the enum or `isize`.
+    pub discriminant_type: syn::Ident,
+    pub length_delimited: bool,
+    pub other: Option<OtherVariant<'a>>,
+    pub post_read: Option<syn::Path>,
+    pub endian: Option<Endian>,
+    pub bound: Bound,
 }
 
 pub struct EnumVariant<'a> {
     pub ident: &'a syn::Ident,
     pub fields: &'a syn::Fields,
+    pub id: VariantId<'a>,
+    pub endian: Option<Endian>,
+}
+
+/// The id of an enum variant.
+#[derive(Clone)]
+pub enum VariantId<'a> {
+    /// An id known while expanding the derive.
+    Value(u64),
+    /// An id `offset` past a discriminant that is not a literal, only known once compiled.
+    Discriminant { expr: &'a syn::Expr, offset: u64 },
+}
+
+impl<'a> VariantId<'a> {
+    fn from_discriminant(discriminant: &'a syn::Expr) -> syn::Result<VariantId<'a>> {
+        match discriminant {
+            syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Int(lit),
+                ..
+            }) => Ok(VariantId::Value(lit.base10_parse::<u64>()?)),
+            expr => Ok(VariantId::Discriminant { expr, offset: 0 }),
+        }
+    }
+
+    /// The id of the variant after this one if it has none of its own.
+    fn next(&self) -> Option<VariantId<'a>> {
+        match self {
+            VariantId::Value(value) => value.checked_add(1).map(VariantId::Value),
+            VariantId::Discriminant { expr, offset } => Some(VariantId::Discriminant {
+                expr,
+                offset: offset.checked_add(1)?,
+            }),
+        }
+    }
+}
+
+/// The `#[mbytey(other)]` variant, holding the tag of an unknown variant and, in a
+/// `length_delimited` enum, optionally its payload.
+pub struct OtherVariant<'a> {
+    pub ident: &'a syn::Ident,
+    pub tag: syn::Member,
+    pub payload: Option<syn::Member>,
 }
 
 impl StructSource<'_> {
-    pub fn from_input(input: &syn::DeriveInput) -> StructSource {
+    pub fn from_input(input: &syn::DeriveInput) -> syn::Result<StructSource<'_>> {
         let ident = &input.ident;
         let fields = match &input.data {
             syn::Data::Struct(data) => &data.fields,
@@ -24,36 +83,282 @@ impl StructSource<'_> {
         };
         let generics = &input.generics;
 
-        StructSource {
+        let attrs = ContainerAttrs::from_input(input)?;
+
+        if attrs.tag.is_some() || attrs.length_delimited {
+            return Err(syn::Error::new(
+                ident.span(),
+                "`tag` and `length_delimited` can only be used on enums",
+            ));
+        }
+
+        Ok(StructSource {
             ident,
             fields,
             generics,
-        }
+            post_read: attrs.post_read,
+            endian: attrs.endian,
+            version: attrs.version,
+            magic: attrs.magic,
+            bound: attrs.bound,
+        })
     }
 }
 
 impl EnumSource<'_> {
-    pub fn from_input(input: &syn::DeriveInput) -> EnumSource {
+    /// Reads the enum and the id of each variant.
+    ///
+    /// A variant's id is its `id = N` attribute, else its explicit discriminant, else one more
+    /// than the id of the variant before it. The first variant defaults to `0` like in Rust if
+    /// any variant has an explicit discriminant, and to `1` otherwise.
+    ///
+    /// The tag is the one set with `tag`, else the `u8`, `u16` or `u32` repr of an enum whose
+    /// discriminants are its ids, else a `u16`, so enums numbered from `1` keep the tag they
+    /// always had whatever their repr.
+    pub fn from_input(input: &syn::DeriveInput) -> syn::Result<EnumSource<'_>> {
         let ident = &input.ident;
         let mut vars: Vec<EnumVariant> = Vec::new();
         let generics = &input.generics;
+        let container_attrs = ContainerAttrs::from_input(input)?;
+
+        if container_attrs.version.is_some() || container_attrs.magic.is_some() {
+            return Err(syn::Error::new(
+                ident.span(),
+                "`version` and `magic` can only be used on structs",
+            ));
+        }
+
+        let length_delimited = container_attrs.length_delimited;
+        let mut other: Option<OtherVariant> = None;
 
-        match &input.data {
-            syn::Data::Enum(syn::DataEnum { variants, .. }) => {
-                for variant in variants {
-                    let ident = &variant.ident;
-                    let fields = &variant.fields;
+        let syn::Data::Enum(syn::DataEnum { variants, .. }) = &input.data else {
+            panic!("EnumSource::from_input called on a non-enum");
+        };
 
-                    vars.push(EnumVariant { ident, fields });
+        let has_discriminants = variants
+            .iter()
+            .any(|variant| variant.discriminant.is_some());
+        let repr = TagType::from_repr(&input.attrs)?;
+        let tag = match (container_attrs.tag, &repr) {
+            (Some(tag), _) => tag,
+            (None, Some((_, Some(repr_tag)))) if has_discriminants => *repr_tag,
+            _ => TagType::default(),
+        };
+        let discriminant_type = match repr {
+            Some((repr, _)) => repr,
+            None => syn::Ident::new("isize", proc_macro2::Span::call_site()),
+        };
+        let mut next_id = Some(VariantId::Value(if has_discriminants { 0 } else { 1 }));
+        let mut seen: HashMap<u64, &syn::Ident> = HashMap::new();
+
+        for variant in variants {
+            let ident = &variant.ident;
+            let fields = &variant.fields;
+            let attrs = VariantAttrs::from_variant(variant)?;
+
+            if attrs.other {
+                if other.is_some() {
+                    return Err(syn::Error::new(
+                        ident.span(),
+                        "only one variant can be marked `other`",
+                    ));
                 }
+
+                other = Some(OtherVariant::from_variant(
+                    variant,
+                    &attrs,
+                    length_delimited,
+                )?);
+                continue;
             }
-            _ => panic!("StructSource::from_input called on a non-struct"),
+
+            let id = match (&attrs.id, &variant.discriminant) {
+                (Some(lit), _) => VariantId::Value(lit.base10_parse::<u64>()?),
+                (None, Some((_, discriminant))) => VariantId::from_discriminant(discriminant)?,
+                (None, None) => next_id.ok_or_else(|| {
+                    syn::Error::new(ident.span(), "the id of this variant overflows a u64")
+                })?,
+            };
+
+            // Ids from other discriminants are checked by the compiler, see `id_check`.
+            if let VariantId::Value(id) = id {
+                if id > tag.max() {
+                    return Err(syn::Error::new(
+                        ident.span(),
+                        format!("the id {} does not fit in a {} tag", id, tag.name()),
+                    ));
+                }
+
+                if let Some(previous) = seen.insert(id, ident) {
+                    return Err(syn::Error::new(
+                        ident.span(),
+                        format!("the id {} is already used by `{}`", id, previous),
+                    ));
+                }
+            }
+
+            next_id = id.next();
+            vars.push(EnumVariant {
+                ident,
+                fields,
+                id,
+                endian: attrs.endian,
+            });
         }
 
-        EnumSource {
+        Ok(EnumSource {
             ident,
             variants: vars,
             generics,
+            tag,
+            discriminant_type,
+            length_delimited,
+            other,
+            post_read: container_attrs.post_read,
+            endian: container_attrs.endian,
+            bound: container_attrs.bound,
+        })
+    }
+
+    /// The id of `variant` as a value of the type the tag is read as. An id from a discriminant
+    /// that is not a literal is evaluated by the compiler, which fails if it does not fit in the
+    /// tag. Negative ones are written as their two's complement.
+    pub fn id(&self, variant: &EnumVariant) -> proc_macro2::TokenStream {
+        match &variant.id {
+            VariantId::Value(id) => {
+                let literal = self.tag.literal(*id);
+
+                quote! { #literal }
+            }
+            VariantId::Discriminant { expr, offset } => {
+                let discriminant_type = &self.discriminant_type;
+                let tag_type = self.tag.ty();
+                let max = self.tag.max();
+                let message = format!(
+                    "the id of `{}::{}` does not fit in a {} tag",
+                    self.ident,
+                    variant.ident,
+                    self.tag.name()
+                );
+
+                quote! {
+                    const {
+                        const __DISCRIMINANT: #discriminant_type = #expr;
+                        let __id = __DISCRIMINANT as i128 + #offset as i128;
+
+                        if __id < -(#max as i128 + 1) / 2 || __id > #max as i128 {
+                            ::core::panic!(#message);
+                        }
+
+                        __id as #tag_type
+                    }
+                }
+            }
+        }
+    }
+
+    /// The pattern matching the tag of `variant`.
+    pub fn id_pattern(&self, variant: &EnumVariant) -> proc_macro2::TokenStream {
+        let id = self.id(variant);
+
+        match variant.id {
+            VariantId::Value(_) => id,
+            VariantId::Discriminant { .. } => quote! { __id if __id == #id },
+        }
+    }
+
+    /// An item failing to compile if two variants have the same id, for the ids that are only
+    /// known once compiled.
+    pub fn id_check(&self) -> Option<proc_macro2::TokenStream> {
+        if self
+            .variants
+            .iter()
+            .all(|variant| matches!(variant.id, VariantId::Value(_)))
+        {
+            return None;
         }
+
+        let ids = self.variants.iter().map(|variant| self.id(variant));
+        let tag_type = self.tag.ty();
+        let message = format!("two variants of `{}` have the same id", self.ident);
+
+        Some(quote! {
+            const _: () = {
+                let __ids: &[#tag_type] = &[#(#ids),*];
+                let mut __i = 0;
+
+                while __i < __ids.len() {
+                    let mut __j = __i + 1;
+
+                    while __j < __ids.len() {
+                        if __ids[__i] == __ids[__j] {
+                            ::core::panic!(#message);
+                        }
+
+                        __j += 1;
+                    }
+
+                    __i += 1;
+                }
+            };
+        })
+    }
+}
+
+impl OtherVariant<'_> {
+    fn from_variant<'a>(
+        variant: &'a syn::Variant,
+        attrs: &VariantAttrs,
+        length_delimited: bool,
+    ) -> syn::Result<OtherVariant<'a>> {
+        if let Some(lit) = &attrs.id {
+            return Err(syn::Error::new_spanned(
+                lit,
+                "the `other` variant takes the id that was read and can not have one",
+            ));
+        }
+
+        if attrs.endian.is_some() {
+            return Err(syn::Error::new(
+                variant.ident.span(),
+                "the `other` variant has no fields to set the byte order of",
+            ));
+        }
+
+        if let Some((_, discriminant)) = &variant.discriminant {
+            return Err(syn::Error::new_spanned(
+                discriminant,
+                "the `other` variant takes the id that was read and can not have one",
+            ));
+        }
+
+        let mut members =
+            variant
+                .fields
+                .iter()
+                .enumerate()
+                .map(|(index, field)| match &field.ident {
+                    Some(ident) => syn::Member::Named(ident.clone()),
+                    None => syn::Member::Unnamed(syn::Index::from(index)),
+                });
+        let expected = match length_delimited {
+            true => 1..=2,
+            false => 1..=1,
+        };
+
+        if !expected.contains(&variant.fields.len()) {
+            let message = match length_delimited {
+                true => "the `other` variant needs a field for the tag and can have one for the payload bytes",
+                false => "the `other` variant needs exactly one field, for the tag",
+            };
+
+            return Err(syn::Error::new(variant.ident.span(), message));
+        }
+
+        Ok(OtherVariant {
+            ident: &variant.ident,
+            tag: members.next().unwrap(),
+            payload: members.next(),
+        })
     }
 }
@@ -6,6 +6,31 @@ pub struct Symbol(&'static str);
 
 pub const BYTEY: Symbol = Symbol("mbytey");
 pub const SKIP: Symbol = Symbol("skip");
+pub const SKIP_READ: Symbol = Symbol("skip_read");
+pub const SKIP_WRITE: Symbol = Symbol("skip_write");
+pub const DEFAULT: Symbol = Symbol("default");
+pub const POST_READ: Symbol = Symbol("post_read");
+pub const ENDIAN: Symbol = Symbol("endian");
+pub const VERSION: Symbol = Symbol("version");
+pub const SINCE: Symbol = Symbol("since");
+pub const UNTIL: Symbol = Symbol("until");
+pub const IF: Symbol = Symbol("if");
+pub const COUNT: Symbol = Symbol("count");
+pub const PAD: Symbol = Symbol("pad");
+pub const ALIGN: Symbol = Symbol("align");
+pub const MAGIC: Symbol = Symbol("magic");
+pub const BOUND: Symbol = Symbol("bound");
+pub const WRITE: Symbol = Symbol("write");
+pub const READ: Symbol = Symbol("read");
+pub const ENCODE_AS: Symbol = Symbol("encode_as");
+pub const BITS: Symbol = Symbol("bits");
+pub const WITH: Symbol = Symbol("with");
+pub const WRITE_WITH: Symbol = Symbol("write_with");
+pub const READ_WITH: Symbol = Symbol("read_with");
+pub const TAG: Symbol = Symbol("tag");
+pub const ID: Symbol = Symbol("id");
+pub const OTHER: Symbol = Symbol("other");
+pub const LENGTH_DELIMITED: Symbol = Symbol("length_delimited");
 
 impl PartialEq<Symbol> for Ident {
     fn eq(&self, word: &Symbol) -> bool {
@@ -13,7 +38,7 @@ impl PartialEq<Symbol> for Ident {
     }
 }
 
-impl<'a> PartialEq<Symbol> for &'a Ident {
+impl PartialEq<Symbol> for &Ident {
     fn eq(&self, word: &Symbol) -> bool {
         *self == word.0
     }
@@ -25,7 +50,7 @@ impl PartialEq<Symbol> for Path {
     }
 }
 
-impl<'a> PartialEq<Symbol> for &'a Path {
+impl PartialEq<Symbol> for &Path {
     fn eq(&self, word: &Symbol) -> bool {
         self.is_ident(word.0)
     }
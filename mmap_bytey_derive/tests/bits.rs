@@ -0,0 +1,154 @@
+use mmap_bytey::{
+    BitField, MByteBuffer, MByteBufferError, MByteBufferRead, MByteBufferReadRef, MByteBufferWrite,
+    Result,
+};
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+enum Stance {
+    Standing,
+    Crouching,
+    Prone,
+}
+
+impl BitField for Stance {
+    fn to_bit_field(&self, _bits: u32) -> Result<u64> {
+        Ok(*self as u64)
+    }
+
+    fn from_bit_field(raw: u64, _bits: u32) -> Result<Self> {
+        match raw {
+            0 => Ok(Stance::Standing),
+            1 => Ok(Stance::Crouching),
+            2 => Ok(Stance::Prone),
+            tag => Err(MByteBufferError::InvalidTag {
+                tag,
+                type_name: "Stance",
+            }),
+        }
+    }
+}
+
+#[derive(MByteBufferWrite, MByteBufferRead, PartialEq, Debug)]
+struct Flags {
+    #[mbytey(bits = 1)]
+    alive: bool,
+    #[mbytey(bits = 1)]
+    sprinting: bool,
+    #[mbytey(bits = 2)]
+    stance: Stance,
+    #[mbytey(bits = 3)]
+    weapon: u8,
+    id: u16,
+    #[mbytey(bits = 5)]
+    offset: i8,
+    #[mbytey(bits = 12)]
+    ammo: u16,
+}
+
+#[derive(MByteBufferWrite, MByteBufferRead, PartialEq, Debug)]
+enum Action {
+    Idle,
+    Move(#[mbytey(bits = 4)] u8, #[mbytey(bits = 4)] u8),
+}
+
+#[derive(MByteBufferReadRef, PartialEq, Debug)]
+struct Header<'a> {
+    #[mbytey(bits = 3)]
+    kind: u8,
+    #[mbytey(bits = 5)]
+    flags: u8,
+    name: &'a str,
+}
+
+#[test]
+fn test_bits_struct() {
+    let mut buffer = MByteBuffer::new().unwrap();
+    let flags = Flags {
+        alive: true,
+        sprinting: false,
+        stance: Stance::Prone,
+        weapon: 5,
+        id: 0x1234,
+        offset: -7,
+        ammo: 300,
+    };
+
+    buffer.write_be(&flags).unwrap();
+
+    assert_eq!(buffer.length(), 1 + 2 + 3);
+    assert_eq!(Flags::FIXED_SIZE, Some(6));
+    assert_eq!(flags.encoded_len().unwrap(), 6);
+
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read_slice(3).unwrap(), [0b1010_1010, 0x12, 0x34]);
+
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read_be::<Flags>().unwrap(), flags);
+}
+
+#[test]
+fn test_bits_errors() {
+    let mut buffer = MByteBuffer::new().unwrap();
+    let flags = Flags {
+        alive: true,
+        sprinting: false,
+        stance: Stance::Standing,
+        weapon: 8,
+        id: 0,
+        offset: 0,
+        ammo: 0,
+    };
+
+    assert!(matches!(
+        buffer.write(&flags),
+        Err(MByteBufferError::BitOverflow { value: 8, bits: 3 })
+    ));
+
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer.write_slice(&[0b0011_0000, 0, 0, 0, 0, 0]).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    let error = buffer.read::<Flags>().unwrap_err();
+
+    assert_eq!(error.path(), Some("Flags.stance"));
+    assert!(matches!(
+        error.root_cause(),
+        MByteBufferError::InvalidTag { tag: 3, .. }
+    ));
+}
+
+#[test]
+fn test_bits_enum() {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer.write_be(Action::Move(3, 15)).unwrap();
+    buffer.write_be(Action::Idle).unwrap();
+
+    assert_eq!(buffer.length(), 3 + 2);
+
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read_be::<Action>().unwrap(), Action::Move(3, 15));
+    assert_eq!(buffer.read_be::<Action>().unwrap(), Action::Idle);
+}
+
+#[test]
+fn test_bits_read_ref() {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer.write(0b101_10001u8).unwrap();
+    buffer.write("player").unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(
+        buffer.read_ref::<Header>().unwrap(),
+        Header {
+            kind: 5,
+            flags: 17,
+            name: "player"
+        }
+    );
+}
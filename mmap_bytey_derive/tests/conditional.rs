@@ -0,0 +1,156 @@
+use mmap_bytey::{
+    MByteBuffer, MByteBufferError, MByteBufferRead, MByteBufferReadRef, MByteBufferWrite,
+};
+
+#[derive(MByteBufferWrite, MByteBufferRead, PartialEq, Debug)]
+struct Header {
+    flags: u8,
+    len: u16,
+}
+
+#[derive(MByteBufferWrite, MByteBufferRead, PartialEq, Debug)]
+struct Packet {
+    header: Header,
+    #[mbytey(if = "self.header.flags & 1 != 0")]
+    session: u32,
+    #[mbytey(if = "header.flags & 2 != 0", default = "u16::MAX")]
+    port: u16,
+    #[mbytey(count = "header.len")]
+    items: Vec<u16>,
+}
+
+#[derive(MByteBufferWrite, MByteBufferRead, PartialEq, Debug)]
+enum Record {
+    Names {
+        count: u8,
+        #[mbytey(count = "count")]
+        names: Vec<String>,
+    },
+    Flag(bool, #[mbytey(if = "self.0")] u8),
+}
+
+#[derive(MByteBufferReadRef, PartialEq, Debug)]
+struct Borrowed<'a> {
+    has_name: bool,
+    #[mbytey(if = "has_name")]
+    name: &'a str,
+}
+
+#[test]
+fn test_conditional_fields() {
+    let mut buffer = MByteBuffer::new().unwrap();
+    let packet = Packet {
+        header: Header { flags: 1, len: 2 },
+        session: 9,
+        port: u16::MAX,
+        items: vec![3, 4],
+    };
+
+    buffer.write_be(&packet).unwrap();
+
+    assert_eq!(Packet::FIXED_SIZE, None);
+    assert_eq!(packet.encoded_len().unwrap(), 3 + 4 + 4);
+    assert_eq!(buffer.length(), 3 + 4 + 4);
+
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(
+        buffer.read_slice(11).unwrap(),
+        [1, 0, 2, 0, 0, 0, 9, 0, 3, 0, 4]
+    );
+
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read_be::<Packet>().unwrap(), packet);
+
+    let mut buffer = MByteBuffer::new().unwrap();
+    let packet = Packet {
+        header: Header { flags: 2, len: 0 },
+        session: 0,
+        port: 80,
+        items: Vec::new(),
+    };
+
+    buffer.write(&packet).unwrap();
+
+    assert_eq!(buffer.length(), 3 + 2);
+
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read::<Packet>().unwrap(), packet);
+}
+
+#[test]
+fn test_count_mismatch() {
+    let mut buffer = MByteBuffer::new().unwrap();
+    let packet = Packet {
+        header: Header { flags: 0, len: 3 },
+        session: 0,
+        port: 0,
+        items: vec![1],
+    };
+
+    assert!(matches!(
+        buffer.write(&packet),
+        Err(MByteBufferError::CountMismatch {
+            field: "Packet.items",
+            count: 3,
+            len: 1
+        })
+    ));
+
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer.write_slice(&[0, 2, 0, 7, 0]).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    let error = buffer.read::<Packet>().unwrap_err();
+
+    assert_eq!(error.path(), Some("Packet.items[1]"));
+}
+
+#[test]
+fn test_conditional_enum() {
+    let mut buffer = MByteBuffer::new().unwrap();
+    let names = Record::Names {
+        count: 2,
+        names: vec![String::from("a"), String::from("b")],
+    };
+
+    buffer.write(&names).unwrap();
+    buffer.write(Record::Flag(false, 0)).unwrap();
+    buffer.write(Record::Flag(true, 5)).unwrap();
+
+    assert_eq!(Record::Flag(false, 7).encoded_len().unwrap(), 3);
+
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read::<Record>().unwrap(), names);
+    assert_eq!(buffer.read::<Record>().unwrap(), Record::Flag(false, 0));
+    assert_eq!(buffer.read::<Record>().unwrap(), Record::Flag(true, 5));
+}
+
+#[test]
+fn test_conditional_read_ref() {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer.write(false).unwrap();
+    buffer.write(true).unwrap();
+    buffer.write("name").unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(
+        buffer.read_ref::<Borrowed>().unwrap(),
+        Borrowed {
+            has_name: false,
+            name: ""
+        }
+    );
+    assert_eq!(
+        buffer.read_ref::<Borrowed>().unwrap(),
+        Borrowed {
+            has_name: true,
+            name: "name"
+        }
+    );
+}
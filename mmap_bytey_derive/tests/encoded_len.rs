@@ -0,0 +1,84 @@
+use mmap_bytey::{MByteBuffer, MByteBufferWrite};
+
+#[derive(MByteBufferWrite)]
+struct Position {
+    x: f32,
+    y: f32,
+    z: f32,
+}
+
+#[derive(MByteBufferWrite)]
+struct Player {
+    name: String,
+    position: Position,
+    items: Vec<u16>,
+    #[mbytey(skip)]
+    #[allow(dead_code)]
+    cached: u64,
+}
+
+#[derive(MByteBufferWrite)]
+enum Fixed {
+    A(u32),
+    B { a: u16, b: u16 },
+}
+
+#[derive(MByteBufferWrite)]
+enum Packet {
+    Ping,
+    Move(Position),
+    Login(Player),
+}
+
+fn written_len<T: MByteBufferWrite>(value: T) -> usize {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer.write(value).unwrap();
+    buffer.length()
+}
+
+#[test]
+fn test_fixed_size() {
+    assert_eq!(Position::FIXED_SIZE, Some(12));
+    assert_eq!(Player::FIXED_SIZE, None);
+    assert_eq!(Fixed::FIXED_SIZE, Some(6));
+    assert_eq!(Packet::FIXED_SIZE, None);
+    assert_eq!(<&Position>::FIXED_SIZE, Some(12));
+}
+
+#[test]
+fn test_encoded_len() {
+    let player = Player {
+        name: "player".to_owned(),
+        position: Position {
+            x: 1.0,
+            y: 2.0,
+            z: 3.0,
+        },
+        items: vec![1, 2, 3],
+        cached: 5,
+    };
+
+    assert_eq!(player.encoded_len().unwrap(), written_len(&player));
+    assert_eq!(
+        Packet::Ping.encoded_len().unwrap(),
+        written_len(Packet::Ping)
+    );
+
+    let login = Packet::Login(player);
+
+    assert_eq!(login.encoded_len().unwrap(), written_len(&login));
+    assert_eq!(Fixed::A(1).encoded_len().unwrap(), written_len(Fixed::A(1)));
+    assert_eq!(
+        Fixed::B { a: 1, b: 2 }.encoded_len().unwrap(),
+        written_len(Fixed::B { a: 1, b: 2 })
+    );
+
+    let moved = Packet::Move(Position {
+        x: 0.0,
+        y: 0.0,
+        z: 0.0,
+    });
+
+    assert_eq!(moved.encoded_len().unwrap(), 14);
+}
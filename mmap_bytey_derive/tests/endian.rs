@@ -0,0 +1,106 @@
+use mmap_bytey::{MByteBuffer, MByteBufferRead, MByteBufferReadRef, MByteBufferWrite};
+
+#[derive(MByteBufferWrite, MByteBufferRead, PartialEq, Debug)]
+#[mbytey(endian = "big")]
+struct Header {
+    length: u16,
+    #[mbytey(endian = "little")]
+    sequence: u32,
+}
+
+#[derive(MByteBufferWrite, MByteBufferRead, PartialEq, Debug)]
+struct Packet {
+    header: Header,
+    payload: Payload,
+    checksum: u16,
+}
+
+#[derive(MByteBufferWrite, MByteBufferRead, PartialEq, Debug)]
+#[mbytey(tag = "u16", endian = "big")]
+enum Payload {
+    #[mbytey(endian = "little")]
+    Move {
+        x: i16,
+        y: i16,
+    },
+    Chat(String),
+}
+
+#[derive(MByteBufferReadRef, PartialEq, Debug)]
+struct Frame<'a> {
+    #[mbytey(endian = "big")]
+    kind: u16,
+    name: &'a str,
+}
+
+#[test]
+fn test_endian_struct() {
+    let mut buffer = MByteBuffer::new().unwrap();
+    let header = Header {
+        length: 0x0102,
+        sequence: 0x03040506,
+    };
+
+    buffer.write_le(&header).unwrap();
+    buffer.write_be(&header).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(
+        buffer.read_slice(12).unwrap(),
+        [1, 2, 6, 5, 4, 3, 1, 2, 6, 5, 4, 3]
+    );
+
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read_le::<Header>().unwrap(), header);
+    assert_eq!(buffer.read::<Header>().unwrap(), header);
+}
+
+#[test]
+fn test_endian_mixed() {
+    let mut buffer = MByteBuffer::new().unwrap();
+    let packet = Packet {
+        header: Header {
+            length: 1,
+            sequence: 2,
+        },
+        payload: Payload::Move { x: 3, y: -1 },
+        checksum: 0x0a0b,
+    };
+
+    buffer.write_le(&packet).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(
+        buffer.read_slice(14).unwrap(),
+        [0, 1, 2, 0, 0, 0, 0, 1, 3, 0, 0xff, 0xff, 0x0b, 0x0a]
+    );
+
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read_le::<Packet>().unwrap(), packet);
+
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer.write_le(Payload::Chat(String::from("a"))).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read_slice(3).unwrap(), [0, 2, 0]);
+}
+
+#[test]
+fn test_endian_read_ref() {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer.write_be(7u16).unwrap();
+    buffer.write_le("frame").unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(
+        buffer.read_ref_le::<Frame>().unwrap(),
+        Frame {
+            kind: 7,
+            name: "frame"
+        }
+    );
+}
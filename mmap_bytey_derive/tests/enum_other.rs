@@ -0,0 +1,157 @@
+use mmap_bytey::{MByteBuffer, MByteBufferError, MByteBufferRead, MByteBufferWrite};
+
+#[derive(MByteBufferWrite, MByteBufferRead, PartialEq, Debug)]
+#[mbytey(tag = "u8")]
+enum Command {
+    Ping,
+    Move(u16),
+    #[mbytey(other)]
+    Unknown(u8),
+}
+
+#[derive(MByteBufferWrite, MByteBufferRead, PartialEq, Debug)]
+#[mbytey(tag = "u8", length_delimited)]
+enum MessageV1 {
+    Chat(String),
+    #[mbytey(other)]
+    Unknown {
+        tag: u8,
+        payload: Vec<u8>,
+    },
+}
+
+#[derive(MByteBufferWrite, MByteBufferRead, PartialEq, Debug)]
+#[mbytey(tag = "u8", length_delimited)]
+enum MessageV2 {
+    Chat(String, u32),
+    Trade { item: u32, count: u16 },
+}
+
+#[derive(MByteBufferWrite, MByteBufferRead, PartialEq, Debug)]
+#[mbytey(tag = "varint", length_delimited)]
+enum Skipping {
+    Chat(String),
+    #[mbytey(other)]
+    Unknown(u64),
+}
+
+#[test]
+fn test_other_tag() {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer.write(9u8).unwrap();
+    buffer.write(Command::Unknown(9)).unwrap();
+    buffer.write(Command::Move(3)).unwrap();
+
+    assert_eq!(Command::FIXED_SIZE, None);
+    assert_eq!(Command::Unknown(9).encoded_len().unwrap(), 1);
+
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read::<Command>().unwrap(), Command::Unknown(9));
+    assert_eq!(buffer.read::<Command>().unwrap(), Command::Unknown(9));
+    assert_eq!(buffer.read::<Command>().unwrap(), Command::Move(3));
+}
+
+#[test]
+fn test_length_delimited() {
+    let mut buffer = MByteBuffer::new().unwrap();
+    let message = MessageV2::Chat(String::from("hi"), 7);
+
+    buffer.write_le(&message).unwrap();
+
+    assert_eq!(message.encoded_len().unwrap(), buffer.length());
+    assert_eq!(buffer.length(), 1 + 8 + (8 + 2) + 4);
+
+    buffer.move_cursor(1).unwrap();
+
+    assert_eq!(buffer.read_le::<usize>().unwrap(), 8 + 2 + 4);
+
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read_le::<MessageV2>().unwrap(), message);
+}
+
+#[test]
+fn test_length_delimited_forward_compatible() {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer
+        .write(MessageV2::Chat(String::from("hello"), 42))
+        .unwrap();
+    buffer
+        .write(MessageV2::Trade { item: 5, count: 2 })
+        .unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    // The old reader skips the field appended to `Chat` and keeps the unknown `Trade` as bytes.
+    assert_eq!(
+        buffer.read::<MessageV1>().unwrap(),
+        MessageV1::Chat(String::from("hello"))
+    );
+
+    let unknown = buffer.read::<MessageV1>().unwrap();
+
+    assert_eq!(buffer.cursor(), buffer.length());
+    assert!(matches!(unknown, MessageV1::Unknown { tag: 2, .. }));
+
+    // Written back, the unknown variant is unchanged and readable by the new reader.
+    let mut copy = MByteBuffer::new().unwrap();
+
+    copy.write(&unknown).unwrap();
+    copy.move_cursor(0).unwrap();
+
+    assert_eq!(
+        copy.read::<MessageV2>().unwrap(),
+        MessageV2::Trade { item: 5, count: 2 }
+    );
+}
+
+#[test]
+fn test_length_delimited_skip() {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer
+        .write(MessageV2::Trade { item: 5, count: 2 })
+        .unwrap();
+    buffer.write(Skipping::Chat(String::from("a"))).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read::<Skipping>().unwrap(), Skipping::Unknown(2));
+    assert_eq!(
+        buffer.read::<Skipping>().unwrap(),
+        Skipping::Chat(String::from("a"))
+    );
+}
+
+#[test]
+fn test_length_delimited_errors() {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer.write(1u8).unwrap();
+    buffer.write(2usize).unwrap();
+    buffer.write(String::from("hi")).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    let error = buffer.read::<MessageV1>().unwrap_err();
+
+    assert!(matches!(
+        error,
+        MByteBufferError::PayloadOverrun {
+            expected: 2,
+            read: 10,
+            type_name: "MessageV1::Chat"
+        }
+    ));
+
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer.write(1u8).unwrap();
+    buffer.write(100usize).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert!(matches!(
+        buffer.read::<MessageV1>(),
+        Err(MByteBufferError::ReadOutOfBounds { .. })
+    ));
+}
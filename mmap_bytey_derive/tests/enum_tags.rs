@@ -0,0 +1,198 @@
+use mmap_bytey::{MByteBuffer, MByteBufferError, MByteBufferRead, MByteBufferWrite};
+
+#[derive(MByteBufferWrite, MByteBufferRead, PartialEq, Debug)]
+#[mbytey(tag = "u8")]
+enum Command {
+    Ping,
+    #[mbytey(id = 10)]
+    Move(u16),
+    Stop,
+}
+
+#[derive(MByteBufferWrite, MByteBufferRead, PartialEq, Debug, Clone, Copy)]
+#[repr(u8)]
+enum Kind {
+    Small = 5,
+    Medium,
+    Large = 9,
+}
+
+#[derive(MByteBufferWrite, MByteBufferRead, PartialEq, Debug, Clone, Copy)]
+#[repr(u8)]
+enum Plain {
+    First,
+    Second,
+}
+
+const BASE: i32 = 1 << 3;
+
+#[derive(MByteBufferWrite, MByteBufferRead, PartialEq, Debug, Clone, Copy)]
+#[repr(i32)]
+enum Signed {
+    Below = -1,
+    Shifted = 1 << 4,
+    Next,
+    FromConst = BASE,
+}
+
+#[derive(MByteBufferWrite, MByteBufferRead, PartialEq, Debug)]
+#[mbytey(tag = "varint")]
+enum Event {
+    Join {
+        id: u32,
+    },
+    #[mbytey(id = 300)]
+    Leave,
+}
+
+#[derive(MByteBufferWrite, MByteBufferRead, PartialEq, Debug)]
+#[mbytey(tag = "u32")]
+enum Wide {
+    A,
+    B(u8),
+}
+
+#[test]
+fn test_tag_u8_and_ids() {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer.write_be(Command::Ping).unwrap();
+    buffer.write_be(Command::Move(0x0102)).unwrap();
+    buffer.write_be(Command::Stop).unwrap();
+
+    assert_eq!(Command::FIXED_SIZE, None);
+    assert_eq!(Command::Stop.encoded_len().unwrap(), 1);
+
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read_slice(5).unwrap(), [1, 10, 1, 2, 11]);
+
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read_be::<Command>().unwrap(), Command::Ping);
+    assert_eq!(buffer.read_be::<Command>().unwrap(), Command::Move(0x0102));
+    assert_eq!(buffer.read_be::<Command>().unwrap(), Command::Stop);
+}
+
+#[test]
+fn test_discriminants() {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    for kind in [Kind::Small, Kind::Medium, Kind::Large] {
+        buffer.write(kind).unwrap();
+    }
+
+    // The tag is a `u8` like the enum's repr.
+    assert_eq!(Kind::FIXED_SIZE, Some(1));
+
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read_slice(3).unwrap(), [5, 6, 9]);
+
+    buffer.move_cursor(0).unwrap();
+
+    for kind in [Kind::Small, Kind::Medium, Kind::Large] {
+        assert_eq!(buffer.read::<Kind>().unwrap() as u8, kind as u8);
+    }
+}
+
+#[test]
+fn test_tag_varint() {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer.write(Event::Join { id: 7 }).unwrap();
+    buffer.write(Event::Leave).unwrap();
+
+    assert_eq!(Event::Leave.encoded_len().unwrap(), 2);
+    assert_eq!(buffer.length(), 1 + 4 + 2);
+
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read::<Event>().unwrap(), Event::Join { id: 7 });
+
+    let cursor = buffer.cursor();
+
+    assert_eq!(buffer.read_slice(2).unwrap(), [0xac, 0x02]);
+
+    buffer.move_cursor(cursor).unwrap();
+
+    assert_eq!(buffer.read::<Event>().unwrap(), Event::Leave);
+}
+
+#[test]
+fn test_tag_u32() {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer.write_be(Wide::B(3)).unwrap();
+
+    assert_eq!(Wide::A.encoded_len().unwrap(), 4);
+
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read_slice(5).unwrap(), [0, 0, 0, 2, 3]);
+
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read_be::<Wide>().unwrap(), Wide::B(3));
+}
+
+#[test]
+fn test_unknown_tag() {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer.write(7u8).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert!(matches!(
+        buffer.read::<Command>(),
+        Err(MByteBufferError::InvalidTag {
+            tag: 7,
+            type_name: "Command"
+        })
+    ));
+}
+
+#[test]
+fn test_repr_without_discriminants() {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    // Without discriminants the ids are numbered from 1 as a `u16`, whatever the repr.
+    buffer.write(Plain::Second).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(Plain::FIXED_SIZE, Some(2));
+    assert_eq!(buffer.read_slice(2).unwrap(), 2u16.to_ne_bytes());
+
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read::<Plain>().unwrap(), Plain::Second);
+}
+
+#[test]
+fn test_discriminant_expressions() {
+    let mut buffer = MByteBuffer::new().unwrap();
+    let all = [
+        Signed::Below,
+        Signed::Shifted,
+        Signed::Next,
+        Signed::FromConst,
+    ];
+
+    for value in all {
+        buffer.write_be(value).unwrap();
+    }
+
+    buffer.move_cursor(0).unwrap();
+
+    // A signed repr has no tag of its own and keeps the `u16` one.
+    assert_eq!(
+        buffer.read_slice(8).unwrap(),
+        [0xff, 0xff, 0, 16, 0, 17, 0, 8]
+    );
+
+    buffer.move_cursor(0).unwrap();
+
+    for value in all {
+        assert_eq!(buffer.read_be::<Signed>().unwrap(), value);
+    }
+}
@@ -0,0 +1,78 @@
+use mmap_bytey::{MByteBuffer, MByteBufferError};
+use mmap_bytey_derive::{MByteBufferRead, MByteBufferWrite};
+
+#[derive(MByteBufferWrite, MByteBufferRead, PartialEq, Debug)]
+struct Item {
+    item_id: u32,
+}
+
+#[derive(MByteBufferWrite, MByteBufferRead, PartialEq, Debug)]
+struct Player {
+    name: String,
+    inventory: Vec<Item>,
+}
+
+#[derive(MByteBufferWrite, MByteBufferRead, PartialEq, Debug)]
+struct LoginPacket {
+    version: u16,
+    player: Player,
+}
+
+#[test]
+fn test_struct_field_path() {
+    let mut buffer = MByteBuffer::new().unwrap();
+    let val = LoginPacket {
+        version: 1,
+        player: Player {
+            name: "player".to_owned(),
+            inventory: (0..4).map(|item_id| Item { item_id }).collect(),
+        },
+    };
+
+    buffer.write(&val).unwrap();
+    let length = buffer.length();
+    buffer.truncate(length - 2).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    let err = buffer.read::<LoginPacket>().unwrap_err();
+
+    assert_eq!(err.path(), Some("LoginPacket.player.inventory[3].item_id"));
+    assert!(matches!(
+        err.root_cause(),
+        MByteBufferError::ReadOutOfBounds { .. }
+    ));
+    assert!(err
+        .to_string()
+        .starts_with("LoginPacket.player.inventory[3].item_id: Read out of bounds"));
+}
+
+#[test]
+fn test_enum_field_path() {
+    #[derive(MByteBufferWrite, MByteBufferRead, PartialEq, Debug)]
+    enum Test {
+        Named { a: u16, b: char },
+        Unnamed(u16, char),
+    }
+
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer.write(1u16).unwrap();
+    buffer.write(5u16).unwrap();
+    buffer.write(0xD800u32).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    let err = buffer.read::<Test>().unwrap_err();
+
+    assert_eq!(err.path(), Some("Test::Named.b"));
+    assert!(matches!(err.root_cause(), MByteBufferError::NotAChar));
+
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer.write(2u16).unwrap();
+    buffer.write(5u16).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    let err = buffer.read::<Test>().unwrap_err();
+
+    assert_eq!(err.path(), Some("Test::Unnamed.1"));
+}
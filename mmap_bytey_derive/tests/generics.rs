@@ -0,0 +1,209 @@
+use mmap_bytey::{MByteBuffer, MByteBufferRead, MByteBufferReadRef, MByteBufferWrite};
+use std::marker::PhantomData;
+
+#[derive(MByteBufferWrite, MByteBufferRead, PartialEq, Debug)]
+struct Msg<T> {
+    id: u16,
+    value: T,
+}
+
+#[derive(MByteBufferWrite, MByteBufferRead, PartialEq, Debug)]
+enum Either<A, B> {
+    Left(A),
+    Right { values: Vec<B> },
+}
+
+/// Neither writable, readable nor defaultable.
+#[derive(PartialEq, Debug)]
+struct Opaque;
+
+#[derive(MByteBufferWrite, MByteBufferRead, PartialEq, Debug)]
+struct Tagged<T> {
+    id: u32,
+    marker: PhantomData<T>,
+}
+
+#[derive(MByteBufferWrite, MByteBufferRead, PartialEq, Debug)]
+struct Cached<T, C> {
+    value: T,
+    #[mbytey(skip)]
+    cache: C,
+}
+
+trait Protocol {
+    type Id;
+}
+
+#[derive(PartialEq, Debug)]
+struct V1;
+
+impl Protocol for V1 {
+    type Id = u8;
+}
+
+#[derive(MByteBufferWrite, MByteBufferRead, PartialEq, Debug)]
+struct Packet<P: Protocol> {
+    id: P::Id,
+}
+
+#[derive(MByteBufferWrite, MByteBufferRead, PartialEq, Debug)]
+struct Flags<T> {
+    #[mbytey(bits = 3)]
+    kind: T,
+    #[mbytey(bits = 5)]
+    rest: u8,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct Level(u8);
+
+impl From<u8> for Level {
+    fn from(value: u8) -> Self {
+        Level(value)
+    }
+}
+
+impl From<Level> for u8 {
+    fn from(level: Level) -> Self {
+        level.0
+    }
+}
+
+#[derive(MByteBufferWrite, MByteBufferRead, PartialEq, Debug)]
+struct Narrow<T> {
+    #[mbytey(encode_as = "u8", bound = "T: Clone + From<u8>, u8: From<T>")]
+    value: T,
+}
+
+#[derive(MByteBufferWrite, MByteBufferRead, PartialEq, Debug)]
+#[mbytey(bound(write = "T: MByteBufferWrite", read = "T: MByteBufferRead + Default"))]
+struct Optional<T> {
+    present: bool,
+    #[mbytey(if = "present")]
+    value: T,
+}
+
+#[derive(MByteBufferReadRef, PartialEq, Debug)]
+struct Named<'a, T> {
+    name: &'a str,
+    value: T,
+}
+
+fn round_trip<T: MByteBufferWrite + MByteBufferRead>(value: &T) -> T {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    value.write_to_mbuffer(&mut buffer).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    buffer.read::<T>().unwrap()
+}
+
+#[test]
+fn test_inferred_bounds() {
+    let msg = Msg {
+        id: 1,
+        value: String::from("hello"),
+    };
+
+    assert_eq!(round_trip(&msg), msg);
+    assert_eq!(Msg::<u32>::FIXED_SIZE, Some(6));
+
+    let left: Either<u8, String> = Either::Left(3);
+    let right: Either<u8, String> = Either::Right {
+        values: vec![String::from("a")],
+    };
+
+    assert_eq!(round_trip(&left), left);
+    assert_eq!(round_trip(&right), right);
+}
+
+#[test]
+fn test_unused_parameters() {
+    let tagged: Tagged<Opaque> = Tagged {
+        id: 5,
+        marker: PhantomData,
+    };
+
+    assert_eq!(round_trip(&tagged), tagged);
+
+    let mut buffer = MByteBuffer::new().unwrap();
+    let cached = Cached {
+        value: 7u8,
+        cache: vec![1u32],
+    };
+
+    buffer.write(&cached).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(
+        buffer.read::<Cached<u8, Vec<u32>>>().unwrap(),
+        Cached {
+            value: 7,
+            cache: Vec::new()
+        }
+    );
+
+    // Writing needs nothing of a skipped field.
+    buffer
+        .write(Cached {
+            value: 1u8,
+            cache: Opaque,
+        })
+        .unwrap();
+}
+
+#[test]
+fn test_associated_types() {
+    let packet: Packet<V1> = Packet { id: 4 };
+
+    assert_eq!(round_trip(&packet), packet);
+}
+
+#[test]
+fn test_bit_field_bounds() {
+    let flags = Flags { kind: 5u8, rest: 9 };
+
+    assert_eq!(round_trip(&flags), flags);
+}
+
+#[test]
+fn test_bound_override() {
+    let narrow = Narrow { value: Level(200) };
+
+    assert_eq!(round_trip(&narrow), narrow);
+
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer
+        .write(Optional {
+            present: false,
+            value: 9u32,
+        })
+        .unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(
+        buffer.read::<Optional<u32>>().unwrap(),
+        Optional {
+            present: false,
+            value: 0
+        }
+    );
+}
+
+#[test]
+fn test_read_ref_bounds() {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer.write("name").unwrap();
+    buffer.write(3u16).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(
+        buffer.read_ref::<Named<u16>>().unwrap(),
+        Named {
+            name: "name",
+            value: 3
+        }
+    );
+}
@@ -0,0 +1,229 @@
+use mmap_bytey::{
+    MByteBuffer, MByteBufferError, MByteBufferRead, MByteBufferReadRef, MByteBufferWrite,
+};
+
+#[derive(MByteBufferWrite, MByteBufferRead, PartialEq, Debug)]
+#[mbytey(magic = b"MBTY")]
+struct Header {
+    kind: u8,
+    #[mbytey(pad = 3)]
+    size: u32,
+    #[mbytey(align = 8)]
+    offset: u16,
+}
+
+#[derive(MByteBufferWrite, MByteBufferRead, PartialEq, Debug)]
+enum Chunk {
+    Data(u8, #[mbytey(align = 4)] u32),
+    Flags {
+        has_extra: bool,
+        #[mbytey(if = "has_extra", pad = 1, align = 4)]
+        extra: u8,
+    },
+}
+
+#[derive(MByteBufferWrite, MByteBufferRead, PartialEq, Debug)]
+struct Record {
+    kind: u8,
+    chunk: Option<Box<Chunk>>,
+}
+
+#[derive(MByteBufferWrite, MByteBufferRead, PartialEq, Debug)]
+struct Aligned {
+    x: u8,
+    #[mbytey(align = 8)]
+    y: u8,
+}
+
+#[derive(MByteBufferReadRef, PartialEq, Debug)]
+#[mbytey(magic = b"\x7fELF")]
+struct Borrowed<'a> {
+    #[mbytey(pad = 2)]
+    name: &'a str,
+}
+
+#[test]
+fn test_magic_pad_align() {
+    let mut buffer = MByteBuffer::new().unwrap();
+    let header = Header {
+        kind: 1,
+        size: 2,
+        offset: 3,
+    };
+
+    buffer.write_be(&header).unwrap();
+
+    assert_eq!(Header::FIXED_SIZE, None);
+    assert_eq!(header.encoded_len().unwrap(), 18);
+    assert_eq!(buffer.length(), 18);
+
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(
+        buffer.read_slice(18).unwrap(),
+        [b'M', b'B', b'T', b'Y', 1, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 3]
+    );
+
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read_be::<Header>().unwrap(), header);
+}
+
+#[test]
+fn test_align_follows_buffer_position() {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer.write(Chunk::Data(1, 2)).unwrap();
+    buffer.write(0u8).unwrap();
+
+    assert_eq!(Chunk::Data(3, 4).encoded_len().unwrap(), 8);
+    assert_eq!(
+        Chunk::Data(3, 4).encoded_len_at(buffer.cursor()).unwrap(),
+        7
+    );
+
+    buffer.write(Chunk::Data(3, 4)).unwrap();
+
+    // The `u8` of the first chunk ends at 3 and is padded to 4, the one of the second ends at 12.
+    assert_eq!(buffer.length(), 8 + 1 + 7);
+
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read::<Chunk>().unwrap(), Chunk::Data(1, 2));
+    assert_eq!(buffer.read::<u8>().unwrap(), 0);
+    assert_eq!(buffer.read::<Chunk>().unwrap(), Chunk::Data(3, 4));
+}
+
+#[test]
+fn test_encoded_len_at_unaligned_offset() {
+    let mut buffer = MByteBuffer::new().unwrap();
+    let record = Record {
+        kind: 1,
+        chunk: Some(Box::new(Chunk::Data(2, 3))),
+    };
+
+    for offset in 0..8 {
+        buffer.truncate(0).unwrap();
+        buffer.write_zeros(offset).unwrap();
+
+        let len = record.encoded_len_at(buffer.cursor()).unwrap();
+
+        buffer.write(&record).unwrap();
+
+        assert_eq!(len, buffer.length() - offset, "offset {offset}");
+    }
+
+    assert_eq!(
+        record.encoded_len().unwrap(),
+        record.encoded_len_at(0).unwrap()
+    );
+}
+
+#[test]
+fn test_encoded_len_of_aligned_elements() {
+    let mut buffer = MByteBuffer::new().unwrap();
+    let items = vec![Aligned { x: 1, y: 2 }, Aligned { x: 3, y: 4 }];
+
+    buffer.write(&items).unwrap();
+
+    // The second element starts one byte past an 8 byte boundary, so it needs one byte less padding.
+    assert_eq!(buffer.length(), 25);
+    assert_eq!(items.encoded_len().unwrap(), buffer.length());
+
+    buffer.move_cursor(0).unwrap();
+    assert_eq!(buffer.read::<Vec<Aligned>>().unwrap(), items);
+
+    for offset in 0..8 {
+        let value = (
+            offset as u8,
+            Some(Aligned { x: 5, y: 6 }),
+            [Aligned { x: 7, y: 8 }],
+        );
+
+        buffer.truncate(0).unwrap();
+        buffer.write_zeros(offset).unwrap();
+
+        let len = value.encoded_len_at(buffer.cursor()).unwrap();
+
+        buffer.write(value).unwrap();
+
+        assert_eq!(len, buffer.length() - offset, "offset {offset}");
+    }
+}
+
+#[test]
+fn test_conditional_padding() {
+    let mut buffer = MByteBuffer::new().unwrap();
+    let without = Chunk::Flags {
+        has_extra: false,
+        extra: 0,
+    };
+    let with = Chunk::Flags {
+        has_extra: true,
+        extra: 5,
+    };
+
+    assert_eq!(without.encoded_len().unwrap(), 3);
+    assert_eq!(with.encoded_len().unwrap(), 5);
+
+    buffer.write(&without).unwrap();
+    buffer.truncate(0).unwrap();
+    buffer.write(&with).unwrap();
+
+    assert_eq!(buffer.length(), 5);
+
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read::<Chunk>().unwrap(), with);
+}
+
+#[test]
+fn test_magic_mismatch() {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer.write_slice(b"MBTX").unwrap();
+    buffer.write_zeros(14).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    match buffer.read::<Header>() {
+        Err(MByteBufferError::MagicMismatch {
+            expected,
+            found,
+            type_name,
+        }) => {
+            assert_eq!(expected, b"MBTY");
+            assert_eq!(found, b"MBTX");
+            assert_eq!(type_name, "Header");
+        }
+        other => panic!("expected a magic mismatch, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_missing_padding() {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer.write_slice(b"MBTY").unwrap();
+    buffer.write(1u8).unwrap();
+    buffer.write_zeros(2).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    let error = buffer.read::<Header>().unwrap_err();
+
+    assert_eq!(error.path(), Some("Header.size"));
+}
+
+#[test]
+fn test_read_ref_magic_pad() {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer.write_slice(b"\x7fELF").unwrap();
+    buffer.write_zeros(2).unwrap();
+    buffer.write("name").unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(
+        buffer.read_ref::<Borrowed>().unwrap(),
+        Borrowed { name: "name" }
+    );
+}
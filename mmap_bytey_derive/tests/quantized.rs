@@ -0,0 +1,92 @@
+use mmap_bytey::{
+    FixedPoint, MByteBuffer, MByteBufferRead, MByteBufferWrite, Quantized, SmallestThree, F16,
+};
+
+#[derive(MByteBufferWrite, MByteBufferRead, PartialEq, Debug)]
+struct PlayerState {
+    id: u32,
+    #[mbytey(encode_as = "Quantized<f32, -1024, 1024, 16>")]
+    x: f32,
+    #[mbytey(encode_as = "Quantized<f32, -1024, 1024, 16>")]
+    y: f32,
+    #[mbytey(encode_as = "SmallestThree")]
+    rotation: [f32; 4],
+    #[mbytey(encode_as = "F16")]
+    speed: f32,
+    #[mbytey(encode_as = "FixedPoint<i16, 4>")]
+    height: f64,
+}
+
+#[derive(MByteBufferWrite, MByteBufferRead, PartialEq, Debug)]
+enum Event {
+    Moved(#[mbytey(encode_as = "Quantized<f32, 0, 100, 8>")] f32),
+    Turned {
+        #[mbytey(encode_as = "SmallestThree<16>")]
+        rotation: [f32; 4],
+    },
+}
+
+#[test]
+fn test_struct_as_attribute() {
+    let mut buffer = MByteBuffer::new().unwrap();
+    let state = PlayerState {
+        id: 7,
+        x: -512.0,
+        y: 100.25,
+        rotation: [0.0, 0.0, 0.0, 1.0],
+        speed: 4.5,
+        height: 12.0625,
+    };
+
+    buffer.write(&state).unwrap();
+
+    assert_eq!(buffer.length(), 4 + 2 + 2 + 4 + 2 + 2);
+    assert_eq!(PlayerState::FIXED_SIZE, Some(16));
+    assert_eq!(state.encoded_len().unwrap(), 16);
+
+    buffer.move_cursor(0).unwrap();
+
+    let read = buffer.read::<PlayerState>().unwrap();
+    let max_error = Quantized::<f32, -1024, 1024, 16>::max_error() as f32;
+
+    assert_eq!(read.id, 7);
+    assert!((read.x - state.x).abs() <= max_error);
+    assert!((read.y - state.y).abs() <= max_error);
+    assert!(read
+        .rotation
+        .iter()
+        .zip(state.rotation)
+        .all(|(read, written)| (read - written).abs() < 0.001));
+    assert_eq!(read.speed, 4.5);
+    assert_eq!(read.height, 12.0625);
+}
+
+#[test]
+fn test_enum_as_attribute() {
+    let mut buffer = MByteBuffer::new().unwrap();
+    let half = std::f32::consts::FRAC_1_SQRT_2;
+
+    buffer.write_be(Event::Moved(40.0)).unwrap();
+    buffer
+        .write_be(Event::Turned {
+            rotation: [half, 0.0, 0.0, half],
+        })
+        .unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    let Event::Moved(distance) = buffer.read_be::<Event>().unwrap() else {
+        panic!("expected Event::Moved");
+    };
+
+    assert!((distance - 40.0).abs() <= 100.0 / 255.0 / 2.0);
+
+    let Event::Turned { rotation } = buffer.read_be::<Event>().unwrap() else {
+        panic!("expected Event::Turned");
+    };
+
+    for (read, written) in rotation.iter().zip([half, 0.0, 0.0, half]) {
+        assert!((read - written).abs() < 0.0001);
+    }
+
+    assert_eq!(buffer.length(), (2 + 1) + (2 + 7));
+}
@@ -39,3 +39,33 @@ fn test_enum_read() {
 
     assert_eq!(val_unit, buffer.read::<Test>().unwrap());
 }
+
+#[test]
+fn test_enum_read_byte_order() {
+    #[derive(MByteBufferWrite, MByteBufferRead, PartialEq, Debug)]
+    enum Test {
+        Unit,
+        Unnamed(u16, u32),
+    }
+
+    let mut buffer = MByteBuffer::new().unwrap();
+    let val = Test::Unnamed(0x0102, 7);
+
+    buffer.write_be(&val).unwrap();
+    buffer.write_le(&val).unwrap();
+    buffer
+        .write_with::<mmap_bytey::Big, _>(&Test::Unit)
+        .unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read_slice(4).unwrap(), [0, 2, 1, 2]);
+
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(val, buffer.read_be::<Test>().unwrap());
+    assert_eq!(val, buffer.read_le::<Test>().unwrap());
+    assert_eq!(
+        Test::Unit,
+        buffer.read_with::<mmap_bytey::Big, Test>().unwrap()
+    );
+}
@@ -0,0 +1,121 @@
+use mmap_bytey::{MByteBuffer, MByteBufferError, MByteBufferReadRef, MByteBufferWrite};
+
+#[derive(MByteBufferWrite, PartialEq, Debug)]
+struct OwnedPosition {
+    x: i16,
+    y: i16,
+}
+
+#[derive(MByteBufferWrite, PartialEq, Debug)]
+enum OwnedEvent {
+    Chat { from: String, text: String },
+    Move(OwnedPosition, Vec<(u8, u16)>),
+    Spawn([u32; 2], Box<u8>),
+    Quit,
+}
+
+#[derive(MByteBufferReadRef, PartialEq, Debug)]
+struct Position {
+    x: i16,
+    y: i16,
+}
+
+#[derive(MByteBufferReadRef, PartialEq, Debug)]
+enum Event<'a> {
+    Chat { from: &'a str, text: &'a str },
+    Move(Position, Vec<(u8, u16)>),
+    Spawn([u32; 2], Box<u8>),
+    Quit,
+}
+
+#[derive(MByteBufferWrite, PartialEq, Debug)]
+#[mbytey(tag = "u8", length_delimited)]
+enum MessageV2 {
+    Chat(String, u32),
+    Trade { item: u32 },
+}
+
+#[derive(MByteBufferReadRef, PartialEq, Debug)]
+#[mbytey(tag = "u8", length_delimited)]
+enum MessageV1<'a> {
+    Chat(&'a str),
+    #[mbytey(other)]
+    Unknown {
+        tag: u8,
+        payload: &'a [u8],
+    },
+}
+
+#[test]
+fn test_read_ref_enum() {
+    let mut buffer = MByteBuffer::new().unwrap();
+    let owned = [
+        OwnedEvent::Chat {
+            from: "player".to_owned(),
+            text: "hello".to_owned(),
+        },
+        OwnedEvent::Move(OwnedPosition { x: -1, y: 2 }, vec![(1, 300), (2, 400)]),
+        OwnedEvent::Spawn([5, 6], Box::new(7)),
+        OwnedEvent::Quit,
+    ];
+
+    for event in &owned {
+        buffer.write_be(event).unwrap();
+    }
+
+    buffer.move_cursor(0).unwrap();
+
+    let mut reader = buffer.reader();
+
+    assert_eq!(
+        reader.read_be::<Event>().unwrap(),
+        Event::Chat {
+            from: "player",
+            text: "hello"
+        }
+    );
+    assert_eq!(
+        reader.read_be::<Event>().unwrap(),
+        Event::Move(Position { x: -1, y: 2 }, vec![(1, 300), (2, 400)])
+    );
+    assert_eq!(
+        reader.read_be::<Event>().unwrap(),
+        Event::Spawn([5, 6], Box::new(7))
+    );
+    assert_eq!(reader.read_be::<Event>().unwrap(), Event::Quit);
+}
+
+#[test]
+fn test_read_ref_enum_invalid_tag() {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer.write(9u16).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert!(matches!(
+        buffer.read_ref::<Event>(),
+        Err(MByteBufferError::InvalidTag { tag: 9, .. })
+    ));
+}
+
+#[test]
+fn test_read_ref_enum_length_delimited() {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer.write(MessageV2::Chat("hi".to_owned(), 3)).unwrap();
+    buffer.write(MessageV2::Trade { item: 4 }).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    let mut reader = buffer.reader();
+
+    // The appended `u32` is skipped and the unknown variant keeps its payload borrowed.
+    assert_eq!(reader.read::<MessageV1>().unwrap(), MessageV1::Chat("hi"));
+    assert_eq!(
+        reader.read::<MessageV1>().unwrap(),
+        MessageV1::Unknown {
+            tag: 2,
+            payload: &4u32.to_ne_bytes()
+        }
+    );
+    assert_eq!(reader.cursor(), reader.length());
+}
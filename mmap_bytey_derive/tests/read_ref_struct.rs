@@ -0,0 +1,73 @@
+use std::borrow::Cow;
+
+use mmap_bytey::MByteBuffer;
+use mmap_bytey_derive::{MByteBufferReadRef, MByteBufferWrite};
+
+#[derive(MByteBufferWrite, PartialEq, Debug)]
+struct OwnedPacket {
+    id: u32,
+    name: String,
+    payload: Vec<u8>,
+    motd: String,
+    note: Option<String>,
+}
+
+#[derive(MByteBufferReadRef, PartialEq, Debug)]
+struct BorrowedPacket<'a> {
+    id: u32,
+    name: &'a str,
+    payload: &'a [u8],
+    motd: Cow<'a, str>,
+    note: Option<&'a str>,
+}
+
+#[derive(MByteBufferReadRef, PartialEq, Debug)]
+struct Header {
+    id: u32,
+}
+
+#[test]
+fn test_read_ref_struct() {
+    let mut buffer = MByteBuffer::new().unwrap();
+    let val = OwnedPacket {
+        id: 7,
+        name: "player".to_owned(),
+        payload: vec![1, 2, 3, 4],
+        motd: "hello".to_owned(),
+        note: Some("note".to_owned()),
+    };
+
+    buffer.write(&val).unwrap();
+    buffer.write_le(&val).unwrap();
+    buffer.write_be(&val).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    let expected = BorrowedPacket {
+        id: 7,
+        name: "player",
+        payload: &[1, 2, 3, 4],
+        motd: Cow::Borrowed("hello"),
+        note: Some("note"),
+    };
+
+    let mut reader = buffer.reader();
+
+    assert_eq!(reader.read::<BorrowedPacket>().unwrap(), expected);
+    assert_eq!(reader.read_le::<BorrowedPacket>().unwrap(), expected);
+    assert_eq!(reader.read_be::<BorrowedPacket>().unwrap(), expected);
+}
+
+#[test]
+fn test_read_ref_borrows_buffer() {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer.write(7u32).unwrap();
+    buffer.write("borrowed").unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    let header = buffer.read_ref::<Header>().unwrap();
+    assert_eq!(header, Header { id: 7 });
+
+    let name = buffer.read_ref::<&str>().unwrap();
+    assert_eq!(name, "borrowed");
+}
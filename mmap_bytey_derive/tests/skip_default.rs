@@ -0,0 +1,166 @@
+use mmap_bytey::{
+    MByteBuffer, MByteBufferError, MByteBufferRead, MByteBufferReadRef, MByteBufferWrite, Result,
+};
+use std::time::Instant;
+
+#[derive(MByteBufferWrite, MByteBufferRead, Debug)]
+#[mbytey(post_read = "Session::rebuild")]
+struct Session {
+    id: u32,
+    names: Vec<String>,
+    #[mbytey(skip, default = "Instant::now()")]
+    opened: Instant,
+    #[mbytey(skip)]
+    name_count: usize,
+}
+
+impl Session {
+    fn rebuild(&mut self) -> Result<()> {
+        self.name_count = self.names.len();
+
+        Ok(())
+    }
+}
+
+#[derive(MByteBufferWrite, MByteBufferRead, PartialEq, Debug)]
+struct Request {
+    id: u32,
+    #[mbytey(skip_read, default = "String::from(\"unknown\")")]
+    origin: String,
+}
+
+#[derive(MByteBufferWrite, MByteBufferRead, PartialEq, Debug)]
+struct Response {
+    id: u32,
+    #[mbytey(skip_write)]
+    origin: String,
+}
+
+#[derive(MByteBufferWrite, MByteBufferRead, PartialEq, Debug)]
+#[mbytey(post_read = "check_level")]
+enum Level {
+    Low,
+    High(#[mbytey(skip, default = "3")] u8, u8),
+}
+
+fn check_level(level: &mut Level) -> Result<()> {
+    match level {
+        Level::High(min, value) if *value < *min => Err(MByteBufferError::OtherError {
+            error: String::from("level below its minimum"),
+        }),
+        _ => Ok(()),
+    }
+}
+
+#[derive(MByteBufferReadRef, PartialEq, Debug)]
+#[mbytey(post_read = "Tag::trim")]
+struct Tag<'a> {
+    name: &'a str,
+    #[mbytey(skip, default = "\"\"")]
+    trimmed: &'a str,
+}
+
+impl Tag<'_> {
+    fn trim(&mut self) -> Result<()> {
+        self.trimmed = self.name.trim();
+
+        Ok(())
+    }
+}
+
+#[test]
+fn test_skip_default_post_read() {
+    let mut buffer = MByteBuffer::new().unwrap();
+    let before = Instant::now();
+    let session = Session {
+        id: 1,
+        names: vec![String::from("a"), String::from("b")],
+        opened: before,
+        name_count: 0,
+    };
+
+    buffer.write(&session).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    let read = buffer.read::<Session>().unwrap();
+
+    assert_eq!(read.id, 1);
+    assert_eq!(read.name_count, 2);
+    assert!(read.opened >= before);
+}
+
+#[test]
+fn test_skip_read_write() {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer
+        .write(Request {
+            id: 4,
+            origin: String::from("client"),
+        })
+        .unwrap();
+
+    assert_eq!(buffer.length(), 4 + 8 + 6);
+
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(
+        buffer.read::<Response>().unwrap(),
+        Response {
+            id: 4,
+            origin: String::from("client")
+        }
+    );
+
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer
+        .write(Response {
+            id: 5,
+            origin: String::from("server"),
+        })
+        .unwrap();
+
+    assert_eq!(buffer.length(), 4);
+
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(
+        buffer.read::<Request>().unwrap(),
+        Request {
+            id: 5,
+            origin: String::from("unknown")
+        }
+    );
+}
+
+#[test]
+fn test_enum_post_read() {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer.write(Level::High(0, 5)).unwrap();
+    buffer.write(Level::High(0, 1)).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read::<Level>().unwrap(), Level::High(3, 5));
+    assert!(matches!(
+        buffer.read::<Level>(),
+        Err(MByteBufferError::OtherError { .. })
+    ));
+}
+
+#[test]
+fn test_read_ref_post_read() {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer.write(" tag ").unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(
+        buffer.read_ref::<Tag>().unwrap(),
+        Tag {
+            name: " tag ",
+            trimmed: "tag"
+        }
+    );
+}
@@ -0,0 +1,65 @@
+use mmap_bytey::{
+    FixedStr, MByteBuffer, MByteBufferError, MByteBufferRead, MByteBufferReadRef, MByteBufferWrite,
+    NulTerminated, Utf16,
+};
+
+#[derive(MByteBufferWrite, MByteBufferRead, PartialEq, Debug)]
+struct LegacyLogin {
+    #[mbytey(encode_as = "FixedStr<16>")]
+    account: String,
+    #[mbytey(encode_as = "NulTerminated")]
+    password: String,
+    #[mbytey(encode_as = "Utf16")]
+    display_name: String,
+}
+
+#[derive(MByteBufferReadRef, PartialEq, Debug)]
+struct LegacyLoginRef<'a> {
+    #[mbytey(encode_as = "FixedStr<16, &'a str>")]
+    account: &'a str,
+    #[mbytey(encode_as = "NulTerminated<&'a str>")]
+    password: &'a str,
+}
+
+#[test]
+fn test_string_encodings_derive() {
+    let mut buffer = MByteBuffer::new().unwrap();
+    let login = LegacyLogin {
+        account: String::from("admin"),
+        password: String::from("hunter2"),
+        display_name: String::from("Zoë"),
+    };
+
+    buffer.write_le(&login).unwrap();
+
+    assert_eq!(buffer.length(), 16 + 8 + (8 + 2 * 3));
+
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read_le::<LegacyLogin>().unwrap(), login);
+
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(
+        buffer.read_ref_le::<LegacyLoginRef>().unwrap(),
+        LegacyLoginRef {
+            account: "admin",
+            password: "hunter2"
+        }
+    );
+}
+
+#[test]
+fn test_string_encodings_derive_errors() {
+    let mut buffer = MByteBuffer::new().unwrap();
+    let login = LegacyLogin {
+        account: String::from("a name that is far too long"),
+        password: String::new(),
+        display_name: String::new(),
+    };
+
+    assert!(matches!(
+        buffer.write(&login),
+        Err(MByteBufferError::StringTooLong { max: 16, .. })
+    ));
+}
@@ -0,0 +1,174 @@
+use mmap_bytey::{
+    MByteBuffer, MByteBufferError, MByteBufferRead, MByteBufferReadRef, MByteBufferWrite,
+};
+
+mod v1 {
+    use super::*;
+
+    #[derive(MByteBufferWrite, MByteBufferRead, PartialEq, Debug)]
+    #[mbytey(version = 1)]
+    pub struct Save {
+        pub level: u16,
+        pub gold: u32,
+    }
+}
+
+mod v3 {
+    use super::*;
+
+    #[derive(MByteBufferWrite, MByteBufferRead, PartialEq, Debug)]
+    #[mbytey(version = 3)]
+    pub struct Save {
+        pub level: u16,
+        #[mbytey(until = 3)]
+        pub gold: u32,
+        #[mbytey(since = 2, default = "100")]
+        pub health: u8,
+        #[mbytey(since = 3)]
+        pub coins: u64,
+    }
+}
+
+#[derive(MByteBufferWrite, MByteBufferRead, PartialEq, Debug)]
+#[mbytey(version = 3)]
+struct Flags {
+    #[mbytey(bits = 3)]
+    a: u8,
+    #[mbytey(until = 2)]
+    b: u8,
+    #[mbytey(bits = 3)]
+    c: u8,
+    d: u16,
+}
+
+#[derive(MByteBufferReadRef, PartialEq, Debug)]
+#[mbytey(version = 2)]
+struct Profile<'a> {
+    name: &'a str,
+    #[mbytey(since = 2)]
+    title: Option<&'a str>,
+}
+
+#[test]
+fn test_version_write() {
+    let mut buffer = MByteBuffer::new().unwrap();
+    let save = v3::Save {
+        level: 5,
+        gold: 10,
+        health: 50,
+        coins: 7,
+    };
+
+    buffer.write_be(&save).unwrap();
+
+    assert_eq!(v3::Save::FIXED_SIZE, Some(4 + 2 + 1 + 8));
+    assert_eq!(save.encoded_len().unwrap(), buffer.length());
+
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read_slice(7).unwrap(), [0, 0, 0, 3, 0, 5, 50]);
+
+    buffer.move_cursor(0).unwrap();
+
+    // The removed `gold` is not written, so it reads back as its default.
+    assert_eq!(
+        buffer.read_be::<v3::Save>().unwrap(),
+        v3::Save { gold: 0, ..save }
+    );
+}
+
+#[test]
+fn test_version_read_older() {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer.write(v1::Save { level: 2, gold: 30 }).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(
+        buffer.read::<v3::Save>().unwrap(),
+        v3::Save {
+            level: 2,
+            gold: 30,
+            health: 100,
+            coins: 0,
+        }
+    );
+}
+
+#[test]
+fn test_version_newer() {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer
+        .write(v3::Save {
+            level: 2,
+            gold: 0,
+            health: 1,
+            coins: 0,
+        })
+        .unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert!(matches!(
+        buffer.read::<v1::Save>(),
+        Err(MByteBufferError::UnsupportedVersion {
+            version: 3,
+            latest: 1,
+            type_name: "Save"
+        })
+    ));
+}
+
+#[test]
+fn test_version_read_ref() {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer.write(1u32).unwrap();
+    buffer.write("old").unwrap();
+    buffer.write(2u32).unwrap();
+    buffer.write("new").unwrap();
+    buffer.write(Some("sir")).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(
+        buffer.read_ref::<Profile>().unwrap(),
+        Profile {
+            name: "old",
+            title: None
+        }
+    );
+    assert_eq!(
+        buffer.read_ref::<Profile>().unwrap(),
+        Profile {
+            name: "new",
+            title: Some("sir")
+        }
+    );
+}
+
+#[test]
+fn test_version_bit_runs() {
+    let mut buffer = MByteBuffer::new().unwrap();
+    let flags = Flags {
+        a: 5,
+        b: 0,
+        c: 3,
+        d: 7,
+    };
+
+    buffer.write_be(&flags).unwrap();
+
+    // The removed field still ends the run of `a`, as it does when reading.
+    assert_eq!(buffer.length(), 4 + 1 + 1 + 2);
+
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(
+        buffer.read_slice(8).unwrap(),
+        [0, 0, 0, 3, 0b1010_0000, 0b0110_0000, 0, 7]
+    );
+
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read_be::<Flags>().unwrap(), flags);
+}
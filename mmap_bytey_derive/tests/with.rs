@@ -0,0 +1,169 @@
+use mmap_bytey::{
+    ByteOrder, MByteBuffer, MByteBufferError, MByteBufferRead, MByteBufferReadRef,
+    MByteBufferReader, MByteBufferWrite, Result, VarInt,
+};
+
+mod as_varint {
+    use super::*;
+
+    pub fn write<O: ByteOrder>(value: &u64, buffer: &mut MByteBuffer) -> Result<()> {
+        buffer.write_with::<O, _>(VarInt(*value))?;
+
+        Ok(())
+    }
+
+    pub fn read<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<u64> {
+        Ok(buffer.read_with::<O, VarInt>()?.0)
+    }
+
+    pub fn read_ref<O: ByteOrder>(reader: &mut MByteBufferReader<'_>) -> Result<u64> {
+        Ok(reader.read_with::<O, VarInt>()?.0)
+    }
+}
+
+/// Writes a flag as the `Y` or `N` byte of an old format.
+fn write_yes_no<O: ByteOrder>(value: &bool, buffer: &mut MByteBuffer) -> Result<()> {
+    buffer.write_with::<O, u8>(if *value { b'Y' } else { b'N' })?;
+
+    Ok(())
+}
+
+fn read_yes_no<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<bool> {
+    match buffer.read_with::<O, u8>()? {
+        b'Y' => Ok(true),
+        b'N' => Ok(false),
+        tag => Err(MByteBufferError::InvalidTag {
+            tag: tag as u64,
+            type_name: "yes/no",
+        }),
+    }
+}
+
+#[derive(MByteBufferWrite, MByteBufferRead, PartialEq, Debug)]
+struct Account {
+    #[mbytey(with = "as_varint")]
+    id: u64,
+    #[mbytey(write_with = "write_yes_no", read_with = "read_yes_no")]
+    active: bool,
+    balance: u32,
+}
+
+#[derive(MByteBufferWrite, MByteBufferRead, PartialEq, Debug)]
+enum Event {
+    Deposit(#[mbytey(with = "as_varint")] u64),
+    Close,
+}
+
+#[derive(MByteBufferReadRef, PartialEq, Debug)]
+struct Name<'a> {
+    #[mbytey(with = "as_varint")]
+    id: u64,
+    name: &'a str,
+}
+
+#[test]
+fn test_with_struct() {
+    let mut buffer = MByteBuffer::new().unwrap();
+    let account = Account {
+        id: 300,
+        active: true,
+        balance: 7,
+    };
+
+    buffer.write_be(&account).unwrap();
+
+    assert_eq!(Account::FIXED_SIZE, None);
+    assert_eq!(account.encoded_len().unwrap(), 2 + 1 + 4);
+
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(
+        buffer.read_slice(7).unwrap(),
+        [0xac, 0x02, b'Y', 0, 0, 0, 7]
+    );
+
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read_be::<Account>().unwrap(), account);
+}
+
+#[test]
+fn test_with_errors() {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer.write_slice(&[1, b'?', 0, 0, 0, 0]).unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    let error = buffer.read::<Account>().unwrap_err();
+
+    assert_eq!(error.path(), Some("Account.active"));
+}
+
+#[test]
+fn test_with_enum() {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer.write(Event::Deposit(5)).unwrap();
+    buffer.write(Event::Close).unwrap();
+
+    assert_eq!(Event::Deposit(5).encoded_len().unwrap(), 3);
+    assert_eq!(buffer.length(), 3 + 2);
+
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(buffer.read::<Event>().unwrap(), Event::Deposit(5));
+    assert_eq!(buffer.read::<Event>().unwrap(), Event::Close);
+}
+
+#[test]
+fn test_with_read_ref() {
+    let mut buffer = MByteBuffer::new().unwrap();
+
+    buffer.write(VarInt(1000)).unwrap();
+    buffer.write("player").unwrap();
+    buffer.move_cursor(0).unwrap();
+
+    assert_eq!(
+        buffer.read_ref::<Name>().unwrap(),
+        Name {
+            id: 1000,
+            name: "player"
+        }
+    );
+}
+
+mod as_blob {
+    use super::*;
+    use std::collections::VecDeque;
+
+    pub fn write<O: ByteOrder>(value: &VecDeque<u8>, buffer: &mut MByteBuffer) -> Result<()> {
+        buffer.write_with::<O, _>(VarInt(value.len() as u64))?;
+
+        for byte in value {
+            buffer.write_with::<O, u8>(*byte)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn read<O: ByteOrder>(buffer: &mut MByteBuffer) -> Result<VecDeque<u8>> {
+        let len = buffer.read_with::<O, VarInt>()?.0 as usize;
+
+        Ok(buffer.read_slice(len)?.iter().copied().collect())
+    }
+}
+
+#[derive(MByteBufferWrite, MByteBufferRead, PartialEq, Debug)]
+struct Blob {
+    #[mbytey(with = "as_blob")]
+    data: std::collections::VecDeque<u8>,
+}
+
+#[test]
+fn test_with_encoded_len_large() {
+    let blob = Blob {
+        data: vec![7; 4000].into(),
+    };
+
+    assert_eq!(blob.encoded_len().unwrap(), 2 + 4000);
+}
@@ -2,7 +2,7 @@
 #![doc = include_str!("../README.md")]
 
 #[doc(inline)]
-pub use mmap_bytey_byte_buffer::byte_buffer::MByteBuffer;
+pub use mmap_bytey_byte_buffer::byte_buffer::{MByteBuffer, MByteBufferReader};
 
 #[doc(inline)]
 pub use mmap_bytey_byte_buffer::allocator::BUFFER_SIZE;
@@ -16,5 +16,29 @@ pub use mmap_bytey_byte_buffer::byte_buffer_write::{self, MByteBufferWrite};
 #[doc(inline)]
 pub use mmap_bytey_byte_buffer::byte_buffer_read::{self, MByteBufferRead};
 
+#[doc(inline)]
+pub use mmap_bytey_byte_buffer::byte_buffer_read_ref::{self, MByteBufferReadRef};
+
+#[doc(inline)]
+pub use mmap_bytey_byte_buffer::bits::{self, BitField, BitReader, BitWriter, LsbFirst, MsbFirst};
+
+#[doc(inline)]
+pub use mmap_bytey_byte_buffer::byte_order::{self, Big, ByteOrder, Endianness, Little, Native};
+
+#[doc(inline)]
+pub use mmap_bytey_byte_buffer::decode_mode::DecodeMode;
+
+#[doc(inline)]
+pub use mmap_bytey_byte_buffer::quantized::{
+    self, FixedPoint, Quantized, SmallestThree, BF16, F16,
+};
+
+#[doc(inline)]
+pub use mmap_bytey_byte_buffer::string_encoding::{self, FixedStr, NulTerminated, Utf16};
+
+#[doc(inline)]
+pub use mmap_bytey_byte_buffer::varint::{self, VarInt};
+
 pub use mmap_bytey_derive::MByteBufferRead;
+pub use mmap_bytey_derive::MByteBufferReadRef;
 pub use mmap_bytey_derive::MByteBufferWrite;